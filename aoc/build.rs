@@ -0,0 +1,77 @@
+//! Fingerprints the sources of every day, together with `common`, so that cached answers are
+//! only reused while the solver that produced them is unchanged.
+
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+#[allow(dead_code)]
+#[path = "src/fingerprint.rs"]
+mod fingerprint;
+
+use fingerprint::Fingerprint;
+
+fn main() -> io::Result<()> {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the runner lives inside the workspace")
+        .to_path_buf();
+    let common = workspace.join("common").join("src");
+    println!("cargo:rerun-if-changed={}", common.display());
+
+    let mut days: Vec<(u8, PathBuf)> = Vec::new();
+    for entry in fs::read_dir(&workspace)? {
+        let path = entry?.path();
+        let number = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("day"))
+            .and_then(|number| number.parse::<u8>().ok());
+        if let Some(number) = number {
+            days.push((number, path.join("src")));
+        }
+    }
+    days.sort();
+
+    let mut hashes = String::from("pub const SOURCE_HASHES: &[(u8, u64)] = &[\n");
+    for (number, sources) in days {
+        println!("cargo:rerun-if-changed={}", sources.display());
+        let mut fingerprint = Fingerprint::new();
+        for dir in [&sources, &common] {
+            for file in rust_files(dir)? {
+                fingerprint.write(
+                    file.strip_prefix(&workspace)
+                        .unwrap_or(&file)
+                        .to_string_lossy()
+                        .as_bytes(),
+                );
+                fingerprint.write(&fs::read(&file)?);
+            }
+        }
+        hashes.push_str(&format!(
+            "    ({}, {:#018x}),\n",
+            number,
+            fingerprint.finish()
+        ));
+    }
+    hashes.push_str("];\n");
+
+    let out_dir = PathBuf::from(env::var("OUT_DIR").expect("cargo sets OUT_DIR"));
+    fs::write(out_dir.join("source_hashes.rs"), hashes)
+}
+
+/// Every `.rs` file under `dir`, in a stable order.
+fn rust_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(rust_files(&path)?);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(path);
+        }
+    }
+    files.sort();
+    Ok(files)
+}
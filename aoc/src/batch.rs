@@ -0,0 +1,233 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use common::{input, ParamTable, Part};
+use serde::Serialize;
+
+use crate::{bench::as_nanos, days::Day, jobs};
+
+/// The outcome of one part of one input file.
+#[derive(Debug, Serialize)]
+pub struct BatchRow {
+    pub file: String,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Parsing and solving the part, since every row is solved on its own.
+    #[serde(rename = "duration_ns", serialize_with = "as_nanos")]
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+/// The regular files of `dir`, sorted by name, skipping hidden ones such as `.gitkeep`.
+pub fn input_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        if !hidden && entry.file_type()?.is_file() {
+            files.push(entry.path());
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// Solves every part of `day` for every file, on `threads` threads, with one row per file and
+/// part in file order. A file whose solver panics fails only its own rows.
+pub fn run_batch(
+    day: &Day,
+    files: &[PathBuf],
+    parts: &[Part],
+    params: &ParamTable,
+    threads: Option<usize>,
+) -> Vec<BatchRow> {
+    let runs: Vec<(&PathBuf, Part)> = files
+        .iter()
+        .flat_map(|file| parts.iter().map(move |&part| (file, part)))
+        .collect();
+
+    let report = jobs::run_all(&runs, threads, |&(file, part)| {
+        solve_file(day, file, part, params)
+    });
+    let elapsed: Vec<Duration> = report.results.iter().map(|run| run.elapsed).collect();
+    report
+        .into_values()
+        .zip(runs.iter().zip(elapsed))
+        .map(|(row, (&(file, part), duration))| {
+            row.unwrap_or_else(|message| BatchRow {
+                file: file_label(file),
+                part: part.number(),
+                answer: None,
+                duration,
+                error: Some(format!("panicked: {}", message)),
+            })
+        })
+        .collect()
+}
+
+fn solve_file(day: &Day, file: &Path, part: Part, params: &ParamTable) -> BatchRow {
+    let start = Instant::now();
+    let answer = input::read_path(file)
+        .map_err(|error| error.to_string())
+        .and_then(|input| (day.solve)(&input, &[part], params).map_err(|error| error.to_string()))
+        .and_then(|mut answers| match answers.pop() {
+            Some((_, answer)) => answer.map_err(|error| error.to_string()),
+            None => Err(format!("{} was not solved", part)),
+        });
+
+    let (answer, error) = match answer {
+        Ok(answer) => (Some(answer.to_string()), None),
+        Err(error) => (None, Some(error)),
+    };
+    BatchRow {
+        file: file_label(file),
+        part: part.number(),
+        answer,
+        duration: start.elapsed(),
+        error,
+    }
+}
+
+/// The name of `file`, without the directory it is in.
+fn file_label(file: &Path) -> String {
+    file.file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|| file.display().to_string())
+}
+
+pub fn to_csv(rows: &[BatchRow]) -> String {
+    let mut csv = String::from("file,part,answer,duration_ns,error\n");
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            csv_field(&row.file),
+            row.part,
+            csv_field(row.answer.as_deref().unwrap_or_default()),
+            row.duration.as_nanos(),
+            csv_field(row.error.as_deref().unwrap_or_default())
+        ));
+    }
+    csv
+}
+
+pub fn to_json(rows: &[BatchRow]) -> String {
+    serde_json::to_string_pretty(rows).expect("batch rows only contain plain data")
+}
+
+/// Quotes `value` when it contains a separator, a quote or a line break, as answers such as
+/// `4,6,3` do.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{PartAnswers, SolveError};
+
+    use crate::days;
+
+    use super::*;
+
+    #[test]
+    fn test_run_batch_over_a_directory() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(
+            dir.join("alice.txt"),
+            "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+        )
+        .unwrap();
+        fs::write(dir.join("bob.txt"), "1   2\nthree   4\n").unwrap();
+        fs::write(dir.join(".gitkeep"), "").unwrap();
+
+        let files = input_files(&dir).unwrap();
+        let rows = run_batch(
+            crate::days::find(1).unwrap(),
+            &files,
+            &Part::BOTH,
+            &ParamTable::new(),
+            Some(2),
+        );
+        fs::remove_dir_all(&dir).unwrap();
+
+        let outcomes: Vec<(&str, u8, Option<&str>, Option<&str>)> = rows
+            .iter()
+            .map(|row| {
+                (
+                    row.file.as_str(),
+                    row.part,
+                    row.answer.as_deref(),
+                    row.error.as_deref(),
+                )
+            })
+            .collect();
+        let parse_error =
+            Some("line 2, column 1: 'three' is not a valid i32\n    three   4\n    ^");
+        assert_eq!(
+            outcomes,
+            vec![
+                ("alice.txt", 1, Some("11"), None),
+                ("alice.txt", 2, Some("31"), None),
+                ("bob.txt", 1, None, parse_error),
+                ("bob.txt", 2, None, parse_error),
+            ]
+        );
+    }
+
+    fn panics_on_a_bug(
+        input: &str,
+        parts: &[Part],
+        params: &ParamTable,
+    ) -> Result<PartAnswers, SolveError> {
+        assert!(!input.contains("bug"), "the input triggers a bug");
+        (days::DAYS[0].solve)(input, parts, params)
+    }
+
+    #[test]
+    fn test_a_panicking_file_fails_only_its_rows() {
+        let dir = std::env::temp_dir().join(format!("aoc-batch-panic-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("bug.txt"), "bug\n").unwrap();
+        fs::write(dir.join("valid.txt"), "3   4\n4   3\n").unwrap();
+
+        let day = Day {
+            solve: panics_on_a_bug,
+            ..days::DAYS[0]
+        };
+        let files = input_files(&dir).unwrap();
+        let rows = run_batch(&day, &files, &[Part::One], &ParamTable::new(), Some(2));
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].file, "bug.txt");
+        assert_eq!(rows[0].answer, None);
+        assert_eq!(
+            rows[0].error.as_deref(),
+            Some("panicked: the input triggers a bug")
+        );
+        assert_eq!(rows[1].answer.as_deref(), Some("0"));
+        assert!(to_csv(&rows).contains("bug.txt,1,,"));
+    }
+
+    #[test]
+    fn test_csv_quotes_fields() {
+        let rows = [BatchRow {
+            file: "input.txt".to_string(),
+            part: 1,
+            answer: Some("4,6,3".to_string()),
+            duration: Duration::from_nanos(1500),
+            error: None,
+        }];
+        assert_eq!(
+            to_csv(&rows),
+            "file,part,answer,duration_ns,error\ninput.txt,1,\"4,6,3\",1500,\n"
+        );
+    }
+}
@@ -0,0 +1,388 @@
+use std::{
+    fmt,
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use common::{params, ParamTable, Solution, SolveError};
+use serde::Serialize;
+
+/// Times parsing and both parts of one day separately.
+pub type Bencher = fn(&str, &ParamTable, &BenchOptions) -> Result<Samples, SolveError>;
+
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct BenchOptions {
+    /// Measured runs.
+    pub iterations: usize,
+    /// Runs made before measuring, to warm up caches and the allocator.
+    pub warmup: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+/// The duration of every measured run, per phase, and the phases that failed.
+#[derive(Debug, Default)]
+pub struct Samples {
+    parse: Vec<Duration>,
+    part1: Vec<Duration>,
+    part2: Vec<Duration>,
+    errors: Vec<(Phase, SolveError)>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Stats {
+    #[serde(rename = "mean_ns", serialize_with = "as_nanos")]
+    pub mean: Duration,
+    #[serde(rename = "median_ns", serialize_with = "as_nanos")]
+    pub median: Duration,
+    #[serde(rename = "min_ns", serialize_with = "as_nanos")]
+    pub min: Duration,
+}
+
+/// The timings of one phase, or why it failed.
+#[derive(Debug, Serialize)]
+pub struct PhaseReport {
+    pub phase: Phase,
+    #[serde(flatten)]
+    pub stats: Option<Stats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub day: u8,
+    pub phases: Vec<PhaseReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    #[serde(flatten)]
+    pub options: BenchOptions,
+    pub days: Vec<DayReport>,
+}
+
+/// Runs `S::parse_with`, `S::part1` and `S::part2` on `input` `warmup + iterations` times and
+/// keeps the timings of the last `iterations` runs. A phase that fails is recorded with its error
+/// and not run again, so the other part is still timed; the parts are not run once parsing fails.
+pub fn time_phases<S: Solution>(
+    input: &str,
+    params: &ParamTable,
+    options: &BenchOptions,
+) -> Result<Samples, SolveError> {
+    let params: S::Params = params::from_table(params)?;
+    let mut samples = Samples::default();
+
+    for run in 0..options.warmup + options.iterations {
+        let measured = run >= options.warmup;
+        let start = Instant::now();
+        let parsed = match S::parse_with(black_box(input), &params) {
+            Ok(parsed) => black_box(parsed),
+            Err(error) => {
+                samples.errors.push((Phase::Parse, error));
+                break;
+            }
+        };
+        if measured {
+            samples.parse.push(start.elapsed());
+        }
+
+        let parts = [
+            (Phase::Part1, S::part1 as fn(&_) -> _, &mut samples.part1),
+            (Phase::Part2, S::part2, &mut samples.part2),
+        ];
+        for (phase, solve, durations) in parts {
+            if failed(&samples.errors, phase) {
+                continue;
+            }
+            let start = Instant::now();
+            match solve(&parsed) {
+                Ok(answer) => {
+                    black_box(answer);
+                    if measured {
+                        durations.push(start.elapsed());
+                    }
+                }
+                Err(error) => samples.errors.push((phase, error)),
+            }
+        }
+        if failed(&samples.errors, Phase::Part1) && failed(&samples.errors, Phase::Part2) {
+            break;
+        }
+    }
+
+    Ok(samples)
+}
+
+fn failed(errors: &[(Phase, SolveError)], phase: Phase) -> bool {
+    errors.iter().any(|(failed, _)| *failed == phase)
+}
+
+impl Samples {
+    pub fn report(&self, day: u8) -> DayReport {
+        let phases = [
+            (Phase::Parse, &self.parse),
+            (Phase::Part1, &self.part1),
+            (Phase::Part2, &self.part2),
+        ]
+        .into_iter()
+        .filter_map(|(phase, samples)| {
+            let stats = Stats::from_samples(samples);
+            let error = self
+                .errors
+                .iter()
+                .find(|(failed, _)| *failed == phase)
+                .map(|(_, error)| error.to_string());
+            (stats.is_some() || error.is_some()).then_some(PhaseReport {
+                phase,
+                stats,
+                error,
+            })
+        })
+        .collect();
+
+        DayReport { day, phases }
+    }
+}
+
+impl Stats {
+    /// `None` when there are no samples.
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let min = *sorted.first()?;
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+        let mean = sorted.iter().sum::<Duration>() / sorted.len() as u32;
+
+        Some(Stats { mean, median, min })
+    }
+}
+
+impl BenchReport {
+    /// One aligned line per day and phase, for reading in a terminal.
+    pub fn to_table(&self) -> String {
+        let mut table = format!(
+            "{:<3}  {:<6}  {:>12}  {:>12}  {:>12}\n",
+            "Day", "Phase", "Mean", "Median", "Min"
+        );
+        for (day, report) in self.rows() {
+            let day = format!("{:02}", day);
+            let phase = report.phase.to_string();
+            match (&report.stats, &report.error) {
+                (Some(stats), None) => table.push_str(&format!(
+                    "{:<3}  {:<6}  {:>12}  {:>12}  {:>12}\n",
+                    day,
+                    phase,
+                    format!("{:.2?}", stats.mean),
+                    format!("{:.2?}", stats.median),
+                    format!("{:.2?}", stats.min)
+                )),
+                _ => table.push_str(&format!("{:<3}  {:<6}  {}\n", day, phase, report.failure())),
+            }
+        }
+        table
+    }
+
+    pub fn to_markdown(&self) -> String {
+        let mut table = String::from("| Day | Phase | Mean | Median | Min |\n");
+        table.push_str("|----:|:------|-----:|-------:|----:|\n");
+        for (day, report) in self.rows() {
+            match (&report.stats, &report.error) {
+                (Some(stats), None) => table.push_str(&format!(
+                    "| {:02} | {} | {:.2?} | {:.2?} | {:.2?} |\n",
+                    day, report.phase, stats.mean, stats.median, stats.min
+                )),
+                _ => table.push_str(&format!(
+                    "| {:02} | {} | {} | | |\n",
+                    day,
+                    report.phase,
+                    report.failure().replace('|', "\\|")
+                )),
+            }
+        }
+        table
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("benchmark reports only contain plain data")
+    }
+
+    fn rows(&self) -> impl Iterator<Item = (u8, &PhaseReport)> {
+        self.days
+            .iter()
+            .flat_map(|day| day.phases.iter().map(move |phase| (day.day, phase)))
+    }
+}
+
+impl PhaseReport {
+    /// Why the phase has no timings, cut to the first line of the error to fit in a table row.
+    fn failure(&self) -> String {
+        let error = self.error.as_deref().unwrap_or("no measured runs");
+        format!("failed: {}", error.lines().next().unwrap_or_default())
+    }
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part1 => write!(f, "part 1"),
+            Phase::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+pub(crate) fn as_nanos<S: serde::Serializer>(
+    duration: &Duration,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(duration.as_nanos() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Answer;
+
+    use super::*;
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = usize;
+        type Params = common::NoParams;
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &Self::Input) -> Result<Answer, SolveError> {
+            Ok((*lines).into())
+        }
+
+        fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+            Ok((lines * 2).into())
+        }
+    }
+
+    /// Counts lines too, but has no answer for part 1.
+    struct HalfSolved;
+
+    impl Solution for HalfSolved {
+        type Input = usize;
+        type Params = common::NoParams;
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            Count::parse(input)
+        }
+
+        fn part1(_lines: &Self::Input) -> Result<Answer, SolveError> {
+            Err(SolveError::NoSolution("part 1 is not solved".to_string()))
+        }
+
+        fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+            Count::part2(lines)
+        }
+    }
+
+    fn millis(values: &[u64]) -> Vec<Duration> {
+        values
+            .iter()
+            .map(|&value| Duration::from_millis(value))
+            .collect()
+    }
+
+    #[test]
+    fn test_stats_from_samples() {
+        assert_eq!(
+            Stats::from_samples(&millis(&[5, 1, 3])),
+            Some(Stats {
+                mean: Duration::from_millis(3),
+                median: Duration::from_millis(3),
+                min: Duration::from_millis(1),
+            })
+        );
+        assert_eq!(
+            Stats::from_samples(&millis(&[4, 1, 2, 9])).map(|stats| stats.median),
+            Some(Duration::from_millis(3))
+        );
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_time_phases_keeps_measured_runs_only() {
+        let options = BenchOptions {
+            iterations: 3,
+            warmup: 2,
+        };
+        let samples = time_phases::<Count>("1\n2\n", &ParamTable::new(), &options).unwrap();
+        assert_eq!(samples.parse.len(), 3);
+        assert_eq!(samples.part1.len(), 3);
+        assert_eq!(samples.part2.len(), 3);
+        assert_eq!(samples.report(1).phases.len(), 3);
+    }
+
+    #[test]
+    fn test_a_failing_phase_keeps_the_other_timings() {
+        let options = BenchOptions {
+            iterations: 3,
+            warmup: 1,
+        };
+        let samples = time_phases::<HalfSolved>("1\n", &ParamTable::new(), &options).unwrap();
+        assert_eq!(samples.parse.len(), 3);
+        assert_eq!(samples.part1.len(), 0);
+        assert_eq!(samples.part2.len(), 3);
+
+        let report = BenchReport {
+            options,
+            days: vec![samples.report(14)],
+        };
+        let table = report.to_table();
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows.len(), 4);
+        assert_eq!(
+            rows[2],
+            "14   part 1  failed: no solution: part 1 is not solved"
+        );
+        assert!(rows[3].starts_with("14   part 2  "));
+        assert!(report
+            .to_json()
+            .contains("\"error\": \"no solution: part 1 is not solved\""));
+    }
+
+    #[test]
+    fn test_markdown_report() {
+        let stats = Stats::from_samples(&millis(&[2])).unwrap();
+        let report = BenchReport {
+            options: BenchOptions {
+                iterations: 1,
+                warmup: 0,
+            },
+            days: vec![DayReport {
+                day: 6,
+                phases: vec![PhaseReport {
+                    phase: Phase::Part2,
+                    stats: Some(stats),
+                    error: None,
+                }],
+            }],
+        };
+        assert_eq!(
+            report.to_markdown(),
+            "| Day | Phase | Mean | Median | Min |\n\
+             |----:|:------|-----:|-------:|----:|\n\
+             | 06 | part 2 | 2.00ms | 2.00ms | 2.00ms |\n"
+        );
+    }
+}
@@ -0,0 +1,256 @@
+use std::{
+    error, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use common::{ParamTable, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::fingerprint;
+
+include!(concat!(env!("OUT_DIR"), "/source_hashes.rs"));
+
+/// Where `aoc run` keeps answers when `--cache-dir` is not given.
+pub const DEFAULT_DIR: &str = ".aoc-cache";
+
+const FILE_NAME: &str = "answers.json";
+
+/// What an answer depends on: an answer is only reused when all of it matches.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheKey {
+    pub day: u8,
+    pub part: u8,
+    /// Fingerprint of the day's sources and `common` when the answer was computed.
+    pub solver: String,
+    /// Fingerprint of the puzzle input.
+    pub input: String,
+    /// Fingerprint of the parameter overrides.
+    pub params: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    #[serde(flatten)]
+    pub key: CacheKey,
+    pub answer: String,
+}
+
+/// Answers computed by earlier runs, stored as JSON in `<dir>/answers.json`.
+#[derive(Debug)]
+pub struct AnswerCache {
+    path: PathBuf,
+    entries: Vec<CacheEntry>,
+}
+
+#[derive(Debug)]
+pub enum CacheError {
+    Io { path: String, error: io::Error },
+    Parse { path: String, message: String },
+}
+
+impl CacheKey {
+    pub fn new(day: u8, part: Part, input: &str, params: &ParamTable) -> CacheKey {
+        let params = toml::to_string(params).expect("parameter tables serialize to TOML");
+        CacheKey {
+            day,
+            part: part.number(),
+            solver: solver_fingerprint(day),
+            input: fingerprint::hex(input.as_bytes()),
+            params: fingerprint::hex(params.as_bytes()),
+        }
+    }
+}
+
+impl CacheEntry {
+    /// Whether the day's sources changed since the answer was computed, so it is never reused.
+    pub fn is_stale(&self) -> bool {
+        self.key.solver != solver_fingerprint(self.key.day)
+    }
+}
+
+impl AnswerCache {
+    /// Opens the cache in `dir`; a cache that does not exist yet is empty.
+    pub fn open(dir: &Path) -> Result<AnswerCache, CacheError> {
+        let path = dir.join(FILE_NAME);
+        let entries = match fs::read_to_string(&path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(|error| CacheError::Parse {
+                path: path.display().to_string(),
+                message: error.to_string(),
+            })?,
+            Err(error) if error.kind() == io::ErrorKind::NotFound => Vec::new(),
+            Err(error) => {
+                return Err(CacheError::Io {
+                    path: path.display().to_string(),
+                    error,
+                })
+            }
+        };
+        Ok(AnswerCache { path, entries })
+    }
+
+    /// An empty cache that will be written to `dir`, replacing whatever is there.
+    pub fn empty(dir: &Path) -> AnswerCache {
+        AnswerCache {
+            path: dir.join(FILE_NAME),
+            entries: Vec::new(),
+        }
+    }
+
+    pub fn get(&self, key: &CacheKey) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.key == *key)
+            .map(|entry| entry.answer.as_str())
+    }
+
+    /// Records `answer`, replacing older answers for the same day, part, input and parameters.
+    pub fn insert(&mut self, key: CacheKey, answer: String) {
+        self.entries.retain(|entry| {
+            (
+                entry.key.day,
+                entry.key.part,
+                &entry.key.input,
+                &entry.key.params,
+            ) != (key.day, key.part, &key.input, &key.params)
+        });
+        self.entries.push(CacheEntry { key, answer });
+    }
+
+    /// Entries sorted by day and part.
+    pub fn entries(&self) -> Vec<&CacheEntry> {
+        let mut entries: Vec<&CacheEntry> = self.entries.iter().collect();
+        entries.sort_by_key(|entry| (entry.key.day, entry.key.part));
+        entries
+    }
+
+    /// Removes the entries of the days for which `selected` holds and returns how many there were.
+    pub fn clear(&mut self, selected: impl Fn(u8) -> bool) -> usize {
+        let before = self.entries.len();
+        self.entries.retain(|entry| !selected(entry.key.day));
+        before - self.entries.len()
+    }
+
+    pub fn save(&self) -> Result<(), CacheError> {
+        let io_error = |error| CacheError::Io {
+            path: self.path.display().to_string(),
+            error,
+        };
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let contents =
+            serde_json::to_string_pretty(&self.entries).expect("cache entries are plain data");
+        fs::write(&self.path, contents).map_err(io_error)
+    }
+}
+
+/// Prints the entries as an aligned table, marking the ones whose solver has changed since.
+pub fn print_entries(entries: &[&CacheEntry]) {
+    let answer_width = entries
+        .iter()
+        .map(|entry| entry.answer.len())
+        .chain(["Answer".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:<3}  {:<4}  {:<answer_width$}  {:<16}  {:<16}  Status",
+        "Day", "Part", "Answer", "Input", "Params"
+    );
+    for entry in entries {
+        println!(
+            "{:<3}  {:<4}  {:<answer_width$}  {:<16}  {:<16}  {}",
+            format!("{:02}", entry.key.day),
+            entry.key.part,
+            entry.answer,
+            entry.key.input,
+            entry.key.params,
+            if entry.is_stale() { "stale" } else { "current" }
+        );
+    }
+}
+
+fn solver_fingerprint(day: u8) -> String {
+    SOURCE_HASHES
+        .iter()
+        .find(|&&(number, _)| number == day)
+        .map(|(_, hash)| format!("{:016x}", hash))
+        .unwrap_or_default()
+}
+
+impl fmt::Display for CacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CacheError::Io { path, error } => write!(f, "failed to access {}: {}", path, error),
+            CacheError::Parse { path, message } => {
+                write!(f, "invalid answer cache {}: {}", path, message)
+            }
+        }
+    }
+}
+
+impl error::Error for CacheError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            CacheError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keys_depend_on_input_and_params() {
+        let params = ParamTable::new();
+        let key = CacheKey::new(6, Part::Two, "..#\n", &params);
+
+        assert_eq!(key, CacheKey::new(6, Part::Two, "..#\n", &params));
+        assert_ne!(key, CacheKey::new(6, Part::One, "..#\n", &params));
+        assert_ne!(key, CacheKey::new(6, Part::Two, ".#.\n", &params));
+        let overridden = common::params::parse_table("width = 11").unwrap();
+        assert_ne!(key, CacheKey::new(6, Part::Two, "..#\n", &overridden));
+        assert_ne!(
+            key.solver,
+            CacheKey::new(7, Part::Two, "..#\n", &params).solver
+        );
+    }
+
+    #[test]
+    fn test_round_trip_and_clear() {
+        let dir = std::env::temp_dir().join(format!("aoc-cache-{}", std::process::id()));
+        let params = ParamTable::new();
+        let mut cache = AnswerCache::open(&dir).unwrap();
+        assert!(cache.entries().is_empty());
+
+        cache.insert(CacheKey::new(9, Part::Two, "12345", &params), "132".into());
+        cache.insert(CacheKey::new(9, Part::Two, "12345", &params), "2858".into());
+        cache.insert(CacheKey::new(6, Part::One, "^", &params), "41".into());
+        cache.save().unwrap();
+
+        let mut cache = AnswerCache::open(&dir).unwrap();
+        assert_eq!(
+            cache.get(&CacheKey::new(9, Part::Two, "12345", &params)),
+            Some("2858")
+        );
+        assert_eq!(cache.entries().len(), 2);
+        assert!(cache.entries().iter().all(|entry| !entry.is_stale()));
+
+        assert_eq!(cache.clear(|day| day == 9), 1);
+        assert_eq!(cache.entries()[0].key.day, 6);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_stale_entries_are_detected() {
+        let mut key = CacheKey::new(6, Part::One, "^", &ParamTable::new());
+        key.solver = "0000000000000000".to_string();
+        let entry = CacheEntry {
+            key,
+            answer: "41".to_string(),
+        };
+        assert!(entry.is_stale());
+    }
+}
@@ -0,0 +1,181 @@
+use std::{collections::BTreeMap, error, fmt, fs, io, path::Path, str::FromStr};
+
+use common::ParamTable;
+
+/// Where the runner looks for parameters when `--config` is not given.
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+/// Overrides of the puzzle parameters, keyed by day, as read from `aoc.toml`:
+///
+/// ```toml
+/// [day14]
+/// width = 11
+/// height = 7
+/// ```
+#[derive(Debug, Default)]
+pub struct Config {
+    days: BTreeMap<u8, ParamTable>,
+}
+
+/// One `--param key=value` override. The value is read as TOML, falling back to a plain string.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParamOverride {
+    pub key: String,
+    pub value: toml::Value,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io { path: String, error: io::Error },
+    Parse(String),
+    UnknownDay(String),
+}
+
+impl Config {
+    /// Loads `path`, or [`DEFAULT_PATH`] when there is one and no path was given.
+    pub fn load(path: Option<&Path>) -> Result<Config, ConfigError> {
+        let path = match path {
+            Some(path) => path,
+            None if Path::new(DEFAULT_PATH).exists() => Path::new(DEFAULT_PATH),
+            None => return Ok(Config::default()),
+        };
+        let contents = fs::read_to_string(path).map_err(|error| ConfigError::Io {
+            path: path.display().to_string(),
+            error,
+        })?;
+        contents.parse()
+    }
+
+    /// The parameters of `day` from the file, with `overrides` applied on top.
+    pub fn params(&self, day: u8, overrides: &[ParamOverride]) -> ParamTable {
+        let mut params = self.days.get(&day).cloned().unwrap_or_default();
+        for ParamOverride { key, value } in overrides {
+            params.insert(key.clone(), value.clone());
+        }
+        params
+    }
+}
+
+impl FromStr for Config {
+    type Err = ConfigError;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let tables: BTreeMap<String, ParamTable> =
+            toml::from_str(contents).map_err(|error| ConfigError::Parse(error.to_string()))?;
+
+        let mut days = BTreeMap::new();
+        for (key, params) in tables {
+            let day = crate::days::parse_day_key(&key).ok_or(ConfigError::UnknownDay(key))?;
+            days.insert(day, params);
+        }
+
+        Ok(Config { days })
+    }
+}
+
+impl FromStr for ParamOverride {
+    type Err = String;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let (key, value) = text
+            .split_once('=')
+            .ok_or_else(|| format!("expected 'key=value', found '{}'", text))?;
+        let key = key.trim();
+        if key.is_empty() {
+            return Err(format!("'{}' has no parameter name", text));
+        }
+
+        let value = value.trim();
+        let value = format!("value = {}", value)
+            .parse::<toml::Table>()
+            .ok()
+            .and_then(|mut table| table.remove("value"))
+            .unwrap_or_else(|| toml::Value::String(value.to_string()));
+
+        Ok(ParamOverride {
+            key: key.to_string(),
+            value,
+        })
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io { path, error } => write!(f, "failed to read {}: {}", path, error),
+            ConfigError::Parse(message) => write!(f, "invalid config: {}", message),
+            ConfigError::UnknownDay(key) => {
+                write!(f, "invalid config: '{}' is not a solved day", key)
+            }
+        }
+    }
+}
+
+impl error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ConfigError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONFIG: &str = r#"
+        [day14]
+        width = 11
+        height = 7
+
+        [day20]
+        min_saving = 20
+    "#;
+
+    #[test]
+    fn test_params_per_day() {
+        let config = CONFIG.parse::<Config>().unwrap();
+
+        let params = config.params(14, &[]);
+        assert_eq!(params.get("width"), Some(&toml::Value::Integer(11)));
+        assert_eq!(params.len(), 2);
+        assert!(config.params(1, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_overrides_replace_file_values() {
+        let config = CONFIG.parse::<Config>().unwrap();
+        let overrides = [
+            "width=101".parse::<ParamOverride>().unwrap(),
+            "seconds = 5".parse::<ParamOverride>().unwrap(),
+        ];
+
+        let params = config.params(14, &overrides);
+        assert_eq!(params.get("width"), Some(&toml::Value::Integer(101)));
+        assert_eq!(params.get("height"), Some(&toml::Value::Integer(7)));
+        assert_eq!(params.get("seconds"), Some(&toml::Value::Integer(5)));
+    }
+
+    #[test]
+    fn test_parse_override_values() {
+        let value = |text: &str| text.parse::<ParamOverride>().unwrap().value;
+        assert_eq!(value("flag=true"), toml::Value::Boolean(true));
+        assert_eq!(
+            value("name=\"quoted\""),
+            toml::Value::String("quoted".into())
+        );
+        assert_eq!(value("name=bare"), toml::Value::String("bare".into()));
+        assert!("width".parse::<ParamOverride>().is_err());
+        assert!("=3".parse::<ParamOverride>().is_err());
+    }
+
+    #[test]
+    fn test_reject_unknown_day() {
+        let error = "[day30]\nwidth = 1\n".parse::<Config>().unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid config: 'day30' is not a solved day"
+        );
+    }
+}
@@ -0,0 +1,469 @@
+use std::{
+    fmt,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use common::{
+    animate::FrameLimits, Example, Image, ParamTable, Part, PartAnswers, Scene, Solution,
+    SolveError,
+};
+
+use crate::bench::{self, Bencher};
+
+/// Parses the puzzle input once, with the given parameter overrides, and solves the requested
+/// parts.
+pub type Solver = fn(&str, &[Part], &ParamTable) -> Result<PartAnswers, SolveError>;
+
+/// Generates a random input of the given size, or of the day's default size, from a seed.
+pub type InputGenerator = fn(Option<usize>, &ParamTable, u64) -> Result<String, SolveError>;
+
+/// Parses the puzzle input, with the given parameter overrides, and draws what solving it finds.
+pub type SceneRenderer = fn(&str, &ParamTable) -> Result<Scene, SolveError>;
+
+/// Parses the puzzle input, with the given parameter overrides, and draws it as an image.
+pub type ImageDrawer = fn(&str, &ParamTable) -> Result<Image, SolveError>;
+
+/// Parses the puzzle input, with the given parameter overrides, and records its simulation as
+/// frames.
+pub type FrameRecorder = fn(&str, &ParamTable, FrameLimits) -> Result<Vec<Image>, SolveError>;
+
+pub struct Day {
+    pub number: u8,
+    pub solve: Solver,
+    pub bench: Bencher,
+    pub generate: InputGenerator,
+    /// `None` for days that have nothing to draw.
+    pub render: Option<SceneRenderer>,
+    /// `None` for days that have nothing to draw; days that only render export the colors of
+    /// their scene.
+    pub draw: Option<ImageDrawer>,
+    /// `None` for days that are not simulations.
+    pub animate: Option<FrameRecorder>,
+    pub examples: &'static [Example],
+}
+
+impl Day {
+    /// `<inputs-dir>/dayNN/<file_name>`.
+    pub fn input_path(&self, inputs_dir: &Path, file_name: &str) -> PathBuf {
+        inputs_dir
+            .join(format!("day{:02}", self.number))
+            .join(file_name)
+    }
+
+    /// The name of the day's own puzzle input, which days 1 and 2 have always read from
+    /// `challenge_input.txt`.
+    pub fn input_file(&self) -> &'static str {
+        match self.number {
+            1 | 2 => "challenge_input.txt",
+            _ => "challenge.txt",
+        }
+    }
+}
+
+pub const DAYS: [Day; 21] = [
+    Day {
+        number: 1,
+        solve: common::solve::<day01::Day01>,
+        bench: bench::time_phases::<day01::Day01>,
+        generate: common::generate::generate::<day01::Day01>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day01::Day01 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 2,
+        solve: common::solve::<day02::Day02>,
+        bench: bench::time_phases::<day02::Day02>,
+        generate: common::generate::generate::<day02::Day02>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day02::Day02 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 3,
+        solve: common::solve::<day03::Day03>,
+        bench: bench::time_phases::<day03::Day03>,
+        generate: common::generate::generate::<day03::Day03>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day03::Day03 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 4,
+        solve: common::solve::<day04::Day04>,
+        bench: bench::time_phases::<day04::Day04>,
+        generate: common::generate::generate::<day04::Day04>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day04::Day04 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 5,
+        solve: common::solve::<day05::Day05>,
+        bench: bench::time_phases::<day05::Day05>,
+        generate: common::generate::generate::<day05::Day05>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day05::Day05 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 6,
+        solve: common::solve::<day06::Day06>,
+        bench: bench::time_phases::<day06::Day06>,
+        generate: common::generate::generate::<day06::Day06>,
+        render: Some(common::render::render::<day06::Day06>),
+        draw: Some(common::image::draw_rendered::<day06::Day06>),
+        animate: Some(common::animate::animate::<day06::Day06>),
+        examples: <day06::Day06 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 7,
+        solve: common::solve::<day07::Day07>,
+        bench: bench::time_phases::<day07::Day07>,
+        generate: common::generate::generate::<day07::Day07>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day07::Day07 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 8,
+        solve: common::solve::<day08::Day08>,
+        bench: bench::time_phases::<day08::Day08>,
+        generate: common::generate::generate::<day08::Day08>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day08::Day08 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 9,
+        solve: common::solve::<day09::Day09>,
+        bench: bench::time_phases::<day09::Day09>,
+        generate: common::generate::generate::<day09::Day09>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day09::Day09 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 10,
+        solve: common::solve::<day10::Day10>,
+        bench: bench::time_phases::<day10::Day10>,
+        generate: common::generate::generate::<day10::Day10>,
+        render: Some(common::render::render::<day10::Day10>),
+        draw: Some(common::image::draw_rendered::<day10::Day10>),
+        animate: None,
+        examples: <day10::Day10 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 11,
+        solve: common::solve::<day11::Day11>,
+        bench: bench::time_phases::<day11::Day11>,
+        generate: common::generate::generate::<day11::Day11>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day11::Day11 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 12,
+        solve: common::solve::<day12::Day12>,
+        bench: bench::time_phases::<day12::Day12>,
+        generate: common::generate::generate::<day12::Day12>,
+        render: Some(common::render::render::<day12::Day12>),
+        draw: Some(common::image::draw::<day12::Day12>),
+        animate: None,
+        examples: <day12::Day12 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 13,
+        solve: common::solve::<day13::Day13>,
+        bench: bench::time_phases::<day13::Day13>,
+        generate: common::generate::generate::<day13::Day13>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day13::Day13 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 14,
+        solve: common::solve::<day14::Day14>,
+        bench: bench::time_phases::<day14::Day14>,
+        generate: common::generate::generate::<day14::Day14>,
+        render: None,
+        draw: Some(common::image::draw::<day14::Day14>),
+        animate: Some(common::animate::animate::<day14::Day14>),
+        examples: <day14::Day14 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 15,
+        solve: common::solve::<day15::Day15>,
+        bench: bench::time_phases::<day15::Day15>,
+        generate: common::generate::generate::<day15::Day15>,
+        render: None,
+        draw: Some(common::image::draw::<day15::Day15>),
+        animate: Some(common::animate::animate::<day15::Day15>),
+        examples: <day15::Day15 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 16,
+        solve: common::solve::<day16::Day16>,
+        bench: bench::time_phases::<day16::Day16>,
+        generate: common::generate::generate::<day16::Day16>,
+        render: Some(common::render::render::<day16::Day16>),
+        draw: Some(common::image::draw::<day16::Day16>),
+        animate: None,
+        examples: <day16::Day16 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 17,
+        solve: common::solve::<day17::Day17>,
+        bench: bench::time_phases::<day17::Day17>,
+        generate: common::generate::generate::<day17::Day17>,
+        render: None,
+        draw: None,
+        animate: Some(common::animate::animate::<day17::Day17>),
+        examples: <day17::Day17 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 18,
+        solve: common::solve::<day18::Day18>,
+        bench: bench::time_phases::<day18::Day18>,
+        generate: common::generate::generate::<day18::Day18>,
+        render: Some(common::render::render::<day18::Day18>),
+        draw: Some(common::image::draw_rendered::<day18::Day18>),
+        animate: None,
+        examples: <day18::Day18 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 19,
+        solve: common::solve::<day19::Day19>,
+        bench: bench::time_phases::<day19::Day19>,
+        generate: common::generate::generate::<day19::Day19>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day19::Day19 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 20,
+        solve: common::solve::<day20::Day20>,
+        bench: bench::time_phases::<day20::Day20>,
+        generate: common::generate::generate::<day20::Day20>,
+        render: Some(common::render::render::<day20::Day20>),
+        draw: Some(common::image::draw_rendered::<day20::Day20>),
+        animate: None,
+        examples: <day20::Day20 as Solution>::EXAMPLES,
+    },
+    Day {
+        number: 21,
+        solve: common::solve::<day21::Day21>,
+        bench: bench::time_phases::<day21::Day21>,
+        generate: common::generate::generate::<day21::Day21>,
+        render: None,
+        draw: None,
+        animate: None,
+        examples: <day21::Day21 as Solution>::EXAMPLES,
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// The number of a solved day from a `dayNN` table key in `answers.toml` or `aoc.toml`.
+pub fn parse_day_key(key: &str) -> Option<u8> {
+    key.strip_prefix("day")
+        .and_then(|number| number.parse::<u8>().ok())
+        .filter(|&number| find(number).is_some())
+}
+
+/// Which days to run: a single day (`7`), an inclusive range (`3..7` or `3-7`) or `all`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DaySelection {
+    pub first: u8,
+    pub last: u8,
+}
+
+impl DaySelection {
+    pub fn days(&self) -> impl Iterator<Item = &'static Day> + '_ {
+        DAYS.iter()
+            .filter(move |day| day.number >= self.first && day.number <= self.last)
+    }
+
+    pub fn is_single_day(&self) -> bool {
+        self.first == self.last
+    }
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let last_day = DAYS[DAYS.len() - 1].number;
+        if value.eq_ignore_ascii_case("all") {
+            return Ok(DaySelection {
+                first: 1,
+                last: last_day,
+            });
+        }
+
+        let parse_day = |day: &str| -> Result<u8, String> {
+            let number = day
+                .trim()
+                .parse::<u8>()
+                .map_err(|_| format!("'{}' is not a day number", day))?;
+            if find(number).is_none() {
+                return Err(format!(
+                    "day {} is not solved yet (1..={})",
+                    number, last_day
+                ));
+            }
+            Ok(number)
+        };
+
+        let (first, last) = match value.split_once("..").or_else(|| value.split_once('-')) {
+            Some((first, last)) => (parse_day(first)?, parse_day(last)?),
+            None => {
+                let day = parse_day(value)?;
+                (day, day)
+            }
+        };
+
+        if first > last {
+            return Err(format!("range {} is empty", value));
+        }
+
+        Ok(DaySelection { first, last })
+    }
+}
+
+impl fmt::Display for DaySelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_single_day() {
+            write!(f, "day {}", self.first)
+        } else {
+            write!(f, "days {}..={}", self.first, self.last)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_1_and_2_keep_their_input_file() {
+        let inputs_dir = Path::new("inputs");
+        let path = |day: &Day| day.input_path(inputs_dir, day.input_file());
+        assert_eq!(
+            path(&DAYS[0]),
+            Path::new("inputs/day01/challenge_input.txt")
+        );
+        assert_eq!(
+            path(&DAYS[1]),
+            Path::new("inputs/day02/challenge_input.txt")
+        );
+        assert_eq!(path(&DAYS[2]), Path::new("inputs/day03/challenge.txt"));
+    }
+
+    #[test]
+    fn test_renderers_draw_their_examples() {
+        for day in DAYS.iter() {
+            let Some(render) = day.render else {
+                continue;
+            };
+            for example in day.examples {
+                let params = common::params::parse_table(example.params.unwrap_or_default());
+                let scene = params.and_then(|params| render(example.input, &params));
+                assert!(
+                    scene.is_ok(),
+                    "day {} cannot render '{}'",
+                    day.number,
+                    example.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_drawers_draw_their_examples() {
+        for day in DAYS.iter() {
+            let Some(draw) = day.draw else {
+                continue;
+            };
+            for example in day.examples {
+                let params = common::params::parse_table(example.params.unwrap_or_default());
+                let image = params.and_then(|params| draw(example.input, &params));
+                assert!(
+                    image.is_ok(),
+                    "day {} cannot draw '{}'",
+                    day.number,
+                    example.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_animators_record_their_examples() {
+        for day in DAYS.iter() {
+            let Some(animate) = day.animate else {
+                continue;
+            };
+            for example in day.examples {
+                let params = common::params::parse_table(example.params.unwrap_or_default());
+                let frames = params
+                    .and_then(|params| animate(example.input, &params, FrameLimits::default()));
+                assert!(
+                    frames.is_ok_and(|frames| !frames.is_empty()),
+                    "day {} cannot animate '{}'",
+                    day.number,
+                    example.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_single_day() {
+        assert_eq!(
+            "7".parse::<DaySelection>(),
+            Ok(DaySelection { first: 7, last: 7 })
+        );
+    }
+
+    #[test]
+    fn test_parse_all() {
+        let selection = "all".parse::<DaySelection>().unwrap();
+        assert_eq!(selection.days().count(), DAYS.len());
+    }
+
+    #[test]
+    fn test_parse_ranges() {
+        assert_eq!(
+            "3..7".parse::<DaySelection>(),
+            Ok(DaySelection { first: 3, last: 7 })
+        );
+        assert_eq!(
+            "3-7".parse::<DaySelection>(),
+            Ok(DaySelection { first: 3, last: 7 })
+        );
+        assert_eq!("3..7".parse::<DaySelection>().unwrap().days().count(), 5);
+    }
+
+    #[test]
+    fn test_parse_invalid_selections() {
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("7..3".parse::<DaySelection>().is_err());
+        assert!("seven".parse::<DaySelection>().is_err());
+    }
+}
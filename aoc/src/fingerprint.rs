@@ -0,0 +1,52 @@
+//! A hash that is stable across builds and Rust versions, unlike `DefaultHasher`, so that cache
+//! keys written by one build of the runner are still found by the next one. Also used by the
+//! build script to fingerprint the solvers' sources.
+
+const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// 64-bit FNV-1a.
+#[derive(Clone, Copy, Debug)]
+pub struct Fingerprint(u64);
+
+impl Fingerprint {
+    pub fn new() -> Self {
+        Fingerprint(OFFSET_BASIS)
+    }
+
+    pub fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= u64::from(byte);
+            self.0 = self.0.wrapping_mul(PRIME);
+        }
+    }
+
+    pub fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Default for Fingerprint {
+    fn default() -> Self {
+        Fingerprint::new()
+    }
+}
+
+/// The fingerprint of `bytes` as 16 hex digits.
+pub fn hex(bytes: &[u8]) -> String {
+    let mut fingerprint = Fingerprint::new();
+    fingerprint.write(bytes);
+    format!("{:016x}", fingerprint.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_known_values() {
+        assert_eq!(hex(b""), "cbf29ce484222325");
+        assert_eq!(hex(b"a"), "af63dc4c8601ec8c");
+        assert_ne!(hex(b"1\n2\n"), hex(b"1\n3\n"));
+    }
+}
@@ -0,0 +1,168 @@
+use std::{
+    fmt,
+    time::{Duration, Instant},
+};
+
+use common::panics;
+use rayon::prelude::*;
+
+/// The outcome of one job and how long it took.
+#[derive(Debug)]
+pub struct Timed<T> {
+    /// What the job returned, or the message it panicked with.
+    pub value: Result<T, String>,
+    pub elapsed: Duration,
+    /// The CPU time the job's thread spent on it, `None` where the platform cannot tell.
+    pub cpu: Option<Duration>,
+}
+
+/// The results of a batch of jobs, in the order the jobs were given.
+#[derive(Debug)]
+pub struct JobReport<T> {
+    pub results: Vec<Timed<T>>,
+    pub threads: usize,
+    pub wall_clock: Duration,
+}
+
+/// Runs `job` on every item on a pool of `threads` threads, or one per CPU when `None`. A job
+/// that panics fails on its own, without stopping the others.
+pub fn run_all<I, T, F>(items: &[I], threads: Option<usize>, job: F) -> JobReport<T>
+where
+    I: Sync,
+    T: Send,
+    F: Fn(&I) -> T + Sync,
+{
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads.unwrap_or(0))
+        .build()
+        .expect("the job thread pool could not be started");
+
+    let start = Instant::now();
+    let results = pool.install(|| {
+        items
+            .par_iter()
+            .map(|item| {
+                let job_start = Instant::now();
+                let cpu_start = thread_cpu_time();
+                let value = panics::catch(|| job(item));
+                Timed {
+                    value,
+                    elapsed: job_start.elapsed(),
+                    cpu: thread_cpu_time()
+                        .zip(cpu_start)
+                        .map(|(end, start)| end - start),
+                }
+            })
+            .collect()
+    });
+
+    JobReport {
+        results,
+        threads: pool.current_num_threads(),
+        wall_clock: start.elapsed(),
+    }
+}
+
+/// The CPU time the calling thread has used so far.
+#[cfg(unix)]
+fn thread_cpu_time() -> Option<Duration> {
+    let mut time = libc::timespec {
+        tv_sec: 0,
+        tv_nsec: 0,
+    };
+    // SAFETY: `time` is a valid, writable timespec for the duration of the call.
+    let status = unsafe { libc::clock_gettime(libc::CLOCK_THREAD_CPUTIME_ID, &mut time) };
+    (status == 0).then(|| Duration::new(time.tv_sec as u64, time.tv_nsec as u32))
+}
+
+#[cfg(not(unix))]
+fn thread_cpu_time() -> Option<Duration> {
+    None
+}
+
+impl<T> JobReport<T> {
+    /// The summed CPU time of the jobs, or `None` when it could not be measured for every job.
+    pub fn cpu_time(&self) -> Option<Duration> {
+        self.results.iter().map(|result| result.cpu).sum()
+    }
+
+    pub fn into_values(self) -> impl Iterator<Item = Result<T, String>> {
+        self.results.into_iter().map(|result| result.value)
+    }
+}
+
+impl<T> fmt::Display for JobReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let plural = |count: usize| if count == 1 { "" } else { "s" };
+        write!(
+            f,
+            "{} job{} on {} thread{}: {:.2?} wall-clock",
+            self.results.len(),
+            plural(self.results.len()),
+            self.threads,
+            plural(self.threads),
+            self.wall_clock
+        )?;
+        match self.cpu_time() {
+            Some(cpu_time) => write!(f, ", {:.2?} CPU", cpu_time),
+            None => Ok(()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    #[test]
+    fn test_results_keep_the_job_order() {
+        let delays = [30u64, 0, 20, 10];
+        let report = run_all(&delays, Some(4), |&delay| {
+            thread::sleep(Duration::from_millis(delay));
+            delay * 2
+        });
+
+        assert_eq!(report.threads, 4);
+        assert!(report.results[0].elapsed >= Duration::from_millis(30));
+        assert_eq!(
+            report.into_values().collect::<Vec<_>>(),
+            vec![Ok(60), Ok(0), Ok(40), Ok(20)]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_cpu_time_counts_work_but_not_waiting() {
+        let report = run_all(&[false, true], Some(2), |&busy| {
+            let start = thread_cpu_time().unwrap();
+            if busy {
+                while thread_cpu_time().unwrap() - start < Duration::from_millis(20) {}
+            } else {
+                thread::sleep(Duration::from_millis(200));
+            }
+        });
+
+        let cpu = |index: usize| report.results[index].cpu.unwrap();
+        assert!(cpu(0) < Duration::from_millis(100));
+        assert!(cpu(1) >= Duration::from_millis(20));
+        assert_eq!(report.cpu_time(), Some(cpu(0) + cpu(1)));
+        assert!(report.to_string().ends_with(" CPU"));
+    }
+
+    #[test]
+    fn test_a_panicking_job_fails_alone() {
+        let report = run_all(&[1, 0, 2], Some(2), |&divisor| {
+            if divisor == 0 {
+                panic!("cannot divide by zero");
+            }
+            6 / divisor
+        });
+
+        assert_eq!(
+            report.into_values().collect::<Vec<_>>(),
+            vec![Ok(6), Err("cannot divide by zero".to_string()), Ok(3)]
+        );
+    }
+}
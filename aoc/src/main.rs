@@ -0,0 +1,930 @@
+use std::{
+    fs,
+    io::IsTerminal,
+    path::{Path, PathBuf},
+};
+
+use bench::{BenchOptions, BenchReport};
+use cache::{AnswerCache, CacheKey};
+use clap::{Parser, Subcommand, ValueEnum};
+use common::{
+    animate::{self, FrameLimits},
+    image::ImageFormat,
+    InputError, InputSource, ParamTable, Part, SolveError,
+};
+use config::{Config, ParamOverride};
+use days::{Day, DaySelection};
+use manifest::Manifest;
+
+mod batch;
+mod bench;
+mod cache;
+mod config;
+mod days;
+mod fingerprint;
+mod jobs;
+mod manifest;
+mod serve;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2024 runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Solver diagnostics to print on stderr, e.g. `day17=trace` or `debug`; defaults to
+    /// `RUST_LOG`, then to warnings only.
+    #[arg(long, global = true, value_name = "FILTER")]
+    log: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day (`7`), a range of days (`3..7`) or `all` of them.
+    Run {
+        days: DaySelection,
+
+        /// Only run the given part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Puzzle input to use instead of `<inputs-dir>/dayNN/challenge.txt`
+        /// (`challenge_input.txt` for days 1 and 2), `-` for stdin (single day only).
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Directory containing the `dayNN` folders.
+        #[arg(long, default_value = ".")]
+        inputs_dir: PathBuf,
+
+        /// Puzzle parameters per day; `aoc.toml` is used when it exists.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Overrides one puzzle parameter, e.g. `--param width=11` (single day only).
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<ParamOverride>,
+
+        /// Worker threads running the days in parallel; one per CPU by default.
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+
+        /// Solve every part again instead of reusing the answers of earlier runs.
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Directory of the answer cache.
+        #[arg(long, default_value = cache::DEFAULT_DIR)]
+        cache_dir: PathBuf,
+    },
+    /// Time parsing and each part of the selected days over several iterations.
+    Bench {
+        #[arg(default_value = "all")]
+        days: DaySelection,
+
+        /// Measured runs per day.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        iterations: u64,
+
+        /// Unmeasured runs made before the measured ones.
+        #[arg(long, default_value_t = 2)]
+        warmup: u64,
+
+        #[arg(long, value_enum, default_value_t = ReportFormat::Table)]
+        format: ReportFormat,
+
+        /// Puzzle input to use instead of `<inputs-dir>/dayNN/challenge.txt`
+        /// (`challenge_input.txt` for days 1 and 2), `-` for stdin (single day only).
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Directory containing the `dayNN` folders.
+        #[arg(long, default_value = ".")]
+        inputs_dir: PathBuf,
+
+        /// Puzzle parameters per day; `aoc.toml` is used when it exists.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Overrides one puzzle parameter, e.g. `--param width=11` (single day only).
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<ParamOverride>,
+    },
+    /// Run one day over every input file in a directory and print a table of the answers.
+    Batch {
+        day: DaySelection,
+
+        /// Directory of input files, e.g. one per user.
+        dir: PathBuf,
+
+        /// Only run the given part.
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        #[arg(long, value_enum, default_value_t = BatchFormat::Csv)]
+        format: BatchFormat,
+
+        /// Puzzle parameters per day; `aoc.toml` is used when it exists.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Overrides one puzzle parameter, e.g. `--param width=11`.
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<ParamOverride>,
+
+        /// Worker threads solving the files in parallel; one per CPU by default.
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+    },
+    /// Generate random puzzle inputs for one day, to stress-test or benchmark it at scale.
+    Generate {
+        day: DaySelection,
+
+        /// Size of each input, e.g. its lines or the side of its grid; close to a real input by
+        /// default.
+        #[arg(long)]
+        size: Option<usize>,
+
+        /// Seed of the first input; the same seed always generates the same input.
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+
+        /// Inputs to generate, from consecutive seeds (requires `--out-dir`).
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        count: u64,
+
+        /// Directory to write `seed-<seed>.txt` files to instead of printing the input.
+        #[arg(long)]
+        out_dir: Option<PathBuf>,
+
+        /// Puzzle parameters per day; `aoc.toml` is used when it exists.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Overrides one puzzle parameter, e.g. `--param width=11`.
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<ParamOverride>,
+    },
+    /// Draw the grid state of one day, e.g. the guard's patrol of day 6, with colors.
+    Render {
+        day: DaySelection,
+
+        /// Puzzle input to use instead of `<inputs-dir>/dayNN/challenge.txt`
+        /// (`challenge_input.txt` for days 1 and 2), `-` for stdin.
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Directory containing the `dayNN` folders.
+        #[arg(long, default_value = ".")]
+        inputs_dir: PathBuf,
+
+        /// Puzzle parameters per day; `aoc.toml` is used when it exists.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Overrides one puzzle parameter, e.g. `--param width=11`.
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<ParamOverride>,
+
+        /// When to color the output; `auto` colors it for a terminal unless `NO_COLOR` is set.
+        #[arg(long, value_enum, default_value_t = ColorChoice::Auto)]
+        color: ColorChoice,
+    },
+    /// Record the simulation of one day step by step as an animated GIF or numbered frames.
+    Animate {
+        day: DaySelection,
+
+        /// GIF file to write, or the directory to write the frames into with `--frames`.
+        #[arg(long)]
+        out: PathBuf,
+
+        /// Write every frame into `--out` as a numbered image in this format instead of a GIF.
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        frames: Option<ExportFormat>,
+
+        /// Record every Nth step of the simulation.
+        #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..))]
+        stride: u64,
+
+        /// Stop recording after this many frames.
+        #[arg(long, default_value_t = 500, value_parser = clap::value_parser!(u64).range(1..))]
+        max_frames: u64,
+
+        /// Hundredths of a second each frame of the GIF is shown for.
+        #[arg(long, default_value_t = 10)]
+        delay: u16,
+
+        /// Pixels per tile in GIF, PNG and PPM frames.
+        #[arg(long, default_value_t = 4, value_parser = clap::value_parser!(u64).range(1..=64))]
+        scale: u64,
+
+        /// Puzzle input to use instead of `<inputs-dir>/dayNN/challenge.txt`
+        /// (`challenge_input.txt` for days 1 and 2), `-` for stdin.
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Directory containing the `dayNN` folders.
+        #[arg(long, default_value = ".")]
+        inputs_dir: PathBuf,
+
+        /// Puzzle parameters per day; `aoc.toml` is used when it exists.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Overrides one puzzle parameter, e.g. `--param seconds=200`.
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<ParamOverride>,
+    },
+    /// Save the grid state of one day as a PNG, PPM or SVG image.
+    Export {
+        day: DaySelection,
+
+        /// Image file to write.
+        #[arg(long)]
+        out: PathBuf,
+
+        /// Image format; taken from the extension of `--out` by default.
+        #[arg(long, value_enum)]
+        format: Option<ExportFormat>,
+
+        /// Pixels per tile in PNG and PPM images.
+        #[arg(long, default_value_t = 8, value_parser = clap::value_parser!(u64).range(1..=64))]
+        scale: u64,
+
+        /// Puzzle input to use instead of `<inputs-dir>/dayNN/challenge.txt`
+        /// (`challenge_input.txt` for days 1 and 2), `-` for stdin.
+        #[arg(long)]
+        input: Option<InputSource>,
+
+        /// Directory containing the `dayNN` folders.
+        #[arg(long, default_value = ".")]
+        inputs_dir: PathBuf,
+
+        /// Puzzle parameters per day; `aoc.toml` is used when it exists.
+        #[arg(long)]
+        config: Option<PathBuf>,
+
+        /// Overrides one puzzle parameter, e.g. `--param width=11`.
+        #[arg(long = "param", value_name = "KEY=VALUE")]
+        params: Vec<ParamOverride>,
+    },
+    /// List or clear the answers cached by `aoc run`.
+    Cache {
+        #[command(subcommand)]
+        action: CacheAction,
+
+        /// Directory of the answer cache.
+        #[arg(long, default_value = cache::DEFAULT_DIR)]
+        cache_dir: PathBuf,
+    },
+    /// Check the answers of every solver against the known-answers manifest.
+    Verify {
+        #[arg(default_value = "all")]
+        days: DaySelection,
+
+        /// Manifest of expected answers per day, input file and part.
+        #[arg(long, default_value = "answers.toml")]
+        manifest: PathBuf,
+
+        /// Directory containing the `dayNN` folders.
+        #[arg(long, default_value = ".")]
+        inputs_dir: PathBuf,
+
+        /// Worker threads running the days in parallel; one per CPU by default.
+        #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+        jobs: Option<u64>,
+    },
+    /// Answer `POST /day/{n}/part/{p}` requests carrying a puzzle input with JSON, on localhost.
+    Serve {
+        /// Port to listen on; 0 picks a free one.
+        #[arg(long, default_value_t = 2024)]
+        port: u16,
+
+        /// Puzzle parameters per day; `aoc.toml` is used when it exists. Requests override them
+        /// with their query, e.g. `?width=11&height=7`.
+        #[arg(long)]
+        config: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheAction {
+    /// Print every cached answer, marking those computed by an older version of the solver.
+    List,
+    /// Remove the cached answers of the selected days.
+    Clear {
+        #[arg(default_value = "all")]
+        days: DaySelection,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum BatchFormat {
+    Csv,
+    Json,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ExportFormat {
+    Png,
+    Ppm,
+    Svg,
+}
+
+impl From<ExportFormat> for ImageFormat {
+    fn from(format: ExportFormat) -> Self {
+        match format {
+            ExportFormat::Png => ImageFormat::Png,
+            ExportFormat::Ppm => ImageFormat::Ppm,
+            ExportFormat::Svg => ImageFormat::Svg,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ColorChoice {
+    Auto,
+    Always,
+    Never,
+}
+
+impl ColorChoice {
+    fn enabled(self) -> bool {
+        match self {
+            ColorChoice::Auto => {
+                std::io::stdout().is_terminal() && std::env::var_os("NO_COLOR").is_none()
+            }
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ReportFormat {
+    /// Aligned columns for the terminal.
+    Table,
+    Markdown,
+    Json,
+}
+
+fn main() {
+    let cli = Cli::parse();
+    match &cli.log {
+        Some(directives) => {
+            if let Err(error) = common::logging::init(directives) {
+                eprintln!("invalid --log filter '{}': {}", directives, error);
+                std::process::exit(2);
+            }
+        }
+        None => common::logging::init_from_env(),
+    }
+
+    match cli.command {
+        Command::Run {
+            days,
+            part,
+            input,
+            inputs_dir,
+            config,
+            params,
+            jobs,
+            no_cache,
+            cache_dir,
+        } => {
+            check_single_day(&days, input.is_some(), !params.is_empty());
+            let config = load_config(config.as_deref());
+            let mut cache = (!no_cache).then(|| {
+                AnswerCache::open(&cache_dir).unwrap_or_else(|error| {
+                    eprintln!("{}; starting with an empty cache", error);
+                    AnswerCache::empty(&cache_dir)
+                })
+            });
+
+            let runs: Vec<(&Day, InputSource, ParamTable)> = days
+                .days()
+                .map(|day| {
+                    let source = input_source(day, input.clone(), &inputs_dir);
+                    (day, source, config.params(day.number, &params))
+                })
+                .collect();
+            let report = jobs::run_all(
+                &runs,
+                jobs.map(|jobs| jobs as usize),
+                |(day, source, params)| run_day(day, part, source, params, cache.as_ref()),
+            );
+
+            let timings = (report.results.len() > 1).then(|| report.to_string());
+            let mut reused = 0;
+            for (run, (day, _, _)) in report.into_values().zip(&runs) {
+                let run = run.unwrap_or_else(|message| DayRun {
+                    lines: vec![format!("Day {:02}: panicked: {}", day.number, message)],
+                    ..DayRun::default()
+                });
+                for line in run.lines {
+                    println!("{}", line);
+                }
+                reused += run.cached;
+                if let Some(cache) = cache.as_mut() {
+                    for (key, answer) in run.solved {
+                        cache.insert(key, answer);
+                    }
+                }
+            }
+            if let Some(timings) = timings {
+                eprintln!("{}", timings);
+            }
+            if reused > 0 {
+                eprintln!(
+                    "{} answers reused from {}; pass --no-cache to solve them again",
+                    reused,
+                    cache_dir.display()
+                );
+            }
+            if let Some(Err(error)) = cache.map(|cache| cache.save()) {
+                eprintln!("{}", error);
+            }
+        }
+        Command::Bench {
+            days,
+            iterations,
+            warmup,
+            format,
+            input,
+            inputs_dir,
+            config,
+            params,
+        } => {
+            check_single_day(&days, input.is_some(), !params.is_empty());
+            let config = load_config(config.as_deref());
+
+            let options = BenchOptions {
+                iterations: iterations as usize,
+                warmup: warmup as usize,
+            };
+            let mut report = BenchReport {
+                options,
+                days: Vec::new(),
+            };
+            for day in days.days() {
+                let source = input_source(day, input.clone(), &inputs_dir);
+                let params = config.params(day.number, &params);
+                if let Some(day_report) = bench_day(day, &source, &params, &options) {
+                    report.days.push(day_report);
+                }
+            }
+
+            match format {
+                ReportFormat::Table => print!("{}", report.to_table()),
+                ReportFormat::Markdown => print!("{}", report.to_markdown()),
+                ReportFormat::Json => println!("{}", report.to_json()),
+            }
+        }
+        Command::Batch {
+            day,
+            dir,
+            part,
+            format,
+            config,
+            params,
+            jobs,
+        } => {
+            let puzzle = Puzzle::select(&day, "batch runs", config.as_deref(), &params);
+            let files = match batch::input_files(&dir) {
+                Ok(files) => files,
+                Err(error) => {
+                    eprintln!("failed to list {}: {}", dir.display(), error);
+                    std::process::exit(2);
+                }
+            };
+            let rows = batch::run_batch(
+                puzzle.day,
+                &files,
+                parts(part),
+                &puzzle.params,
+                jobs.map(|jobs| jobs as usize),
+            );
+            match format {
+                BatchFormat::Csv => print!("{}", batch::to_csv(&rows)),
+                BatchFormat::Json => println!("{}", batch::to_json(&rows)),
+            }
+        }
+        Command::Generate {
+            day,
+            size,
+            seed,
+            count,
+            out_dir,
+            config,
+            params,
+        } => {
+            let puzzle = Puzzle::select(
+                &day,
+                "generate makes inputs for",
+                config.as_deref(),
+                &params,
+            );
+            let Some(out_dir) = out_dir else {
+                if count > 1 {
+                    eprintln!("--count needs --out-dir to write the inputs to");
+                    std::process::exit(2);
+                }
+                print!("{}", puzzle.generate(size, seed));
+                return;
+            };
+
+            if let Err(error) = fs::create_dir_all(&out_dir) {
+                eprintln!("failed to create {}: {}", out_dir.display(), error);
+                std::process::exit(2);
+            }
+            for seed in (seed..).take(count as usize) {
+                let path = out_dir.join(format!("seed-{}.txt", seed));
+                if let Err(error) = fs::write(&path, puzzle.generate(size, seed)) {
+                    eprintln!("failed to write {}: {}", path.display(), error);
+                    std::process::exit(2);
+                }
+            }
+            println!(
+                "Generated {} inputs for day {:02} in {}",
+                count,
+                puzzle.day.number,
+                out_dir.display()
+            );
+        }
+        Command::Render {
+            day,
+            input,
+            inputs_dir,
+            config,
+            params,
+            color,
+        } => {
+            let puzzle = Puzzle::select(&day, "render draws", config.as_deref(), &params);
+            let Some(render) = puzzle.day.render else {
+                eprintln!("Day {:02} has nothing to render", puzzle.day.number);
+                std::process::exit(2);
+            };
+
+            let input = puzzle.read_input(input, &inputs_dir);
+            let scene = puzzle.or_exit(render(&input, &puzzle.params));
+            if color.enabled() {
+                print!("{}", scene.to_ansi());
+            } else {
+                print!("{}", scene);
+            }
+        }
+        Command::Export {
+            day,
+            out,
+            format,
+            scale,
+            input,
+            inputs_dir,
+            config,
+            params,
+        } => {
+            let puzzle = Puzzle::select(&day, "export draws", config.as_deref(), &params);
+            let Some(format) = format
+                .map(ImageFormat::from)
+                .or_else(|| ImageFormat::from_path(&out))
+            else {
+                eprintln!(
+                    "cannot tell the image format of {}; pass --format",
+                    out.display()
+                );
+                std::process::exit(2);
+            };
+            let Some(draw) = puzzle.day.draw else {
+                eprintln!("Day {:02} has nothing to draw", puzzle.day.number);
+                std::process::exit(2);
+            };
+
+            let input = puzzle.read_input(input, &inputs_dir);
+            let image = puzzle.or_exit(draw(&input, &puzzle.params));
+            if let Err(error) = fs::write(&out, image.encode(format, scale as usize)) {
+                eprintln!("failed to write {}: {}", out.display(), error);
+                std::process::exit(2);
+            }
+            println!(
+                "Wrote {}x{} tiles of day {:02} to {}",
+                image.tiles().cols(),
+                image.tiles().rows(),
+                puzzle.day.number,
+                out.display()
+            );
+        }
+        Command::Animate {
+            day,
+            out,
+            frames,
+            stride,
+            max_frames,
+            delay,
+            scale,
+            input,
+            inputs_dir,
+            config,
+            params,
+        } => {
+            let puzzle = Puzzle::select(&day, "animate records", config.as_deref(), &params);
+            let Some(animate) = puzzle.day.animate else {
+                eprintln!("Day {:02} has no simulation to animate", puzzle.day.number);
+                std::process::exit(2);
+            };
+            let limits = FrameLimits {
+                stride: stride as usize,
+                max_frames: max_frames as usize,
+            };
+
+            let input = puzzle.read_input(input, &inputs_dir);
+            let recorded = puzzle.or_exit(animate(&input, &puzzle.params, limits));
+            let written = match frames {
+                Some(format) => {
+                    animate::write_frames(&recorded, &out, format.into(), scale as usize)
+                }
+                None => fs::write(&out, animate::to_gif(&recorded, scale as usize, delay)),
+            };
+            if let Err(error) = written {
+                eprintln!("failed to write {}: {}", out.display(), error);
+                std::process::exit(2);
+            }
+            println!(
+                "Wrote {} frames of day {:02} to {}",
+                recorded.len(),
+                puzzle.day.number,
+                out.display()
+            );
+        }
+        Command::Cache { action, cache_dir } => {
+            let mut cache = match AnswerCache::open(&cache_dir) {
+                Ok(cache) => cache,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    std::process::exit(2);
+                }
+            };
+            match action {
+                CacheAction::List => cache::print_entries(&cache.entries()),
+                CacheAction::Clear { days } => {
+                    let removed =
+                        cache.clear(|day| days.days().any(|selected| selected.number == day));
+                    if let Err(error) = cache.save() {
+                        eprintln!("{}", error);
+                        std::process::exit(2);
+                    }
+                    println!("Removed {} cached answers for {}", removed, days);
+                }
+            }
+        }
+        Command::Verify {
+            days,
+            manifest,
+            inputs_dir,
+            jobs,
+        } => {
+            let manifest = match Manifest::load(&manifest) {
+                Ok(manifest) => manifest,
+                Err(error) => {
+                    eprintln!("{}", error);
+                    std::process::exit(2);
+                }
+            };
+
+            let days: Vec<&Day> = days.days().collect();
+            let report = jobs::run_all(&days, jobs.map(|jobs| jobs as usize), |day| {
+                verify::verify_day(day, &manifest, &inputs_dir)
+            });
+            let timings = report.to_string();
+
+            let checks: Vec<verify::Check> = report
+                .into_values()
+                .zip(&days)
+                .flat_map(|(checks, day)| {
+                    checks.unwrap_or_else(|message| verify::panicked(day, &message))
+                })
+                .collect();
+            verify::print_report(&checks);
+            println!("{}", timings);
+            if checks
+                .iter()
+                .any(|check| check.status == verify::Status::Fail)
+            {
+                std::process::exit(1);
+            }
+        }
+        Command::Serve { port, config } => {
+            let config = load_config(config.as_deref());
+            let listener = match serve::bind(port) {
+                Ok(listener) => listener,
+                Err(error) => {
+                    eprintln!("failed to listen on port {}: {}", port, error);
+                    std::process::exit(2);
+                }
+            };
+            if let Ok(address) = listener.local_addr() {
+                eprintln!("Listening on http://{}/day/{{n}}/part/{{p}}", address);
+            }
+            serve::serve(listener, &config);
+        }
+    }
+}
+
+/// Exits when `--input` or `--param` is used with more than one day.
+fn check_single_day(days: &DaySelection, has_input: bool, has_params: bool) {
+    for (used, flag) in [(has_input, "--input"), (has_params, "--param")] {
+        if used && !days.is_single_day() {
+            eprintln!("{} can only be used when running a single day", flag);
+            std::process::exit(2);
+        }
+    }
+}
+
+fn load_config(path: Option<&Path>) -> Config {
+    match Config::load(path) {
+        Ok(config) => config,
+        Err(error) => {
+            eprintln!("{}", error);
+            std::process::exit(2);
+        }
+    }
+}
+
+/// `--input`, or the day's [`Day::input_file`] in `<inputs-dir>/dayNN` by default.
+fn input_source(day: &Day, input: Option<InputSource>, inputs_dir: &Path) -> InputSource {
+    input.unwrap_or_else(|| InputSource::Path(day.input_path(inputs_dir, day.input_file())))
+}
+
+/// The day of a single-day command, e.g. `render`, with its parameters from the config and
+/// `--param`.
+struct Puzzle {
+    day: &'static Day,
+    params: ParamTable,
+}
+
+impl Puzzle {
+    /// Exits unless `selection` is a single day; `usage` says what the command does with one,
+    /// e.g. "render draws".
+    fn select(
+        selection: &DaySelection,
+        usage: &str,
+        config: Option<&Path>,
+        overrides: &[ParamOverride],
+    ) -> Puzzle {
+        if !selection.is_single_day() {
+            eprintln!("{} a single day", usage);
+            std::process::exit(2);
+        }
+        let day = days::find(selection.first).expect("the selection only contains solved days");
+        Puzzle {
+            day,
+            params: load_config(config).params(day.number, overrides),
+        }
+    }
+
+    /// Reads the puzzle input, exiting when it cannot be read.
+    fn read_input(&self, input: Option<InputSource>, inputs_dir: &Path) -> String {
+        let input = input_source(self.day, input, inputs_dir).read();
+        self.or_exit(input.map_err(SolveError::from))
+    }
+
+    /// Exits when the parameters do not apply to the day.
+    fn generate(&self, size: Option<usize>, seed: u64) -> String {
+        self.or_exit((self.day.generate)(size, &self.params, seed))
+    }
+
+    /// The value of `result`, exiting with its error otherwise.
+    fn or_exit<T>(&self, result: Result<T, SolveError>) -> T {
+        result.unwrap_or_else(|error| {
+            eprintln!("Day {:02}: {}", self.day.number, error);
+            std::process::exit(2);
+        })
+    }
+}
+
+/// The parts selected by `--part`, both by default.
+fn parts(part: Option<u8>) -> &'static [Part] {
+    match part {
+        Some(1) => &[Part::One],
+        Some(_) => &[Part::Two],
+        None => &Part::BOTH,
+    }
+}
+
+/// The outcome of one day of `aoc run`, printed once every day is done so that days run in
+/// parallel are still reported in order.
+#[derive(Default)]
+struct DayRun {
+    lines: Vec<String>,
+    /// Answers that were computed, to store in the cache.
+    solved: Vec<(CacheKey, String)>,
+    /// How many answers came from the cache.
+    cached: usize,
+}
+
+/// Solves the parts of one day that are not in `cache`.
+fn run_day(
+    day: &Day,
+    part: Option<u8>,
+    source: &InputSource,
+    params: &ParamTable,
+    cache: Option<&AnswerCache>,
+) -> DayRun {
+    let mut run = DayRun::default();
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(InputError::NotFound(path)) => {
+            run.lines.push(format!(
+                "Day {:02}: skipped, input not found at {}",
+                day.number,
+                path.display()
+            ));
+            return run;
+        }
+        Err(error) => {
+            run.lines.push(format!("Day {:02}: {}", day.number, error));
+            return run;
+        }
+    };
+
+    let parts: Vec<(Part, CacheKey, Option<String>)> = parts(part)
+        .iter()
+        .map(|&part| {
+            let key = CacheKey::new(day.number, part, &input, params);
+            let cached = cache.and_then(|cache| cache.get(&key)).map(str::to_string);
+            (part, key, cached)
+        })
+        .collect();
+    let unsolved: Vec<Part> = parts
+        .iter()
+        .filter(|(_, _, cached)| cached.is_none())
+        .map(|&(part, _, _)| part)
+        .collect();
+
+    let mut solved = if unsolved.is_empty() {
+        Vec::new()
+    } else {
+        match (day.solve)(&input, &unsolved, params) {
+            Ok(answers) => answers,
+            Err(error) => {
+                run.lines.push(format!("Day {:02}: {}", day.number, error));
+                return run;
+            }
+        }
+    }
+    .into_iter();
+
+    for (part, key, cached) in parts {
+        let answer = match cached {
+            Some(answer) => {
+                run.cached += 1;
+                answer
+            }
+            None => match solved.next().expect("one answer per unsolved part") {
+                (_, Ok(answer)) => {
+                    let answer = answer.to_string();
+                    if cache.is_some() {
+                        run.solved.push((key, answer.clone()));
+                    }
+                    answer
+                }
+                (_, Err(error)) => error.to_string(),
+            },
+        };
+        run.lines
+            .push(format!("Day {:02} - {}: {}", day.number, part, answer));
+    }
+    run
+}
+
+/// Benchmarks one day, reporting on stderr why it was skipped so the report stays parseable.
+fn bench_day(
+    day: &Day,
+    source: &InputSource,
+    params: &ParamTable,
+    options: &BenchOptions,
+) -> Option<bench::DayReport> {
+    let input = match source.read() {
+        Ok(input) => input,
+        Err(InputError::NotFound(path)) => {
+            eprintln!(
+                "Day {:02}: skipped, input not found at {}",
+                day.number,
+                path.display()
+            );
+            return None;
+        }
+        Err(error) => {
+            eprintln!("Day {:02}: {}", day.number, error);
+            return None;
+        }
+    };
+
+    match (day.bench)(&input, params, options) {
+        Ok(samples) => Some(samples.report(day.number)),
+        Err(error) => {
+            eprintln!("Day {:02}: {}", day.number, error);
+            None
+        }
+    }
+}
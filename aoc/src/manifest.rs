@@ -0,0 +1,182 @@
+use std::{collections::BTreeMap, error, fmt, fs, io, path::Path, str::FromStr};
+
+use common::Part;
+use serde::Deserialize;
+
+/// The known answers, keyed by day and input file name, as read from `answers.toml`:
+///
+/// ```toml
+/// [day06."challenge.txt"]
+/// part1 = 4903
+/// part2 = 2165
+/// ```
+#[derive(Debug, Default)]
+pub struct Manifest {
+    days: BTreeMap<u8, BTreeMap<String, ExpectedAnswers>>,
+}
+
+/// The expected answers for one input; a part without an entry is not checked.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ExpectedAnswers {
+    part1: Option<ExpectedAnswer>,
+    part2: Option<ExpectedAnswer>,
+}
+
+/// An answer as written in the manifest: an integer, or a string for text, coordinate and
+/// answers that do not fit in a TOML integer.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ExpectedAnswer {
+    Integer(i64),
+    Text(String),
+}
+
+#[derive(Debug)]
+pub enum ManifestError {
+    Io { path: String, error: io::Error },
+    Parse(String),
+    UnknownDay(String),
+}
+
+impl Manifest {
+    pub fn load(path: &Path) -> Result<Manifest, ManifestError> {
+        let contents = fs::read_to_string(path).map_err(|error| ManifestError::Io {
+            path: path.display().to_string(),
+            error,
+        })?;
+        contents.parse()
+    }
+
+    /// The input files with expected answers for `day`, in file name order.
+    pub fn inputs(&self, day: u8) -> impl Iterator<Item = (&str, &ExpectedAnswers)> {
+        self.days
+            .get(&day)
+            .into_iter()
+            .flatten()
+            .map(|(file_name, answers)| (file_name.as_str(), answers))
+    }
+
+    /// Whether any input of `day` has an expected answer for `part`.
+    pub fn has_answer(&self, day: u8, part: Part) -> bool {
+        self.inputs(day)
+            .any(|(_, answers)| answers.get(part).is_some())
+    }
+}
+
+impl FromStr for Manifest {
+    type Err = ManifestError;
+
+    fn from_str(contents: &str) -> Result<Self, Self::Err> {
+        let tables: BTreeMap<String, BTreeMap<String, ExpectedAnswers>> =
+            toml::from_str(contents).map_err(|error| ManifestError::Parse(error.to_string()))?;
+
+        let mut days = BTreeMap::new();
+        for (key, inputs) in tables {
+            let day = crate::days::parse_day_key(&key).ok_or(ManifestError::UnknownDay(key))?;
+            days.insert(day, inputs);
+        }
+
+        Ok(Manifest { days })
+    }
+}
+
+impl ExpectedAnswers {
+    pub fn get(&self, part: Part) -> Option<&ExpectedAnswer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+impl fmt::Display for ExpectedAnswer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpectedAnswer::Integer(value) => write!(f, "{}", value),
+            ExpectedAnswer::Text(value) => write!(f, "{}", value),
+        }
+    }
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Io { path, error } => write!(f, "failed to read {}: {}", path, error),
+            ManifestError::Parse(message) => write!(f, "invalid manifest: {}", message),
+            ManifestError::UnknownDay(key) => {
+                write!(f, "invalid manifest: '{}' is not a solved day", key)
+            }
+        }
+    }
+}
+
+impl error::Error for ManifestError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            ManifestError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MANIFEST: &str = r#"
+        [day06."challenge.txt"]
+        part2 = 2165
+
+        [day17."test.txt"]
+        part1 = "4,6,3,5,6,3,5,2,1,0"
+        part2 = 117440
+    "#;
+
+    #[test]
+    fn test_parse_manifest() {
+        let manifest = MANIFEST.parse::<Manifest>().unwrap();
+
+        let inputs: Vec<&str> = manifest.inputs(6).map(|(file_name, _)| file_name).collect();
+        assert_eq!(inputs, vec!["challenge.txt"]);
+        assert!(manifest.has_answer(6, Part::Two));
+        assert!(!manifest.has_answer(6, Part::One));
+        assert!(manifest.has_answer(17, Part::One));
+        assert_eq!(manifest.inputs(1).count(), 0);
+    }
+
+    #[test]
+    fn test_expected_answers() {
+        let manifest = MANIFEST.parse::<Manifest>().unwrap();
+        let (_, answers) = manifest.inputs(17).next().unwrap();
+
+        assert_eq!(
+            answers.get(Part::One),
+            Some(&ExpectedAnswer::Text("4,6,3,5,6,3,5,2,1,0".to_string()))
+        );
+        assert_eq!(
+            answers.get(Part::Two),
+            Some(&ExpectedAnswer::Integer(117440))
+        );
+        assert_eq!(ExpectedAnswer::Integer(117440).to_string(), "117440");
+    }
+
+    #[test]
+    fn test_reject_unknown_day() {
+        let error = "[day30.\"test.txt\"]\npart1 = 1\n"
+            .parse::<Manifest>()
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid manifest: 'day30' is not a solved day"
+        );
+    }
+
+    #[test]
+    fn test_reject_unknown_part() {
+        let error = "[day01.\"test.txt\"]\npart3 = 1\n"
+            .parse::<Manifest>()
+            .unwrap_err();
+        assert!(matches!(error, ManifestError::Parse(_)));
+    }
+}
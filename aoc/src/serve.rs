@@ -0,0 +1,599 @@
+use std::{
+    io::{self, BufRead, BufReader, Read, Write},
+    net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream},
+    panic::{self, AssertUnwindSafe},
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+    time::{Duration, Instant},
+};
+
+use common::{ParamTable, Part};
+use serde::Serialize;
+
+use crate::{
+    bench::as_nanos,
+    config::{Config, ParamOverride},
+    days::{self, Day},
+};
+
+/// The largest puzzle input accepted, well above any real one.
+pub const MAX_BODY_BYTES: usize = 16 * 1024 * 1024;
+
+/// The most bytes accepted for the request line and the headers together.
+const MAX_HEAD_BYTES: u64 = 16 * 1024;
+
+/// The most headers accepted in one request.
+const MAX_HEADERS: usize = 100;
+
+/// The most connections answered at once; the server is busy for any beyond them.
+const MAX_CONNECTIONS: usize = 64;
+
+/// How long a client may take to send its whole request, head and body.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How long writing a response may stall before the connection is dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The outcome of `POST /day/{n}/part/{p}`.
+#[derive(Debug, Serialize)]
+pub struct SolveResponse {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    /// Parsing the input and solving the part.
+    #[serde(rename = "duration_ns", serialize_with = "as_nanos")]
+    pub duration: Duration,
+    /// Why the input could not be parsed or solved.
+    pub error: Option<String>,
+}
+
+/// A request the server could not route, e.g. to an unknown day.
+#[derive(Debug, Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// A status code and a JSON body.
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+impl Response {
+    fn json(status: u16, body: &impl Serialize) -> Response {
+        Response {
+            status,
+            body: serde_json::to_string(body).expect("responses only contain plain data"),
+        }
+    }
+
+    fn error(status: u16, error: impl Into<String>) -> Response {
+        Response::json(
+            status,
+            &ErrorResponse {
+                error: error.into(),
+            },
+        )
+    }
+
+    fn write_to(&self, stream: &mut impl Write) -> io::Result<()> {
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            self.status,
+            reason(self.status),
+            self.body.len(),
+            self.body
+        )?;
+        stream.flush()
+    }
+}
+
+/// Binds to `port` on the loopback interface only, so that the solvers are never reachable from
+/// other machines; port 0 picks a free one.
+pub fn bind(port: u16) -> io::Result<TcpListener> {
+    TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+}
+
+/// Answers the connections to `listener`, each on its own thread, until the process is stopped.
+/// Beyond `MAX_CONNECTIONS` at once, connections are answered 503 straight away.
+pub fn serve(listener: TcpListener, config: &Config) {
+    let open = AtomicUsize::new(0);
+    thread::scope(|scope| {
+        for stream in listener.incoming() {
+            let mut stream = match stream {
+                Ok(stream) => stream,
+                Err(error) => {
+                    eprintln!("failed to accept a connection: {}", error);
+                    continue;
+                }
+            };
+            let Some(permit) = Permit::take(&open, MAX_CONNECTIONS) else {
+                let busy = Response::error(503, "the server is busy, try again later");
+                let written = stream
+                    .set_write_timeout(Some(WRITE_TIMEOUT))
+                    .and_then(|()| busy.write_to(&mut stream));
+                if let Err(error) = written {
+                    eprintln!("connection dropped: {}", error);
+                }
+                continue;
+            };
+            scope.spawn(move || {
+                let _permit = permit;
+                if let Err(error) = handle_connection(stream, config) {
+                    eprintln!("connection dropped: {}", error);
+                }
+            });
+        }
+    });
+}
+
+/// One of a limited number of connections being answered, given back when dropped.
+struct Permit<'a> {
+    open: &'a AtomicUsize,
+}
+
+impl<'a> Permit<'a> {
+    /// A permit, unless `limit` connections are already open.
+    fn take(open: &'a AtomicUsize, limit: usize) -> Option<Permit<'a>> {
+        open.fetch_update(Ordering::AcqRel, Ordering::Acquire, |count| {
+            (count < limit).then_some(count + 1)
+        })
+        .ok()
+        .map(|_| Permit { open })
+    }
+}
+
+impl Drop for Permit<'_> {
+    fn drop(&mut self) {
+        self.open.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+/// Reads one request from `stream` and writes its response; the connection is then closed.
+pub fn handle_connection(mut stream: TcpStream, config: &Config) -> io::Result<()> {
+    stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+    let reader = Deadline {
+        stream: &stream,
+        deadline: Instant::now() + REQUEST_TIMEOUT,
+    };
+    let response = match read_request(&mut BufReader::new(reader)) {
+        Ok(request) => {
+            let response = route(&request.method, &request.target, &request.body, config);
+            eprintln!("{} {} {}", request.method, request.target, response.status);
+            response
+        }
+        Err(response) => response,
+    };
+    response.write_to(&mut stream)
+}
+
+/// Reads from `stream` until `deadline` at the latest, however the bytes trickle in, so a slow
+/// client cannot hold a connection open longer than that.
+struct Deadline<'a> {
+    stream: &'a TcpStream,
+    deadline: Instant,
+}
+
+impl Read for Deadline<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let remaining = self.deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            return Err(io::ErrorKind::TimedOut.into());
+        }
+        self.stream.set_read_timeout(Some(remaining))?;
+        self.stream.read(buf)
+    }
+}
+
+struct Request {
+    method: String,
+    target: String,
+    body: Vec<u8>,
+}
+
+/// The request line, the headers up to the blank line, and a body of `Content-Length` bytes.
+/// The request line and headers may take `MAX_HEAD_BYTES` at most, with `MAX_HEADERS` headers.
+fn read_request(reader: &mut impl BufRead) -> Result<Request, Response> {
+    let bad_request = |error: io::Error| match error.kind() {
+        io::ErrorKind::TimedOut | io::ErrorKind::WouldBlock => {
+            Response::error(408, "the request did not arrive in time")
+        }
+        _ => Response::error(400, error.to_string()),
+    };
+    let too_large = || {
+        Response::error(
+            431,
+            format!(
+                "the request line and headers are longer than {} bytes or {} headers",
+                MAX_HEAD_BYTES, MAX_HEADERS
+            ),
+        )
+    };
+
+    let mut head = reader.by_ref().take(MAX_HEAD_BYTES);
+    let mut request_line = String::new();
+    head.read_line(&mut request_line).map_err(bad_request)?;
+    if !request_line.ends_with('\n') && head.limit() == 0 {
+        return Err(too_large());
+    }
+    let mut words = request_line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (words.next(), words.next(), words.next())
+    else {
+        return Err(Response::error(400, "malformed request line"));
+    };
+
+    let mut content_length = None;
+    let mut headers = 0;
+    loop {
+        let mut header = String::new();
+        if head.read_line(&mut header).map_err(bad_request)? == 0 {
+            if head.limit() == 0 {
+                return Err(too_large());
+            }
+            return Err(Response::error(400, "the headers end early"));
+        }
+        if !header.ends_with('\n') && head.limit() == 0 {
+            return Err(too_large());
+        }
+        let header = header.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        headers += 1;
+        if headers > MAX_HEADERS {
+            return Err(too_large());
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(Response::error(
+                400,
+                format!("malformed header '{}'", header),
+            ));
+        };
+        if name.trim().eq_ignore_ascii_case("content-length") {
+            let length = value.trim().parse::<usize>().map_err(|_| {
+                Response::error(400, format!("invalid Content-Length '{}'", value.trim()))
+            })?;
+            content_length = Some(length);
+        }
+    }
+
+    let body = match content_length {
+        Some(length) if length > MAX_BODY_BYTES => {
+            return Err(Response::error(
+                413,
+                format!("the input is larger than {} bytes", MAX_BODY_BYTES),
+            ))
+        }
+        Some(length) => {
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).map_err(bad_request)?;
+            body
+        }
+        None if method == "POST" => {
+            return Err(Response::error(411, "the request needs a Content-Length"))
+        }
+        None => Vec::new(),
+    };
+
+    Ok(Request {
+        method: method.to_string(),
+        target: target.to_string(),
+        body,
+    })
+}
+
+/// The response to `method` on `target`, e.g. `POST /day/7/part/2?width=11` with the puzzle
+/// input as `body`. The query overrides puzzle parameters on top of those in `config`.
+pub fn route(method: &str, target: &str, body: &[u8], config: &Config) -> Response {
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    let ["day", day, "part", part] = segments[..] else {
+        return Response::error(
+            404,
+            format!("no endpoint at {}; try POST /day/{{n}}/part/{{p}}", path),
+        );
+    };
+    if method != "POST" {
+        return Response::error(405, format!("{} solves with POST, not {}", path, method));
+    }
+
+    let Some(day) = day.parse().ok().and_then(days::find) else {
+        return Response::error(404, format!("'{}' is not a solved day", day));
+    };
+    let part = match part {
+        "1" => Part::One,
+        "2" => Part::Two,
+        _ => return Response::error(404, format!("'{}' is not a part, expected 1 or 2", part)),
+    };
+    let overrides = match query_params(query) {
+        Ok(overrides) => overrides,
+        Err(error) => return Response::error(400, error),
+    };
+    let Ok(input) = std::str::from_utf8(body) else {
+        return Response::error(400, "the input is not valid UTF-8");
+    };
+
+    let params = config.params(day.number, &overrides);
+    let Ok(response) = panic::catch_unwind(AssertUnwindSafe(|| solve(day, part, input, &params)))
+    else {
+        return Response::error(500, format!("the day {:02} solver panicked", day.number));
+    };
+    let status = if response.error.is_some() { 422 } else { 200 };
+    Response::json(status, &response)
+}
+
+/// Each `key=value` of the query string as a parameter override, with `%XX` escapes and `+`
+/// decoded in both the key and the value.
+fn query_params(query: &str) -> Result<Vec<ParamOverride>, String> {
+    query
+        .split('&')
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => {
+                format!("{}={}", percent_decode(key)?, percent_decode(value)?).parse()
+            }
+            None => percent_decode(pair)?.parse(),
+        })
+        .collect()
+}
+
+/// `text` with each `%XX` replaced by the byte it encodes and each `+` by a space.
+fn percent_decode(text: &str) -> Result<String, String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut rest = text.as_bytes();
+    while let Some((&byte, tail)) = rest.split_first() {
+        rest = tail;
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let escape = rest
+                    .get(..2)
+                    .and_then(|hex| std::str::from_utf8(hex).ok())
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or_else(|| format!("'{}' has an invalid % escape", text))?;
+                bytes.push(escape);
+                rest = &rest[2..];
+            }
+            _ => bytes.push(byte),
+        }
+    }
+    String::from_utf8(bytes).map_err(|_| format!("'{}' does not decode to UTF-8", text))
+}
+
+/// Parses `input` and solves `part` of `day`, timing both.
+pub fn solve(day: &Day, part: Part, input: &str, params: &ParamTable) -> SolveResponse {
+    let start = Instant::now();
+    let answer = (day.solve)(input, &[part], params).and_then(|mut answers| {
+        answers
+            .pop()
+            .map(|(_, answer)| answer)
+            .expect("the solver answers every requested part")
+    });
+    let duration = start.elapsed();
+
+    let (answer, error) = match answer {
+        Ok(answer) => (Some(answer.to_string()), None),
+        Err(error) => (None, Some(error.to_string())),
+    };
+    SolveResponse {
+        day: day.number,
+        part: part.number(),
+        answer,
+        duration,
+        error,
+    }
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        411 => "Length Required",
+        413 => "Payload Too Large",
+        422 => "Unprocessable Entity",
+        431 => "Request Header Fields Too Large",
+        500 => "Internal Server Error",
+        503 => "Service Unavailable",
+        _ => "Error",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    const DAY01: &str = "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n";
+
+    fn body(response: &Response) -> serde_json::Value {
+        serde_json::from_str(&response.body).unwrap()
+    }
+
+    #[test]
+    fn test_route_solves_a_part() {
+        let response = route(
+            "POST",
+            "/day/1/part/2",
+            DAY01.as_bytes(),
+            &Config::default(),
+        );
+
+        assert_eq!(response.status, 200);
+        let body = body(&response);
+        assert_eq!(body["day"], 1);
+        assert_eq!(body["part"], 2);
+        assert_eq!(body["answer"], "31");
+        assert!(body["duration_ns"].is_u64());
+        assert!(body["error"].is_null());
+    }
+
+    #[test]
+    fn test_route_reports_parse_errors() {
+        let response = route(
+            "POST",
+            "/day/1/part/1",
+            b"1   2\nthree   4\n",
+            &Config::default(),
+        );
+
+        assert_eq!(response.status, 422);
+        let body = body(&response);
+        assert!(body["answer"].is_null());
+        assert_eq!(
+            body["error"],
+            "line 2, column 1: 'three' is not a valid i32\n    three   4\n    ^"
+        );
+    }
+
+    #[test]
+    fn test_route_applies_query_params() {
+        let example = days::find(14).unwrap().examples[0].input;
+        let config: Config = "[day14]\nwidth = 101\n".parse().unwrap();
+
+        let response = route(
+            "POST",
+            "/day/14/part/1?width=11&height=7",
+            example.as_bytes(),
+            &config,
+        );
+        assert_eq!(body(&response)["answer"], "12");
+
+        let response = route("POST", "/day/14/part/1?width", example.as_bytes(), &config);
+        assert_eq!(response.status, 400);
+    }
+
+    #[test]
+    fn test_query_values_are_percent_decoded() {
+        let overrides = query_params("width=1%31&na%6De=a+b%2Fc").unwrap();
+        assert_eq!(overrides[0].key, "width");
+        assert_eq!(overrides[0].value, toml::Value::Integer(11));
+        assert_eq!(overrides[1].key, "name");
+        assert_eq!(overrides[1].value, toml::Value::String("a b/c".to_string()));
+
+        assert!(query_params("width=%1").is_err());
+        assert!(query_params("width=%zz").is_err());
+        assert!(query_params("width=%ff").is_err());
+    }
+
+    #[test]
+    fn test_route_rejects_unknown_endpoints() {
+        let config = Config::default();
+        let status = |method, target| route(method, target, b"", &config).status;
+
+        assert_eq!(status("POST", "/day/26/part/1"), 404);
+        assert_eq!(status("POST", "/day/1/part/3"), 404);
+        assert_eq!(status("POST", "/days"), 404);
+        assert_eq!(status("GET", "/day/1/part/1"), 405);
+        assert_eq!(route("POST", "/day/1/part/1", &[0xff], &config).status, 400);
+    }
+
+    #[test]
+    fn test_serves_over_http() {
+        let listener = bind(0).unwrap();
+        let address = listener.local_addr().unwrap();
+        assert!(address.ip().is_loopback());
+
+        let client = thread::spawn(move || {
+            let mut stream = TcpStream::connect(address).unwrap();
+            write!(
+                stream,
+                "POST /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\nContent-Length: {}\r\n\r\n{}",
+                DAY01.len(),
+                DAY01
+            )
+            .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        });
+        let (stream, _) = listener.accept().unwrap();
+        handle_connection(stream, &Config::default()).unwrap();
+
+        let response = client.join().unwrap();
+        let (head, body) = response.split_once("\r\n\r\n").unwrap();
+        assert!(head.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(head.contains("Content-Type: application/json"));
+        let body: serde_json::Value = serde_json::from_str(body).unwrap();
+        assert_eq!(body["answer"], "11");
+    }
+
+    #[test]
+    fn test_post_needs_a_content_length() {
+        let request = "POST /day/1/part/1 HTTP/1.1\r\nHost: localhost\r\n\r\n3   4\n";
+        let Err(response) = read_request(&mut request.as_bytes()) else {
+            panic!("the request was accepted");
+        };
+        assert_eq!(response.status, 411);
+    }
+
+    #[test]
+    fn test_the_head_is_bounded() {
+        let long_line = format!(
+            "GET /{} HTTP/1.1\r\n\r\n",
+            "a".repeat(MAX_HEAD_BYTES as usize)
+        );
+        let many_headers = format!(
+            "GET / HTTP/1.1\r\n{}\r\n",
+            "X-Header: 1\r\n".repeat(MAX_HEADERS + 1)
+        );
+        let endless_header = format!("GET / HTTP/1.1\r\nX-Header: {}", "1".repeat(20_000));
+
+        for request in [long_line, many_headers, endless_header] {
+            let Err(response) = read_request(&mut request.as_bytes()) else {
+                panic!("the request was accepted");
+            };
+            assert_eq!(response.status, 431);
+        }
+    }
+
+    #[test]
+    fn test_the_whole_request_has_a_deadline() {
+        let listener = bind(0).unwrap();
+        let address = listener.local_addr().unwrap();
+        let client = thread::spawn(move || {
+            let mut stream = TcpStream::connect(address).unwrap();
+            stream.write_all(b"GET / HTTP/1.1\r\nX-Header: ").unwrap();
+            // Each byte comes well within a read timeout, but the head is never finished.
+            for _ in 0..100 {
+                if stream.write_all(b"1").is_err() {
+                    break;
+                }
+                thread::sleep(Duration::from_millis(20));
+            }
+        });
+        let (stream, _) = listener.accept().unwrap();
+
+        let start = Instant::now();
+        let reader = Deadline {
+            stream: &stream,
+            deadline: start + Duration::from_millis(200),
+        };
+        let Err(response) = read_request(&mut BufReader::new(reader)) else {
+            panic!("the request was accepted");
+        };
+        assert_eq!(response.status, 408);
+        assert!(start.elapsed() < Duration::from_secs(1));
+
+        drop(stream);
+        client.join().unwrap();
+    }
+
+    #[test]
+    fn test_permits_are_limited_and_given_back() {
+        let open = AtomicUsize::new(0);
+        let first = Permit::take(&open, 2).unwrap();
+        let second = Permit::take(&open, 2).unwrap();
+        assert!(Permit::take(&open, 2).is_none());
+
+        drop(first);
+        assert!(Permit::take(&open, 2).is_some());
+        drop(second);
+        assert_eq!(open.load(Ordering::Acquire), 0);
+    }
+}
@@ -0,0 +1,239 @@
+use std::{fmt, path::Path};
+
+use common::{params, InputError, InputSource, Part};
+
+use crate::{days::Day, manifest::Manifest};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// There is no expected answer for the part, or the input it was recorded for is absent.
+    Missing,
+}
+
+/// The outcome of checking one part of one day against an example or the manifest.
+#[derive(Debug)]
+pub struct Check {
+    pub day: u8,
+    pub part: Part,
+    pub input: String,
+    pub status: Status,
+    pub details: String,
+}
+
+/// Solves the embedded examples of `day` and every input the manifest lists for it, and compares
+/// the answers.
+pub fn verify_day(day: &Day, manifest: &Manifest, inputs_dir: &Path) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    for example in day.examples {
+        let expected: Vec<(Part, String)> = Part::BOTH
+            .into_iter()
+            .filter_map(|part| Some((part, example.expected(part)?.to_string())))
+            .collect();
+        let label = format!("example: {}", example.name);
+        check_input(
+            day,
+            &label,
+            Ok(example.input.to_string()),
+            example.params.unwrap_or_default(),
+            &expected,
+            &mut checks,
+        );
+    }
+
+    for (file_name, answers) in manifest.inputs(day.number) {
+        let expected: Vec<(Part, String)> = Part::BOTH
+            .into_iter()
+            .filter_map(|part| Some((part, answers.get(part)?.to_string())))
+            .collect();
+        let input = InputSource::Path(day.input_path(inputs_dir, file_name)).read();
+        check_input(day, file_name, input, "", &expected, &mut checks);
+    }
+
+    for part in Part::BOTH {
+        let has_example = day
+            .examples
+            .iter()
+            .any(|example| example.expected(part).is_some());
+        if !has_example && !manifest.has_answer(day.number, part) {
+            checks.push(Check {
+                day: day.number,
+                part,
+                input: "-".to_string(),
+                status: Status::Missing,
+                details: "no expected answer".to_string(),
+            });
+        }
+    }
+
+    checks
+}
+
+/// A failed check of each part of `day`, whose verification panicked with `message`.
+pub fn panicked(day: &Day, message: &str) -> Vec<Check> {
+    Part::BOTH
+        .into_iter()
+        .map(|part| Check {
+            day: day.number,
+            part,
+            input: "-".to_string(),
+            status: Status::Fail,
+            details: format!("panicked: {}", message),
+        })
+        .collect()
+}
+
+/// Solves the parts of `input` that have an expected answer, with the parameters given as TOML in
+/// `params`, and records one check per part.
+fn check_input(
+    day: &Day,
+    label: &str,
+    input: Result<String, InputError>,
+    params: &str,
+    expected: &[(Part, String)],
+    checks: &mut Vec<Check>,
+) {
+    let mut check = |part: Part, status: Status, details: String| {
+        checks.push(Check {
+            day: day.number,
+            part,
+            input: label.to_string(),
+            status,
+            details,
+        })
+    };
+    let parts: Vec<Part> = expected.iter().map(|&(part, _)| part).collect();
+    if parts.is_empty() {
+        return;
+    }
+
+    let input = match input {
+        Ok(input) => input,
+        Err(error) => {
+            let status = match error {
+                InputError::NotFound(_) => Status::Missing,
+                _ => Status::Fail,
+            };
+            for &part in &parts {
+                check(part, status, error.to_string());
+            }
+            return;
+        }
+    };
+
+    let answers =
+        match params::parse_table(params).and_then(|params| (day.solve)(&input, &parts, &params)) {
+            Ok(answers) => answers,
+            Err(error) => {
+                for &part in &parts {
+                    check(part, Status::Fail, error.to_string());
+                }
+                return;
+            }
+        };
+
+    for ((part, answer), (_, expected)) in answers.into_iter().zip(expected) {
+        match answer.map(|answer| answer.to_string()) {
+            Ok(answer) if answer == *expected => check(part, Status::Pass, answer),
+            Ok(answer) => check(
+                part,
+                Status::Fail,
+                format!("expected {}, got {}", expected, answer),
+            ),
+            Err(error) => check(part, Status::Fail, error.to_string()),
+        }
+    }
+}
+
+/// Prints the checks as an aligned table followed by a pass/fail/missing summary.
+pub fn print_report(checks: &[Check]) {
+    let input_width = checks
+        .iter()
+        .map(|check| check.input.len())
+        .chain(["Input".len()])
+        .max()
+        .unwrap_or_default();
+
+    println!(
+        "{:<3}  {:<6}  {:<input_width$}  {:<7}  Details",
+        "Day", "Part", "Input", "Status"
+    );
+    for check in checks {
+        println!(
+            "{:<3}  {:<6}  {:<input_width$}  {:<7}  {}",
+            format!("{:02}", check.day),
+            check.part.to_string(),
+            check.input,
+            check.status.to_string(),
+            check.details
+        );
+    }
+
+    let count = |status: Status| checks.iter().filter(|check| check.status == status).count();
+    println!();
+    println!(
+        "{} passed, {} failed, {} missing",
+        count(Status::Pass),
+        count(Status::Fail),
+        count(Status::Missing)
+    );
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Pass => write!(f, "pass"),
+            Status::Fail => write!(f, "FAIL"),
+            Status::Missing => write!(f, "missing"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_verify_day() {
+        let inputs_dir = std::env::temp_dir().join(format!("aoc-verify-{}", std::process::id()));
+        fs::create_dir_all(inputs_dir.join("day01")).unwrap();
+        fs::write(
+            inputs_dir.join("day01").join("example.txt"),
+            "3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n",
+        )
+        .unwrap();
+        let manifest = r#"
+            [day01."example.txt"]
+            part1 = 11
+            part2 = 30
+
+            [day01."absent.txt"]
+            part1 = 1
+        "#
+        .parse::<Manifest>()
+        .unwrap();
+
+        let checks = verify_day(crate::days::find(1).unwrap(), &manifest, &inputs_dir);
+        fs::remove_dir_all(&inputs_dir).unwrap();
+
+        let outcomes: Vec<(Part, &str, Status)> = checks
+            .iter()
+            .map(|check| (check.part, check.input.as_str(), check.status))
+            .collect();
+        assert_eq!(
+            outcomes,
+            vec![
+                (Part::One, "example: lists", Status::Pass),
+                (Part::Two, "example: lists", Status::Pass),
+                (Part::One, "absent.txt", Status::Missing),
+                (Part::One, "example.txt", Status::Pass),
+                (Part::Two, "example.txt", Status::Fail),
+            ]
+        );
+        assert_eq!(checks[4].details, "expected 30, got 31");
+    }
+}
@@ -0,0 +1,425 @@
+//! Recording simulations step by step: a day steps through its simulation and hands each state
+//! to a [`Recorder`], which keeps every `stride`-th one as a frame until it holds `max_frames`.
+//! The frames are written as an animated GIF or as a directory of numbered images.
+
+use std::{collections::HashMap, fs, io, path::Path};
+
+use crate::{
+    image::{ImageFormat, BACKGROUND},
+    params,
+    render::Color,
+    Grid, Image, ParamTable, Solution, SolveError,
+};
+
+/// A day whose solution is a simulation that can be recorded one step at a time.
+pub trait Animate: Solution {
+    /// Steps through the simulation of `input`, passing every state to `recorder`. Simulations
+    /// that may run for long should stop once [`Recorder::is_full`].
+    fn animate(input: &Self::Input, recorder: &mut Recorder) -> Result<(), SolveError>;
+}
+
+/// Parses `input` for `A`, with the defaults of `A::Params` overridden by `params`, and records
+/// its simulation within `limits`.
+pub fn animate<A: Animate>(
+    input: &str,
+    params: &ParamTable,
+    limits: FrameLimits,
+) -> Result<Vec<Image>, SolveError> {
+    let params: A::Params = params::from_table(params)?;
+    let mut recorder = Recorder::new(limits);
+    A::animate(&A::parse_with(input, &params)?, &mut recorder)?;
+    Ok(recorder.into_frames())
+}
+
+/// Which steps of a simulation become frames.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FrameLimits {
+    /// Keep every `stride`-th step, starting with the first.
+    pub stride: usize,
+    /// Stop recording after this many frames.
+    pub max_frames: usize,
+}
+
+impl Default for FrameLimits {
+    fn default() -> Self {
+        FrameLimits {
+            stride: 1,
+            max_frames: 500,
+        }
+    }
+}
+
+/// Collects the frames of a simulation within its [`FrameLimits`].
+#[derive(Debug)]
+pub struct Recorder {
+    limits: FrameLimits,
+    steps: usize,
+    frames: Vec<Image>,
+}
+
+impl Recorder {
+    pub fn new(limits: FrameLimits) -> Recorder {
+        Recorder {
+            limits: FrameLimits {
+                stride: limits.stride.max(1),
+                ..limits
+            },
+            steps: 0,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Counts one step of the simulation and, if it is one to keep, draws it with `frame`.
+    /// Skipped steps are never drawn.
+    pub fn record(&mut self, frame: impl FnOnce() -> Image) {
+        if self.steps.is_multiple_of(self.limits.stride) && !self.is_full() {
+            self.frames.push(frame());
+        }
+        self.steps += 1;
+    }
+
+    /// Whether `max_frames` frames have been recorded, after which further steps are ignored.
+    pub fn is_full(&self) -> bool {
+        self.frames.len() >= self.limits.max_frames
+    }
+
+    /// How many steps have been recorded, kept or not.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    pub fn frames(&self) -> &[Image] {
+        &self.frames
+    }
+
+    pub fn into_frames(self) -> Vec<Image> {
+        self.frames
+    }
+}
+
+/// Writes each of `frames` into `dir` as `frame-NNNN.<ext>` in `format`, creating the directory
+/// if needed.
+pub fn write_frames(
+    frames: &[Image],
+    dir: &Path,
+    format: ImageFormat,
+    scale: usize,
+) -> io::Result<()> {
+    fs::create_dir_all(dir)?;
+    for (index, frame) in frames.iter().enumerate() {
+        let path = dir.join(format!("frame-{:04}.{}", index, format.extension()));
+        fs::write(path, frame.encode(format, scale))?;
+    }
+    Ok(())
+}
+
+/// The most colors a GIF can hold; frames with more are drawn with the nearest of the first 256.
+const GIF_COLORS: usize = 256;
+
+/// An animated GIF of `frames`, each tile `scale` pixels wide, shown `delay` hundredths of a
+/// second each and looping forever. Frames smaller than the largest one are drawn at its top
+/// left, over [`BACKGROUND`].
+pub fn to_gif(frames: &[Image], scale: usize, delay: u16) -> Vec<u8> {
+    let pixels: Vec<Grid<Color>> = frames.iter().map(|frame| frame.rasterize(scale)).collect();
+    let width = pixels.iter().map(Grid::cols).max().unwrap_or(1).max(1);
+    let height = pixels.iter().map(Grid::rows).max().unwrap_or(1).max(1);
+
+    let mut palette = vec![BACKGROUND];
+    let mut indices: HashMap<Color, u8> = HashMap::from([(BACKGROUND, 0)]);
+    for frame in &pixels {
+        for (_, &color) in frame.iter() {
+            if palette.len() < GIF_COLORS && !indices.contains_key(&color) {
+                indices.insert(color, palette.len() as u8);
+                palette.push(color);
+            }
+        }
+    }
+    // The color table holds a power of two of at least two colors.
+    let table_bits = palette.len().next_power_of_two().trailing_zeros().max(1);
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend((width as u16).to_le_bytes());
+    gif.extend((height as u16).to_le_bytes());
+    // A global color table of 2^table_bits colors, with 8 bits per primary color.
+    gif.push(0xf0 | (table_bits as u8 - 1));
+    gif.extend([0, 0]);
+    for index in 0..1 << table_bits {
+        let color = palette.get(index).copied().unwrap_or(Color::BLACK);
+        gif.extend([color.r, color.g, color.b]);
+    }
+    // The NETSCAPE2.0 extension that makes the animation loop forever.
+    gif.extend(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    let code_size = table_bits.max(2) as u8;
+    for frame in &pixels {
+        // A graphic control extension with the delay, replacing the previous frame.
+        gif.extend([0x21, 0xf9, 0x04, 0x04]);
+        gif.extend(delay.to_le_bytes());
+        gif.extend([0, 0]);
+
+        gif.push(0x2c);
+        gif.extend([0, 0, 0, 0]);
+        gif.extend((width as u16).to_le_bytes());
+        gif.extend((height as u16).to_le_bytes());
+        gif.push(0);
+
+        let mut data = Vec::with_capacity(width * height);
+        for row in 0..height {
+            for col in 0..width {
+                let color = frame.get(row, col).copied().unwrap_or(BACKGROUND);
+                data.push(match indices.get(&color) {
+                    Some(&index) => index,
+                    None => nearest(&palette, color),
+                });
+            }
+        }
+        gif.push(code_size);
+        for block in lzw(&data, code_size).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend(block);
+        }
+        gif.push(0);
+    }
+    gif.push(0x3b);
+    gif
+}
+
+/// The index of the color of `palette` closest to `color`.
+fn nearest(palette: &[Color], color: Color) -> u8 {
+    let distance = |other: &Color| {
+        [(color.r, other.r), (color.g, other.g), (color.b, other.b)]
+            .iter()
+            .map(|&(a, b)| (i32::from(a) - i32::from(b)).pow(2))
+            .sum::<i32>()
+    };
+    (0..palette.len())
+        .min_by_key(|&index| distance(&palette[index]))
+        .unwrap_or(0) as u8
+}
+
+/// The GIF flavor of LZW: variable-width codes packed least significant bit first, starting
+/// one bit wider than `code_size` and growing up to 12 bits, with a clear code whenever the
+/// table is full.
+fn lzw(data: &[u8], code_size: u8) -> Vec<u8> {
+    let clear = 1u16 << code_size;
+    let end = clear + 1;
+    let mut out = BitWriter::default();
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next = end + 1;
+    let mut width = code_size + 1;
+    out.write(clear, width);
+
+    let Some((&first, rest)) = data.split_first() else {
+        out.write(end, width);
+        return out.finish();
+    };
+    let mut prefix = u16::from(first);
+    for &index in rest {
+        if let Some(&code) = table.get(&(prefix, index)) {
+            prefix = code;
+            continue;
+        }
+        out.write(prefix, width);
+        if next < 4096 {
+            table.insert((prefix, index), next);
+            next += 1;
+            // Decoders widen their codes once the next free code no longer fits.
+            if next > 1 << width && width < 12 {
+                width += 1;
+            }
+        } else {
+            out.write(clear, width);
+            table.clear();
+            next = end + 1;
+            width = code_size + 1;
+        }
+        prefix = u16::from(index);
+    }
+    out.write(prefix, width);
+    out.write(end, width);
+    out.finish()
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bits: u8,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u8) {
+        self.buffer |= u32::from(code) << self.bits;
+        self.bits += width;
+        while self.bits >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bits -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bits > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Decodes GIF LZW data the way decoders do, growing the code width as their table fills.
+    fn unlzw(bytes: &[u8], code_size: u8) -> Vec<u8> {
+        let clear = 1usize << code_size;
+        let end = clear + 1;
+        let (mut buffer, mut bits, mut position) = (0u32, 0u8, 0);
+        let mut width = code_size + 1;
+        let mut table: Vec<Vec<u8>> = Vec::new();
+        let mut previous: Option<Vec<u8>> = None;
+        let mut data = Vec::new();
+        loop {
+            while bits < width {
+                buffer |= u32::from(bytes[position]) << bits;
+                position += 1;
+                bits += 8;
+            }
+            let code = (buffer & ((1 << width) - 1)) as usize;
+            buffer >>= width;
+            bits -= width;
+
+            if code == clear {
+                table = (0..clear).map(|index| vec![index as u8]).collect();
+                table.extend([Vec::new(), Vec::new()]);
+                width = code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end {
+                return data;
+            }
+            let entry = match (table.get(code), &previous) {
+                (Some(entry), _) => entry.clone(),
+                (None, Some(previous)) => [previous.clone(), vec![previous[0]]].concat(),
+                (None, None) => panic!("code {} before any other", code),
+            };
+            data.extend(&entry);
+            if let Some(previous) = previous {
+                if table.len() < 4096 {
+                    table.push([previous, vec![entry[0]]].concat());
+                }
+            }
+            if table.len() + 1 > 1 << width && width < 12 {
+                width += 1;
+            }
+            previous = Some(entry);
+        }
+    }
+
+    /// The code size and LZW data of every image in `gif`, skipping the extensions.
+    fn images(gif: &[u8]) -> Vec<(u8, Vec<u8>)> {
+        let table_size = 3 << ((gif[10] & 0x07) + 1);
+        let mut rest = &gif[13 + table_size..];
+        let sub_blocks = |mut rest: &[u8]| {
+            let mut data = Vec::new();
+            while rest[0] != 0 {
+                let length = rest[0] as usize;
+                data.extend(&rest[1..1 + length]);
+                rest = &rest[1 + length..];
+            }
+            (data, rest.len() - 1)
+        };
+        let mut images = Vec::new();
+        loop {
+            let skip = match rest[0] {
+                0x21 => 2,
+                0x2c => 10,
+                0x3b => return images,
+                byte => panic!("unexpected block {:#x}", byte),
+            };
+            let block = &rest[skip..];
+            let (code_size, data) = if rest[0] == 0x2c {
+                (block[0], &block[1..])
+            } else {
+                (0, block)
+            };
+            let (bytes, remaining) = sub_blocks(data);
+            if rest[0] == 0x2c {
+                images.push((code_size, bytes));
+            }
+            rest = &rest[rest.len() - remaining..];
+        }
+    }
+
+    fn frame(color: Color) -> Image {
+        Image::new(Grid::new(2, 3, color))
+    }
+
+    #[test]
+    fn test_recorder_keeps_every_stride_step_up_to_the_limit() {
+        let mut recorder = Recorder::new(FrameLimits {
+            stride: 3,
+            max_frames: 3,
+        });
+        let mut drawn = Vec::new();
+        for step in 0..20 {
+            recorder.record(|| {
+                drawn.push(step);
+                frame(Color::RED)
+            });
+        }
+
+        assert_eq!(drawn, [0, 3, 6]);
+        assert!(recorder.is_full());
+        assert_eq!(recorder.steps(), 20);
+        assert_eq!(recorder.frames().len(), 3);
+    }
+
+    #[test]
+    fn test_lzw_round_trips() {
+        let data: Vec<u8> = (0..5000u32)
+            .map(|index| ((index * 7 + index / 13) % 5) as u8)
+            .collect();
+        assert_eq!(unlzw(&lzw(&data, 3), 3), data);
+
+        let uniform = vec![1; 70_000];
+        assert_eq!(unlzw(&lzw(&uniform, 2), 2), uniform);
+        assert_eq!(unlzw(&lzw(&[], 2), 2), Vec::<u8>::new());
+    }
+
+    #[test]
+    fn test_lzw_restarts_when_the_table_is_full() {
+        // Every pair of bytes is new for a long while, which fills the table quickly.
+        let data: Vec<u8> = (0..20_000u32)
+            .map(|index| (index.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect();
+        assert_eq!(unlzw(&lzw(&data, 8), 8), data);
+    }
+
+    #[test]
+    fn test_gif_holds_one_image_per_frame() {
+        let gif = to_gif(&[frame(Color::RED), frame(Color::BLUE)], 2, 10);
+
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], &[6, 0, 4, 0]);
+        // The background, red and blue, in a table of four colors.
+        assert_eq!(gif[10], 0xf1);
+        assert_eq!(
+            &gif[13..25],
+            &[16, 16, 24, 220, 50, 47, 50, 120, 220, 0, 0, 0]
+        );
+
+        let images = images(&gif);
+        assert_eq!(images.len(), 2);
+        assert_eq!(unlzw(&images[0].1, images[0].0), vec![1; 24]);
+        assert_eq!(unlzw(&images[1].1, images[1].0), vec![2; 24]);
+    }
+
+    #[test]
+    fn test_colors_beyond_the_table_use_the_nearest() {
+        let palette = [Color::BLACK, Color::WHITE, Color::RED];
+        assert_eq!(nearest(&palette, Color::rgb(250, 240, 245)), 1);
+        assert_eq!(nearest(&palette, Color::rgb(200, 10, 10)), 2);
+    }
+}
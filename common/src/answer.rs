@@ -0,0 +1,148 @@
+use std::fmt;
+
+/// The answer to one part of a puzzle.
+///
+/// Integers are stored in the narrowest variant that holds them, so an answer computed as a
+/// `usize` compares equal to the same number computed as an `i128`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i64),
+    BigInteger(i128),
+    Text(String),
+    /// An `x,y` position, such as day 18's first blocking byte.
+    Coordinate(i64, i64),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{}", value),
+            Answer::BigInteger(value) => write!(f, "{}", value),
+            Answer::Text(value) => write!(f, "{}", value),
+            Answer::Coordinate(x, y) => write!(f, "{},{}", x, y),
+        }
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(value: i128) -> Self {
+        match i64::try_from(value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::BigInteger(value),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(value: $integer) -> Self {
+                    Answer::from(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(i32, u32, i64, u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl From<(usize, usize)> for Answer {
+    fn from((x, y): (usize, usize)) -> Self {
+        Answer::Coordinate(x as i64, y as i64)
+    }
+}
+
+impl Answer {
+    fn as_integer(&self) -> Option<i128> {
+        match self {
+            Answer::Integer(value) => Some(*value as i128),
+            Answer::BigInteger(value) => Some(*value),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_partial_eq_integer {
+    ($($integer:ty),*) => {
+        $(
+            impl PartialEq<$integer> for Answer {
+                fn eq(&self, other: &$integer) -> bool {
+                    self.as_integer() == Some(*other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_partial_eq_integer!(i32, u32, i64, u64, isize, usize, i128);
+
+impl PartialEq<str> for Answer {
+    fn eq(&self, other: &str) -> bool {
+        matches!(self, Answer::Text(text) if text == other)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, other: &String) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<(usize, usize)> for Answer {
+    fn eq(&self, &(x, y): &(usize, usize)) -> bool {
+        *self == Answer::Coordinate(x as i64, y as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integers_are_normalized() {
+        assert_eq!(Answer::from(42usize), Answer::from(42i128));
+        assert_eq!(Answer::from(-7i32), Answer::Integer(-7));
+        assert_eq!(
+            Answer::from(i128::MAX),
+            Answer::BigInteger(170141183460469231731687303715884105727)
+        );
+        assert_eq!(Answer::from(u64::MAX), Answer::BigInteger(u64::MAX as i128));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(1930).to_string(), "1930");
+        assert_eq!(
+            Answer::from("4,6,3,5,6,3,5,2,1,0").to_string(),
+            "4,6,3,5,6,3,5,2,1,0"
+        );
+        assert_eq!(Answer::from((6, 1)).to_string(), "6,1");
+    }
+
+    #[test]
+    fn test_compare_with_plain_values() {
+        assert_eq!(Answer::from(11u64), 11);
+        assert_eq!(Answer::from("0,1,2"), "0,1,2");
+        assert_eq!(Answer::from((6, 1)), (6, 1));
+        assert_ne!(Answer::from(11), "11");
+    }
+}
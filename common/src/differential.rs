@@ -0,0 +1,208 @@
+//! Differential testing: an optimized solver is run next to an obviously correct, usually brute
+//! force, reference on generated inputs, and any disagreement is shrunk to a small input that
+//! still shows it.
+
+use std::fmt;
+
+use crate::{panics, Generator, Rng};
+
+/// Seeds each check generates an input from.
+const SEEDS: u64 = 16;
+
+/// A generated input on which the candidate and the reference disagree.
+#[derive(Debug)]
+pub struct Disagreement<T> {
+    pub seed: u64,
+    /// The input after shrinking, not the one generated from `seed`.
+    pub input: String,
+    /// What each side returned, or the message it panicked with.
+    pub reference: Result<T, String>,
+    pub candidate: Result<T, String>,
+}
+
+/// Checks on inputs of up to `size` generated from several seeds that `candidate` returns what
+/// `reference` does, and panics with the smallest disagreeing input that shrinking finds.
+pub fn check_agreement<G, T>(
+    size: usize,
+    reference: impl Fn(&G::Input) -> T,
+    candidate: impl Fn(&G::Input) -> T,
+) where
+    G: Generator,
+    T: PartialEq + fmt::Debug,
+{
+    if let Some(disagreement) = find_disagreement::<G, T>(size, &reference, &candidate) {
+        panic!(
+            "the candidate disagrees with the reference on seed {}, shrunk to:\n{}\n\
+             reference: {:?}\ncandidate: {:?}",
+            disagreement.seed, disagreement.input, disagreement.reference, disagreement.candidate
+        );
+    }
+}
+
+/// The first seed whose input of `size` makes the two sides disagree, with that input shrunk:
+/// first to the smallest generated size that still disagrees, then by dropping runs of lines and
+/// comma-separated items for as long as the input still parses and the sides still disagree.
+pub fn find_disagreement<G, T>(
+    size: usize,
+    reference: &impl Fn(&G::Input) -> T,
+    candidate: &impl Fn(&G::Input) -> T,
+) -> Option<Disagreement<T>>
+where
+    G: Generator,
+    T: PartialEq,
+{
+    let params = G::Params::default();
+    let generate = |size, seed| G::generate(size, &params, &mut Rng::seed(seed));
+    let outcomes = |input: &str| {
+        let parsed = G::parse_with(input, &params).ok()?;
+        // A panic counts as a disagreement.
+        let reference = panics::catch(|| reference(&parsed));
+        let candidate = panics::catch(|| candidate(&parsed));
+        (reference != candidate).then_some((reference, candidate))
+    };
+
+    let seed = (0..SEEDS).find(|&seed| outcomes(&generate(size, seed)).is_some())?;
+    let mut input = (1..=size)
+        .map(|size| generate(size, seed))
+        .find(|input| outcomes(input).is_some())
+        .expect("the input of the full size disagrees");
+    while let Some(smaller) = smaller_inputs(&input)
+        .into_iter()
+        .find(|smaller| outcomes(smaller).is_some())
+    {
+        input = smaller;
+    }
+
+    let (reference, candidate) = outcomes(&input).expect("shrinking keeps the disagreement");
+    Some(Disagreement {
+        seed,
+        input,
+        reference,
+        candidate,
+    })
+}
+
+/// `input` without a run of consecutive lines, longest runs first so that whole records such as a
+/// grid row or a three-line claw machine go at once, then without one comma-separated item of
+/// one of its lines.
+fn smaller_inputs(input: &str) -> Vec<String> {
+    let lines: Vec<&str> = input.lines().collect();
+    let mut smaller = Vec::new();
+    for length in (1..=lines.len().div_ceil(2)).rev() {
+        for start in 0..=lines.len() - length {
+            let mut kept = lines.clone();
+            kept.drain(start..start + length);
+            smaller.push(join_lines(&kept));
+        }
+    }
+    for (line, text) in lines.iter().enumerate() {
+        let items: Vec<&str> = text.split(',').collect();
+        if items.len() < 2 {
+            continue;
+        }
+        for removed in 0..items.len() {
+            let mut kept_items = items.clone();
+            kept_items.remove(removed);
+            let shortened = kept_items.join(",");
+            let mut kept = lines.clone();
+            kept[line] = &shortened;
+            smaller.push(join_lines(&kept));
+        }
+    }
+    smaller
+}
+
+fn join_lines(lines: &[&str]) -> String {
+    lines.iter().map(|line| format!("{}\n", line)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{input, Answer, NoParams, Solution, SolveError};
+
+    use super::*;
+
+    /// Lines of comma-separated digits.
+    struct Rows;
+
+    impl Solution for Rows {
+        type Input = Vec<Vec<u32>>;
+        type Params = NoParams;
+
+        fn parse(text: &str) -> Result<Self::Input, SolveError> {
+            input::lines(text)
+                .map(|line| {
+                    line.text
+                        .split(',')
+                        .map(|value| line.parse(value))
+                        .collect()
+                })
+                .collect()
+        }
+
+        fn part1(rows: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(rows.iter().flatten().sum::<u32>().into())
+        }
+
+        fn part2(rows: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(rows.len().into())
+        }
+    }
+
+    impl Generator for Rows {
+        const DEFAULT_SIZE: usize = 10;
+
+        fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+            (0..size)
+                .map(|_| {
+                    let row: Vec<String> =
+                        (0..4).map(|_| rng.usize_in(0..=9).to_string()).collect();
+                    format!("{}\n", row.join(","))
+                })
+                .collect()
+        }
+    }
+
+    fn sum(rows: &<Rows as Solution>::Input) -> u32 {
+        rows.iter().flatten().sum()
+    }
+
+    #[test]
+    fn test_agreeing_sides_pass() {
+        check_agreement::<Rows, _>(20, sum, |rows| {
+            rows.iter().map(|row| row.iter().sum::<u32>()).sum()
+        });
+    }
+
+    #[test]
+    fn test_disagreement_is_shrunk_to_one_item() {
+        let disagreement = find_disagreement::<Rows, _>(20, &sum, &|rows| {
+            rows.iter().flatten().filter(|&&value| value != 7).sum()
+        })
+        .unwrap();
+
+        assert_eq!(disagreement.input, "7\n");
+        assert_eq!(disagreement.reference, Ok(7));
+        assert_eq!(disagreement.candidate, Ok(0));
+    }
+
+    #[test]
+    fn test_panics_are_disagreements() {
+        let disagreement = find_disagreement::<Rows, _>(20, &sum, &|rows| {
+            assert!(rows.len() < 3, "too many rows");
+            sum(rows)
+        })
+        .unwrap();
+
+        assert_eq!(disagreement.input.lines().count(), 3);
+        assert_eq!(disagreement.candidate, Err("too many rows".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "shrunk to:\n7\n")]
+    fn test_check_agreement_reports_the_shrunk_input() {
+        check_agreement::<Rows, _>(20, sum, |rows| {
+            rows.iter().flatten().filter(|&&value| value != 7).sum()
+        });
+    }
+}
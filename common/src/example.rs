@@ -0,0 +1,145 @@
+use crate::{params, Part, Solution};
+
+/// An example input from the puzzle text together with the answers the text gives for it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Example {
+    pub name: &'static str,
+    pub input: &'static str,
+    /// The expected part 1 answer, as displayed; `None` when the puzzle does not give one.
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+    /// Overrides of the day's parameters, in TOML, for examples smaller than the real puzzle.
+    pub params: Option<&'static str>,
+}
+
+impl Example {
+    pub fn expected(&self, part: Part) -> Option<&'static str> {
+        match part {
+            Part::One => self.part1,
+            Part::Two => self.part2,
+        }
+    }
+}
+
+/// Solves `part` of every example of `S` that has an expected answer for it, panicking with the
+/// name of the first example whose answer differs.
+pub fn check_examples<S: Solution>(part: Part) {
+    for example in S::EXAMPLES {
+        let Some(expected) = example.expected(part) else {
+            continue;
+        };
+
+        let input = params::parse_table(example.params.unwrap_or_default())
+            .and_then(|table| params::from_table(&table))
+            .and_then(|params| S::parse_with(example.input, &params))
+            .unwrap_or_else(|error| panic!("example '{}' does not parse: {}", example.name, error));
+        let answer = match part {
+            Part::One => S::part1(&input),
+            Part::Two => S::part2(&input),
+        }
+        .unwrap_or_else(|error| panic!("example '{}', {}: {}", example.name, part, error));
+
+        assert_eq!(
+            answer.to_string(),
+            expected,
+            "example '{}', {}",
+            example.name,
+            part
+        );
+    }
+}
+
+/// Generates `test_part1_examples` and `test_part2_examples`, which check the solution against
+/// its [`Solution::EXAMPLES`].
+#[macro_export]
+macro_rules! example_tests {
+    ($solution:ty) => {
+        #[test]
+        fn test_part1_examples() {
+            $crate::example::check_examples::<$solution>($crate::Part::One);
+        }
+
+        #[test]
+        fn test_part2_examples() {
+            $crate::example::check_examples::<$solution>($crate::Part::Two);
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Answer, SolveError};
+
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+        type Params = crate::NoParams;
+
+        const EXAMPLES: &'static [Example] = &[
+            Example {
+                name: "three numbers",
+                input: "1\n2\n3\n",
+                part1: Some("6"),
+                part2: None,
+                params: None,
+            },
+            Example {
+                name: "negative",
+                input: "-4\n",
+                part1: Some("-4"),
+                part2: Some("4"),
+                params: None,
+            },
+        ];
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            crate::input::lines(input)
+                .map(|line| line.parse(line.text))
+                .collect()
+        }
+
+        fn part1(numbers: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(numbers.iter().sum::<i64>().into())
+        }
+
+        fn part2(numbers: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(numbers
+                .iter()
+                .map(|number| number.abs())
+                .sum::<i64>()
+                .into())
+        }
+    }
+
+    crate::example_tests!(Sum);
+
+    struct WrongSum;
+
+    impl Solution for WrongSum {
+        type Input = Vec<i64>;
+        type Params = crate::NoParams;
+
+        const EXAMPLES: &'static [Example] = Sum::EXAMPLES;
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            Sum::parse(input)
+        }
+
+        fn part1(numbers: &Self::Input) -> Result<Answer, SolveError> {
+            Ok((numbers.iter().sum::<i64>() + 1).into())
+        }
+
+        fn part2(numbers: &Self::Input) -> Result<Answer, SolveError> {
+            Sum::part2(numbers)
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "example 'three numbers', Part 1")]
+    fn test_mismatch_names_the_example() {
+        check_examples::<WrongSum>(Part::One);
+    }
+}
@@ -0,0 +1,205 @@
+//! Checks that parsers reject malformed input with an error instead of panicking. The fuzz
+//! targets in `fuzz/` feed [`parse_bytes`] arbitrary bytes; [`parser_fuzz_tests!`] runs a quick
+//! version of the same check on mutated examples and generated inputs with every `cargo test`.
+//!
+//! [`parser_fuzz_tests!`]: crate::parser_fuzz_tests
+
+use std::panic::{self, AssertUnwindSafe};
+
+use crate::{Generator, Rng, Solution};
+
+/// Mutated inputs each [`check_parser_never_panics`] call parses.
+const CASES: u64 = 256;
+
+/// Fragments spliced into inputs: separators, grid tiles, signs, a non-ASCII character and
+/// numbers too large for any integer type.
+const FRAGMENTS: &[&str] = &[
+    "\n",
+    "\n\n",
+    " ",
+    ",",
+    ":",
+    "|",
+    "=",
+    "-",
+    "+",
+    "p=",
+    "v=",
+    "X+",
+    "Y=",
+    "#",
+    ".",
+    "S",
+    "E",
+    "@",
+    "^",
+    "A",
+    "0",
+    "7",
+    "é",
+    "\t",
+    "99999999999999999999999",
+    "Register A: ",
+    "Program: ",
+];
+
+/// Parses `data` as `S`'s input with the default parameters and formats any error, ignoring the
+/// outcome. Bytes that are not UTF-8 never reach a parser, since reading the input rejects them.
+pub fn parse_bytes<S: Solution>(data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Err(error) = S::parse_with(input, &S::Params::default()) {
+        let _ = error.to_string();
+    }
+}
+
+/// Generates `test_parser_never_panics`, which parses mutations of the examples and of inputs
+/// generated at `size` and fails on the first one the parser panics on.
+#[macro_export]
+macro_rules! parser_fuzz_tests {
+    ($solution:ty, $size:expr) => {
+        #[test]
+        fn test_parser_never_panics() {
+            $crate::fuzz::check_parser_never_panics::<$solution>($size);
+        }
+    };
+}
+
+/// Parses mutations of `G`'s examples and of inputs generated at `size`, panicking with the first
+/// mutated input the parser panics on.
+pub fn check_parser_never_panics<G: Generator>(size: usize) {
+    let params = G::Params::default();
+    let mut seeds: Vec<String> = G::EXAMPLES
+        .iter()
+        .map(|example| example.input.to_string())
+        .collect();
+    seeds.extend((0..4).map(|seed| G::generate(size, &params, &mut Rng::seed(seed))));
+
+    let mut rng = Rng::seed(0);
+    for case in 0..CASES {
+        let mut input = rng.pick(&seeds).clone().into_bytes();
+        for _ in 0..rng.usize_in(1..=4) {
+            mutate(&mut input, &mut rng);
+        }
+        let input = String::from_utf8_lossy(&input).into_owned();
+        if panic::catch_unwind(AssertUnwindSafe(|| parse_bytes::<G>(input.as_bytes()))).is_err() {
+            panic!("the parser panicked on case {}:\n{:?}", case, input);
+        }
+    }
+}
+
+/// Applies one random edit to `input`: truncating it, replacing, inserting or deleting a byte,
+/// splicing in a fragment, or deleting or duplicating a line.
+fn mutate(input: &mut Vec<u8>, rng: &mut Rng) {
+    let position = rng.usize_in(0..=input.len());
+    match rng.usize_in(0..=5) {
+        0 => input.truncate(position),
+        1 if position < input.len() => input[position] = rng.usize_in(0..=255) as u8,
+        2 if position < input.len() => {
+            input.remove(position);
+        }
+        3 => {
+            let fragment = rng.pick::<&str>(FRAGMENTS);
+            input.splice(position..position, fragment.bytes());
+        }
+        4 | 5 => {
+            let lines: Vec<&[u8]> = input.split_inclusive(|&byte| byte == b'\n').collect();
+            if lines.is_empty() {
+                return;
+            }
+            let line = rng.usize_in(0..=lines.len() - 1);
+            let mut edited: Vec<&[u8]> = lines.clone();
+            if rng.chance(1, 2) {
+                edited.remove(line);
+            } else {
+                edited.insert(line, lines[line]);
+            }
+            *input = edited.concat();
+        }
+        _ => input.insert(position, rng.usize_in(0x20..=0x7e) as u8),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{input, Answer, NoParams, SolveError};
+
+    use super::*;
+
+    /// One number per line, parsed carelessly.
+    struct Careless;
+
+    impl Solution for Careless {
+        type Input = Vec<u32>;
+        type Params = NoParams;
+
+        fn parse(text: &str) -> Result<Self::Input, SolveError> {
+            Ok(text.lines().map(|line| line.parse().unwrap()).collect())
+        }
+
+        fn part1(numbers: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(numbers.len().into())
+        }
+
+        fn part2(numbers: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(numbers.len().into())
+        }
+    }
+
+    impl Generator for Careless {
+        const DEFAULT_SIZE: usize = 10;
+
+        fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+            (0..size)
+                .map(|_| format!("{}\n", rng.usize_in(0..=999)))
+                .collect()
+        }
+    }
+
+    /// The same input, parsed with errors.
+    struct Careful;
+
+    impl Solution for Careful {
+        type Input = Vec<u32>;
+        type Params = NoParams;
+
+        fn parse(text: &str) -> Result<Self::Input, SolveError> {
+            input::lines(text)
+                .map(|line| line.parse(line.text))
+                .collect()
+        }
+
+        fn part1(numbers: &Self::Input) -> Result<Answer, SolveError> {
+            Careless::part1(numbers)
+        }
+
+        fn part2(numbers: &Self::Input) -> Result<Answer, SolveError> {
+            Careless::part2(numbers)
+        }
+    }
+
+    impl Generator for Careful {
+        const DEFAULT_SIZE: usize = 10;
+
+        fn generate(size: usize, params: &NoParams, rng: &mut Rng) -> String {
+            Careless::generate(size, params, rng)
+        }
+    }
+
+    #[test]
+    fn test_careful_parser_passes() {
+        check_parser_never_panics::<Careful>(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "the parser panicked")]
+    fn test_unwrapping_parser_is_caught() {
+        check_parser_never_panics::<Careless>(10);
+    }
+
+    #[test]
+    fn test_invalid_utf8_is_ignored() {
+        parse_bytes::<Careless>(&[b'1', 0xff, b'\n']);
+    }
+}
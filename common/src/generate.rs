@@ -0,0 +1,282 @@
+use std::ops::RangeInclusive;
+
+use crate::{params, Grid, ParamTable, Part, Solution, SolveError};
+
+/// A day that can make up random puzzle inputs, for stress tests and benchmarks at scale.
+pub trait Generator: Solution {
+    /// The size used when none is requested, close to that of a real puzzle input.
+    const DEFAULT_SIZE: usize;
+
+    /// The smallest size for which `generate` can make a valid input.
+    const MIN_SIZE: usize = 1;
+
+    /// The parts that every generated input has an answer for. A day whose part 2 depends on a
+    /// hidden property of the real input, e.g. a picture or a quine, lists only part 1.
+    const SOLVES: &'static [Part] = &Part::BOTH;
+
+    /// Rejects `params` for which no input can be generated, e.g. an empty room.
+    fn check_params(_params: &Self::Params) -> Result<(), SolveError> {
+        Ok(())
+    }
+
+    /// A random input that `parse_with(input, params)` accepts and that has answers. What `size`
+    /// counts, e.g. lines or the side of a grid, is documented by each day. `size` is at least
+    /// `MIN_SIZE` and `params` have passed `check_params`.
+    fn generate(size: usize, params: &Self::Params, rng: &mut Rng) -> String;
+}
+
+/// Generates an input for `G` from `seed`, with the defaults of `G::Params` overridden by `params`.
+pub fn generate<G: Generator>(
+    size: Option<usize>,
+    params: &ParamTable,
+    seed: u64,
+) -> Result<String, SolveError> {
+    let params: G::Params = params::from_table(params)?;
+    G::check_params(&params)?;
+    let size = size.unwrap_or(G::DEFAULT_SIZE);
+    if size < G::MIN_SIZE {
+        return Err(SolveError::Params(format!(
+            "the size is {}, it must be at least {}",
+            size,
+            G::MIN_SIZE
+        )));
+    }
+    Ok(G::generate(size, &params, &mut Rng::seed(seed)))
+}
+
+/// A small seeded pseudo-random generator (SplitMix64). Unlike an external RNG its sequence is
+/// fixed, so a seed reproduces the same input on every build.
+#[derive(Clone, Debug)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn seed(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// A value in `range`, which must not be empty.
+    pub fn usize_in(&mut self, range: RangeInclusive<usize>) -> usize {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick from {}..={}", start, end);
+        start + self.below((end - start) as u64 + 1) as usize
+    }
+
+    /// A value in `range`, which must not be empty.
+    pub fn i64_in(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "cannot pick from {}..={}", start, end);
+        start.wrapping_add(self.below(end.abs_diff(start) + 1) as i64)
+    }
+
+    /// True `numerator` times out of `denominator`.
+    pub fn chance(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.below(denominator) < numerator
+    }
+
+    /// One of `items`, which must not be empty.
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.usize_in(0..=items.len() - 1)]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for index in (1..items.len()).rev() {
+            items.swap(index, self.usize_in(0..=index));
+        }
+    }
+
+    /// A value below `bound`, or any value when `bound` is 0 (the whole `u64` range).
+    fn below(&mut self, bound: u64) -> u64 {
+        if bound == 0 {
+            return self.next_u64();
+        }
+        ((u128::from(self.next_u64()) * u128::from(bound)) >> 64) as u64
+    }
+}
+
+/// A perfect maze of `cells` by `cells` open tiles, walled in and separated by walls, so its side
+/// is `2 * cells + 1` tiles. Open tiles sit at odd rows and columns and every pair of them is
+/// joined by exactly one path.
+pub fn maze(cells: usize, rng: &mut Rng) -> Grid<char> {
+    let side = 2 * cells + 1;
+    let mut maze = Grid::new(side, side, '#');
+    maze[(1, 1)] = '.';
+    let mut stack: Vec<(usize, usize)> = vec![(1, 1)];
+    while let Some(&(row, col)) = stack.last() {
+        let unvisited: Vec<(usize, usize)> = [
+            (row.wrapping_sub(2), col),
+            (row + 2, col),
+            (row, col.wrapping_sub(2)),
+            (row, col + 2),
+        ]
+        .into_iter()
+        .filter(|&next| maze.get(next.0, next.1) == Some(&'#'))
+        .collect();
+        if unvisited.is_empty() {
+            stack.pop();
+            continue;
+        }
+        let next = *rng.pick(&unvisited);
+        maze[((row + next.0) / 2, (col + next.1) / 2)] = '.';
+        maze[next] = '.';
+        stack.push(next);
+    }
+    maze
+}
+
+/// Generates `test_generated_inputs`, which checks that inputs generated from several seeds at
+/// `size` and at the day's minimum size parse and that the parts in `SOLVES` solve them.
+#[macro_export]
+macro_rules! generator_tests {
+    ($solution:ty, $size:expr) => {
+        #[test]
+        fn test_generated_inputs() {
+            $crate::generate::check_generated::<$solution>($size);
+        }
+    };
+}
+
+/// Generates inputs of `size` and of `G::MIN_SIZE` from a few seeds and panics with the size and
+/// seed of the first one that does not parse or that a part in `G::SOLVES` does not solve.
+pub fn check_generated<G: Generator>(size: usize) {
+    let params = G::Params::default();
+    for size in [size, G::MIN_SIZE] {
+        for seed in 0..8 {
+            let input = G::generate(size, &params, &mut Rng::seed(seed));
+            let parsed = G::parse_with(&input, &params).unwrap_or_else(|error| {
+                panic!("size {}, seed {}: {}\n{}", size, seed, error, input)
+            });
+            for part in G::SOLVES {
+                let answer = match part {
+                    Part::One => G::part1(&parsed),
+                    Part::Two => G::part2(&parsed),
+                };
+                if let Err(error) = answer {
+                    panic!(
+                        "size {}, seed {}, part {}: {}\n{}",
+                        size,
+                        seed,
+                        part.number(),
+                        error,
+                        input
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Answer, NoParams};
+
+    use super::*;
+
+    #[test]
+    fn test_same_seed_same_sequence() {
+        let mut first = Rng::seed(7);
+        let mut second = Rng::seed(7);
+        let values: Vec<u64> = (0..4).map(|_| first.next_u64()).collect();
+        assert_eq!(
+            values,
+            (0..4).map(|_| second.next_u64()).collect::<Vec<_>>()
+        );
+        assert_ne!(values, {
+            let mut other = Rng::seed(8);
+            (0..4).map(|_| other.next_u64()).collect::<Vec<_>>()
+        });
+    }
+
+    #[test]
+    fn test_ranges_are_inclusive_and_bounded() {
+        let mut rng = Rng::seed(1);
+        let values: Vec<usize> = (0..200).map(|_| rng.usize_in(3..=5)).collect();
+        assert!(values.iter().all(|value| (3..=5).contains(value)));
+        assert!(values.contains(&3) && values.contains(&5));
+
+        assert!((0..200).all(|_| (-2..=2).contains(&rng.i64_in(-2..=2))));
+        assert_eq!(rng.i64_in(i64::MIN..=i64::MIN), i64::MIN);
+    }
+
+    #[test]
+    fn test_shuffle_keeps_the_items() {
+        let mut items: Vec<u32> = (0..20).collect();
+        Rng::seed(3).shuffle(&mut items);
+        assert_ne!(items, (0..20).collect::<Vec<_>>());
+        items.sort();
+        assert_eq!(items, (0..20).collect::<Vec<_>>());
+    }
+
+    struct Count;
+
+    impl Solution for Count {
+        type Input = usize;
+        type Params = NoParams;
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            Ok(input.lines().count())
+        }
+
+        fn part1(lines: &Self::Input) -> Result<Answer, SolveError> {
+            Ok((*lines).into())
+        }
+
+        fn part2(lines: &Self::Input) -> Result<Answer, SolveError> {
+            Ok((lines * 2).into())
+        }
+    }
+
+    impl Generator for Count {
+        const DEFAULT_SIZE: usize = 3;
+
+        fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+            (0..size)
+                .map(|_| format!("{}\n", rng.usize_in(1..=9)))
+                .collect()
+        }
+    }
+
+    crate::generator_tests!(Count, 5);
+
+    #[test]
+    fn test_maze_opens_every_cell() {
+        let maze = maze(4, &mut Rng::seed(5));
+        assert_eq!(maze.dimensions(), (9, 9));
+        assert!(maze.row(0).iter().all(|&tile| tile == '#'));
+        for row in (1..9).step_by(2) {
+            for col in (1..9).step_by(2) {
+                assert_eq!(maze[(row, col)], '.');
+            }
+        }
+        // A spanning tree of the 16 cells opens exactly 15 walls between them.
+        assert_eq!(maze.find_all(&'.').count(), 16 + 15);
+    }
+
+    #[test]
+    fn test_generate_rejects_sizes_below_the_minimum() {
+        let error = generate::<Count>(Some(0), &ParamTable::new(), 42).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "invalid parameters: the size is 0, it must be at least 1"
+        );
+    }
+
+    #[test]
+    fn test_generate_uses_the_default_size() {
+        let input = generate::<Count>(None, &ParamTable::new(), 42).unwrap();
+        assert_eq!(input.lines().count(), 3);
+        assert_eq!(
+            input,
+            generate::<Count>(Some(3), &ParamTable::new(), 42).unwrap()
+        );
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A signed `(row, col)` position or offset; rows grow downwards and columns to the right.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ZERO: Point = Point::new(0, 0);
+    pub const UP: Point = Point::new(-1, 0);
+    pub const DOWN: Point = Point::new(1, 0);
+    pub const LEFT: Point = Point::new(0, -1);
+    pub const RIGHT: Point = Point::new(0, 1);
+    pub const UP_LEFT: Point = Point::new(-1, -1);
+    pub const UP_RIGHT: Point = Point::new(-1, 1);
+    pub const DOWN_LEFT: Point = Point::new(1, -1);
+    pub const DOWN_RIGHT: Point = Point::new(1, 1);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Point { row, col }
+    }
+
+    pub fn manhattan_distance(self, other: Point) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// Rotates the offset a quarter turn clockwise, e.g. `UP` becomes `RIGHT`.
+    pub fn turn_right(self) -> Self {
+        Point::new(self.col, -self.row)
+    }
+
+    /// Rotates the offset a quarter turn counter-clockwise, e.g. `UP` becomes `LEFT`.
+    pub fn turn_left(self) -> Self {
+        Point::new(-self.col, self.row)
+    }
+
+    pub fn reverse(self) -> Self {
+        -self
+    }
+
+    /// The unit offset for one of the `^`, `v`, `<` and `>` arrows.
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        Direction::from_arrow(arrow).map(Direction::offset)
+    }
+
+    /// Every point at most `radius` steps away from this one in Manhattan distance, this one
+    /// included, with its distance; row by row from the top.
+    pub fn within(self, radius: usize) -> impl Iterator<Item = (Point, usize)> {
+        let radius = radius as isize;
+        (-radius..=radius).flat_map(move |row| {
+            let width = radius - row.abs();
+            (-width..=width).map(move |col| {
+                (
+                    self + Point::new(row, col),
+                    (row.abs() + col.abs()) as usize,
+                )
+            })
+        })
+    }
+
+    /// The unsigned `(row, col)` of the point, if it has no negative coordinate.
+    pub fn to_position(self) -> Option<(usize, usize)> {
+        Some((
+            usize::try_from(self.row).ok()?,
+            usize::try_from(self.col).ok()?,
+        ))
+    }
+}
+
+impl From<(usize, usize)> for Point {
+    fn from((row, col): (usize, usize)) -> Self {
+        Point::new(row as isize, col as isize)
+    }
+}
+
+impl Add for Point {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.row + other.row, self.col + other.col)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.row - other.row, self.col - other.col)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Self;
+
+    fn mul(self, factor: isize) -> Self {
+        Point::new(self.row * factor, self.col * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.row, -self.col)
+    }
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.row, self.col)
+    }
+}
+
+/// Offsets of the 4 orthogonal neighbors, clockwise from up.
+pub const ORTHOGONAL: [Point; 4] = [Point::UP, Point::RIGHT, Point::DOWN, Point::LEFT];
+
+/// Offsets of the 4 diagonal neighbors, clockwise from up-left.
+pub const DIAGONAL: [Point; 4] = [
+    Point::UP_LEFT,
+    Point::UP_RIGHT,
+    Point::DOWN_RIGHT,
+    Point::DOWN_LEFT,
+];
+
+/// Offsets of all 8 neighbors, orthogonal first.
+pub const ALL_DIRECTIONS: [Point; 8] = [
+    Point::UP,
+    Point::RIGHT,
+    Point::DOWN,
+    Point::LEFT,
+    Point::UP_LEFT,
+    Point::UP_RIGHT,
+    Point::DOWN_RIGHT,
+    Point::DOWN_LEFT,
+];
+
+/// One of the 4 orthogonal headings.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All headings, clockwise from up.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn offset(self) -> Point {
+        match self {
+            Direction::Up => Point::UP,
+            Direction::Right => Point::RIGHT,
+            Direction::Down => Point::DOWN,
+            Direction::Left => Point::LEFT,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    /// Parses one of the `^`, `v`, `<` and `>` arrows.
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        match arrow {
+            '^' => Some(Direction::Up),
+            '>' => Some(Direction::Right),
+            'v' => Some(Direction::Down),
+            '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Right => '>',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_arithmetic() {
+        let point = Point::new(3, 4);
+
+        assert_eq!(point + Point::UP, Point::new(2, 4));
+        assert_eq!(point - Point::new(1, 1), Point::new(2, 3));
+        assert_eq!(Point::LEFT * 3, Point::new(0, -3));
+        assert_eq!(-point, Point::new(-3, -4));
+        assert_eq!(point.manhattan_distance(Point::new(-1, 6)), 6);
+    }
+
+    #[test]
+    fn test_rotation() {
+        assert_eq!(Point::UP.turn_right(), Point::RIGHT);
+        assert_eq!(Point::RIGHT.turn_right(), Point::DOWN);
+        assert_eq!(Point::UP.turn_left(), Point::LEFT);
+        assert_eq!(Point::UP_RIGHT.turn_right(), Point::DOWN_RIGHT);
+        assert_eq!(Point::DOWN.reverse(), Point::UP);
+
+        for direction in Direction::ALL {
+            assert_eq!(
+                direction.turn_right().offset(),
+                direction.offset().turn_right()
+            );
+            assert_eq!(
+                direction.turn_left().offset(),
+                direction.offset().turn_left()
+            );
+            assert_eq!(direction.reverse().offset(), direction.offset().reverse());
+            assert_eq!(direction.turn_left().turn_right(), direction);
+        }
+    }
+
+    #[test]
+    fn test_arrows() {
+        assert_eq!(Point::from_arrow('^'), Some(Point::UP));
+        assert_eq!(Point::from_arrow('v'), Some(Point::DOWN));
+        assert_eq!(Direction::from_arrow('<'), Some(Direction::Left));
+        assert_eq!(Direction::from_arrow('x'), None);
+        for direction in Direction::ALL {
+            assert_eq!(Direction::from_arrow(direction.arrow()), Some(direction));
+        }
+    }
+
+    #[test]
+    fn test_within_radius() {
+        let center = Point::new(5, 5);
+        let points: Vec<(Point, usize)> = center.within(2).collect();
+
+        assert_eq!(points.len(), 13);
+        assert_eq!(points[0], (Point::new(3, 5), 2));
+        assert!(points.contains(&(center, 0)));
+        assert!(points.contains(&(Point::new(6, 4), 2)));
+        assert!(points
+            .iter()
+            .all(|&(point, distance)| point.manhattan_distance(center) == distance));
+        assert_eq!(center.within(0).collect::<Vec<_>>(), [(center, 0)]);
+    }
+
+    #[test]
+    fn test_to_position() {
+        assert_eq!(Point::new(2, 5).to_position(), Some((2, 5)));
+        assert_eq!(Point::new(-1, 5).to_position(), None);
+        assert_eq!(Point::from((7, 1)), Point::new(7, 1));
+    }
+}
@@ -0,0 +1,495 @@
+use std::{
+    error, fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{
+    geometry::{Point, ALL_DIRECTIONS, ORTHOGONAL},
+    input::ParseError,
+};
+
+/// Why a block of text could not be parsed as a grid.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// There are no rows to build the grid from.
+    Empty,
+    /// A row does not have the width of the first one; `line` is 1-based and `excerpt` is the
+    /// row.
+    RaggedRow {
+        line: usize,
+        expected: usize,
+        found: usize,
+        excerpt: String,
+    },
+    /// A character does not stand for any cell; `line` and `col` are 1-based and `excerpt` is the
+    /// row.
+    InvalidCell {
+        line: usize,
+        col: usize,
+        found: char,
+        excerpt: String,
+    },
+}
+
+impl GridError {
+    /// The error as a [`ParseError`] pointing into its row, or itself when it has no row.
+    pub fn into_parse_error(self) -> Result<ParseError, GridError> {
+        match self {
+            GridError::Empty => Err(self),
+            GridError::RaggedRow {
+                line,
+                expected,
+                found,
+                excerpt,
+            } => Ok(ParseError {
+                line,
+                col: expected.min(found) + 1,
+                message: format!("the row has {} columns, expected {}", found, expected),
+                excerpt,
+            }),
+            GridError::InvalidCell {
+                line,
+                col,
+                found,
+                excerpt,
+            } => Ok(ParseError {
+                line,
+                col,
+                message: format!("unexpected '{}'", found),
+                excerpt,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.clone().into_parse_error() {
+            Ok(error) => write!(f, "{}", error),
+            Err(_) => write!(f, "grid is empty"),
+        }
+    }
+}
+
+impl error::Error for GridError {}
+
+/// A dense, row-major 2D grid addressed by `(row, col)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a `rows` x `cols` grid with every cell set to `value`.
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            rows,
+            cols,
+            cells: vec![value; rows * cols],
+        }
+    }
+
+    /// Flattens a matrix into a grid, or `None` when its rows have different lengths.
+    pub fn from_rows(matrix: Vec<Vec<T>>) -> Option<Self> {
+        let rows = matrix.len();
+        let cols = matrix.first().map_or(0, Vec::len);
+        if matrix.iter().any(|row| row.len() != cols) {
+            return None;
+        }
+
+        Some(Grid {
+            rows,
+            cols,
+            cells: matrix.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one row per line, converting every character with `cell`.
+    pub fn parse_with(input: &str, mut cell: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        Self::try_parse_with(input, |c| Some(cell(c)))
+    }
+
+    /// Like [`Grid::parse_with`], but `cell` returns `None` for characters that are not allowed.
+    pub fn try_parse_with(
+        input: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, GridError> {
+        let mut cells = Vec::new();
+        let mut rows = 0;
+        let mut cols = 0;
+
+        for (index, line) in input.lines().enumerate() {
+            if line.is_empty() {
+                break;
+            }
+
+            let row_start = cells.len();
+            for (col, c) in line.chars().enumerate() {
+                cells.push(cell(c).ok_or_else(|| GridError::InvalidCell {
+                    line: index + 1,
+                    col: col + 1,
+                    found: c,
+                    excerpt: line.to_string(),
+                })?);
+            }
+            let width = cells.len() - row_start;
+
+            if rows == 0 {
+                cols = width;
+            } else if width != cols {
+                return Err(GridError::RaggedRow {
+                    line: index + 1,
+                    expected: cols,
+                    found: width,
+                    excerpt: line.to_string(),
+                });
+            }
+            rows += 1;
+        }
+
+        if rows == 0 {
+            return Err(GridError::Empty);
+        }
+        Ok(Grid { rows, cols, cells })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Gets the dimensions of the grid as `(rows, cols)`.
+    pub fn dimensions(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// Converts row and column indices to the index in the flat cell array.
+    pub fn coords_to_index(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        Some(row * self.cols + col)
+    }
+
+    /// Converts an index in the flat cell array to row and column indices.
+    pub fn index_to_coords(&self, index: usize) -> Option<(usize, usize)> {
+        if index >= self.cells.len() {
+            return None;
+        }
+        Some((index / self.cols, index % self.cols))
+    }
+
+    /// Whether `point`, which may have stepped off the grid, lies inside it.
+    pub fn contains(&self, point: Point) -> bool {
+        self.point_to_index(point).is_some()
+    }
+
+    fn point_to_index(&self, point: Point) -> Option<usize> {
+        let (row, col) = point.to_position()?;
+        self.coords_to_index(row, col)
+    }
+
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.coords_to_index(row, col)
+            .map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        self.coords_to_index(row, col)
+            .map(move |index| &mut self.cells[index])
+    }
+
+    /// Like [`Grid::get`], but accepts a point that may have stepped off the grid.
+    pub fn get_point(&self, point: Point) -> Option<&T> {
+        self.point_to_index(point).map(|index| &self.cells[index])
+    }
+
+    pub fn get_point_mut(&mut self, point: Point) -> Option<&mut T> {
+        self.point_to_index(point)
+            .map(move |index| &mut self.cells[index])
+    }
+
+    /// The position `offset` away from `position`, if it is still inside the grid.
+    pub fn offset(&self, position: (usize, usize), offset: Point) -> Option<(usize, usize)> {
+        let (row, col) = (Point::from(position) + offset).to_position()?;
+        self.coords_to_index(row, col).map(|_| (row, col))
+    }
+
+    /// The in-bounds positions reached from `position` with each of `offsets`.
+    pub fn neighbors<'a>(
+        &'a self,
+        position: (usize, usize),
+        offsets: &'a [Point],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        offsets
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    /// The in-bounds orthogonal neighbors of `position`.
+    pub fn neighbors4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(position, &ORTHOGONAL)
+    }
+
+    /// The in-bounds orthogonal and diagonal neighbors of `position`.
+    pub fn neighbors8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(position, &ALL_DIRECTIONS)
+    }
+
+    /// Every `(row, col)` of the grid in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let cols = self.cols;
+        (0..self.cells.len()).map(move |index| (index / cols, index % cols))
+    }
+
+    /// Every cell together with its `(row, col)`, in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell equal to `value`, in row-major order.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.cells
+            .iter()
+            .position(|cell| cell == value)
+            .and_then(|index| self.index_to_coords(index))
+    }
+
+    /// The positions of all cells equal to `value`, in row-major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.cols..(row + 1) * self.cols]
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        self.cells.iter().skip(col).step_by(self.cols.max(1))
+    }
+
+    pub fn iter_rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.cols.max(1))
+    }
+
+    /// A grid of the same size with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            rows: self.rows,
+            cols: self.cols,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<char> {
+    /// Parses one row of characters per line.
+    pub fn parse(input: &str) -> Result<Self, GridError> {
+        Self::parse_with(input, |c| c)
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (row, col): (usize, usize)) -> &T {
+        match self.coords_to_index(row, col) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                row, col, self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut T {
+        match self.coords_to_index(row, col) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "({}, {}) is outside of the {}x{} grid",
+                row, col, self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        match self.point_to_index(point) {
+            Some(index) => &self.cells[index],
+            None => panic!(
+                "{} is outside of the {}x{} grid",
+                point, self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        match self.point_to_index(point) {
+            Some(index) => &mut self.cells[index],
+            None => panic!(
+                "{} is outside of the {}x{} grid",
+                point, self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.iter_rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_rows() {
+        let grid = Grid::from_rows(vec![vec!['1', '2', '3'], vec!['4', '5', '6']]).unwrap();
+
+        assert_eq!(grid.dimensions(), (2, 3));
+        assert_eq!(grid[(1, 0)], '4');
+        assert_eq!(grid.row(1), &['4', '5', '6']);
+        assert_eq!(grid.column(2).collect::<String>(), "36");
+    }
+
+    #[test]
+    fn test_invalid_matrix() {
+        let invalid_matrix = vec![
+            vec!['1', '2'], // Wrong number of columns
+            vec!['4', '5', '6'],
+        ];
+
+        assert_eq!(Grid::from_rows(invalid_matrix), None);
+    }
+
+    #[test]
+    fn test_coords_to_index() {
+        let grid = Grid::new(3, 4, 0);
+
+        assert_eq!(grid.coords_to_index(0, 0), Some(0));
+        assert_eq!(grid.coords_to_index(1, 2), Some(6));
+        assert_eq!(grid.coords_to_index(2, 3), Some(11));
+        assert_eq!(grid.coords_to_index(3, 0), None); // Invalid row
+        assert_eq!(grid.coords_to_index(0, 4), None); // Invalid column
+    }
+
+    #[test]
+    fn test_index_to_coords() {
+        let grid = Grid::new(3, 4, 0);
+
+        assert_eq!(grid.index_to_coords(0), Some((0, 0)));
+        assert_eq!(grid.index_to_coords(6), Some((1, 2)));
+        assert_eq!(grid.index_to_coords(11), Some((2, 3)));
+        assert_eq!(grid.index_to_coords(12), None); // Invalid index
+    }
+
+    #[test]
+    fn test_parse_and_display_round_trip() {
+        let input = "#.#\n.S.\n#E#\n";
+        let grid = Grid::parse(input).unwrap();
+
+        assert_eq!(grid.find(&'S'), Some((1, 1)));
+        assert_eq!(grid.find_all(&'#').count(), 4);
+        assert_eq!(grid.to_string(), input);
+    }
+
+    #[test]
+    fn test_parse_with_stops_at_blank_line() {
+        let grid = Grid::parse_with("12\n34\n\n<>", |c| c.to_digit(10).unwrap()).unwrap();
+
+        assert_eq!(grid.dimensions(), (2, 2));
+        assert_eq!(grid[(1, 1)], 4);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Grid::parse(""), Err(GridError::Empty));
+        assert_eq!(
+            Grid::try_parse_with("012\n3x5\n", |c| c.to_digit(10)),
+            Err(GridError::InvalidCell {
+                line: 2,
+                col: 2,
+                found: 'x',
+                excerpt: "3x5".to_string(),
+            })
+        );
+        assert_eq!(
+            Grid::parse("...\n..\n"),
+            Err(GridError::RaggedRow {
+                line: 2,
+                expected: 3,
+                found: 2,
+                excerpt: "..".to_string(),
+            })
+        );
+        assert_eq!(
+            Grid::parse("...\n....\n").unwrap_err().to_string(),
+            "line 2, column 4: the row has 4 columns, expected 3\n    ....\n       ^"
+        );
+    }
+
+    #[test]
+    fn test_neighbors_are_bounds_checked() {
+        let grid = Grid::new(3, 3, '.');
+
+        assert_eq!(
+            grid.neighbors4((0, 0)).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors8((0, 2)).count(), 3);
+        assert_eq!(grid.neighbors8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn test_get_point() {
+        let mut grid = Grid::parse("ab\ncd").unwrap();
+
+        assert_eq!(grid.get_point(Point::new(1, 0)), Some(&'c'));
+        assert_eq!(grid.get_point(Point::new(-1, 0)), None);
+        assert_eq!(grid.get_point(Point::new(0, 2)), None);
+        assert!(grid.contains(Point::new(1, 1)));
+
+        grid[Point::new(0, 1) + Point::DOWN] = 'x';
+        assert_eq!(grid[(1, 1)], 'x');
+        assert_eq!(grid.offset((0, 0), Point::LEFT), None);
+        assert_eq!(grid.offset((0, 0), Point::DOWN_RIGHT), Some((1, 1)));
+    }
+}
@@ -0,0 +1,439 @@
+//! Saving grid states as images: an [`Image`] is a grid of colored tiles with optional paths
+//! drawn over it, written as PPM or PNG by the small encoders below or as SVG.
+
+use std::{collections::HashMap, fmt::Write, path::Path};
+
+use crate::{
+    params,
+    render::{self, Color, Render, Scene},
+    Grid, ParamTable, Point, Solution, SolveError,
+};
+
+/// A day that can draw its parsed input, together with what solving it finds, as an image.
+pub trait Draw: Solution {
+    fn draw(input: &Self::Input) -> Result<Image, SolveError>;
+}
+
+/// Parses `input` for `D`, with the defaults of `D::Params` overridden by `params`, and draws it.
+pub fn draw<D: Draw>(input: &str, params: &ParamTable) -> Result<Image, SolveError> {
+    let params: D::Params = params::from_table(params)?;
+    D::draw(&D::parse_with(input, &params)?)
+}
+
+/// Parses `input` for `R` and draws the colors of its terminal rendering, for days that have no
+/// image of their own.
+pub fn draw_rendered<R: Render>(input: &str, params: &ParamTable) -> Result<Image, SolveError> {
+    render::render::<R>(input, params).map(|scene| Image::from_scene(&scene))
+}
+
+/// The color of tiles that nothing is drawn on.
+pub const BACKGROUND: Color = Color::rgb(16, 16, 24);
+
+/// A line through the centers of a sequence of tiles.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Polyline {
+    pub points: Vec<Point>,
+    pub color: Color,
+}
+
+/// A grid of colored tiles with paths drawn over them.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    tiles: Grid<Color>,
+    paths: Vec<Polyline>,
+}
+
+/// The file formats an [`Image`] can be written as.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFormat {
+    Ppm,
+    Png,
+    Svg,
+}
+
+impl ImageFormat {
+    /// The format named by the extension of `path`, if it is one of them.
+    pub fn from_path(path: &Path) -> Option<ImageFormat> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+        match extension.as_str() {
+            "ppm" => Some(ImageFormat::Ppm),
+            "png" => Some(ImageFormat::Png),
+            "svg" => Some(ImageFormat::Svg),
+            _ => None,
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ImageFormat::Ppm => "ppm",
+            ImageFormat::Png => "png",
+            ImageFormat::Svg => "svg",
+        }
+    }
+}
+
+impl Image {
+    pub fn new(tiles: Grid<Color>) -> Image {
+        Image {
+            tiles,
+            paths: Vec::new(),
+        }
+    }
+
+    /// The colors of a terminal scene: each cell's background, else its foreground, else
+    /// [`BACKGROUND`].
+    pub fn from_scene(scene: &Scene) -> Image {
+        Image::new(
+            scene
+                .cells()
+                .map(|cell| cell.style.bg.or(cell.style.fg).unwrap_or(BACKGROUND)),
+        )
+    }
+
+    /// Adds a line through the centers of the tiles at `points`, drawn over the tiles.
+    pub fn with_path(mut self, points: Vec<Point>, color: Color) -> Image {
+        self.paths.push(Polyline { points, color });
+        self
+    }
+
+    pub fn tiles(&self) -> &Grid<Color> {
+        &self.tiles
+    }
+
+    pub fn paths(&self) -> &[Polyline] {
+        &self.paths
+    }
+
+    /// The image in `format`, each tile `scale` pixels wide in the raster formats.
+    pub fn encode(&self, format: ImageFormat, scale: usize) -> Vec<u8> {
+        match format {
+            ImageFormat::Ppm => self.to_ppm(scale),
+            ImageFormat::Png => self.to_png(scale),
+            ImageFormat::Svg => self.to_svg().into_bytes(),
+        }
+    }
+
+    /// A binary (P6) PPM with each tile `scale` by `scale` pixels.
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        let pixels = self.rasterize(scale);
+        let mut ppm = format!("P6\n{} {}\n255\n", pixels.cols(), pixels.rows()).into_bytes();
+        for color in pixels.iter().map(|(_, color)| color) {
+            ppm.extend([color.r, color.g, color.b]);
+        }
+        ppm
+    }
+
+    /// An 8-bit RGB PNG with each tile `scale` by `scale` pixels. The pixel data is stored
+    /// uncompressed, which keeps the encoder small; images of a puzzle grid stay well below a
+    /// megabyte at small scales.
+    pub fn to_png(&self, scale: usize) -> Vec<u8> {
+        let pixels = self.rasterize(scale);
+        let (height, width) = pixels.dimensions();
+
+        let mut header = Vec::with_capacity(13);
+        header.extend((width as u32).to_be_bytes());
+        header.extend((height as u32).to_be_bytes());
+        // Bit depth 8, color type 2 (RGB), default compression, filtering and no interlacing.
+        header.extend([8, 2, 0, 0, 0]);
+
+        let mut scanlines = Vec::with_capacity(height * (1 + 3 * width));
+        for row in pixels.iter_rows() {
+            // Every scanline starts with its filter type, 0 for none.
+            scanlines.push(0);
+            for color in row {
+                scanlines.extend([color.r, color.g, color.b]);
+            }
+        }
+
+        let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+        png_chunk(&mut png, b"IHDR", &header);
+        png_chunk(&mut png, b"IDAT", &zlib_stored(&scanlines));
+        png_chunk(&mut png, b"IEND", &[]);
+        png
+    }
+
+    /// An SVG one unit per tile, with a CSS class per distinct tile color, runs of equal tiles
+    /// in a row merged into one rectangle, and the paths as polylines.
+    pub fn to_svg(&self) -> String {
+        let (rows, cols) = self.tiles.dimensions();
+        let mut palette: Vec<Color> = Vec::new();
+        let mut classes: HashMap<Color, usize> = HashMap::new();
+        for (_, &color) in self.tiles.iter() {
+            classes.entry(color).or_insert_with(|| {
+                palette.push(color);
+                palette.len() - 1
+            });
+        }
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {} {}\" \
+             width=\"{}\" height=\"{}\" shape-rendering=\"crispEdges\">",
+            cols,
+            rows,
+            cols * 8,
+            rows * 8
+        );
+        svg.push_str("<style>");
+        for (class, color) in palette.iter().enumerate() {
+            let _ = write!(svg, ".t{}{{fill:{}}}", class, hex(*color));
+        }
+        svg.push_str("</style>\n");
+
+        for (row, tiles) in self.tiles.iter_rows().enumerate() {
+            let mut col = 0;
+            while col < tiles.len() {
+                let run = tiles[col..]
+                    .iter()
+                    .take_while(|&&color| color == tiles[col])
+                    .count();
+                let _ = writeln!(
+                    svg,
+                    "<rect class=\"t{}\" x=\"{}\" y=\"{}\" width=\"{}\" height=\"1\"/>",
+                    classes[&tiles[col]], col, row, run
+                );
+                col += run;
+            }
+        }
+
+        for path in &self.paths {
+            let points: Vec<String> = path
+                .points
+                .iter()
+                .map(|point| format!("{}.5,{}.5", point.col, point.row))
+                .collect();
+            let _ = writeln!(
+                svg,
+                "<polyline points=\"{}\" fill=\"none\" stroke=\"{}\" stroke-width=\"0.3\" \
+                 stroke-linecap=\"round\" stroke-linejoin=\"round\"/>",
+                points.join(" "),
+                hex(path.color)
+            );
+        }
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    /// The pixels of the image, with the paths drawn a third of a tile wide between the tile
+    /// centers.
+    pub(crate) fn rasterize(&self, scale: usize) -> Grid<Color> {
+        let scale = scale.max(1);
+        let (rows, cols) = self.tiles.dimensions();
+        let mut pixels = Grid::new(rows * scale, cols * scale, BACKGROUND);
+        for ((row, col), &color) in self.tiles.iter() {
+            for y in row * scale..(row + 1) * scale {
+                for x in col * scale..(col + 1) * scale {
+                    pixels[(y, x)] = color;
+                }
+            }
+        }
+
+        let width = (scale / 3).max(1) as isize;
+        let center = |point: Point| {
+            let half = scale as isize / 2;
+            (
+                point.row * scale as isize + half,
+                point.col * scale as isize + half,
+            )
+        };
+        for path in &self.paths {
+            for segment in path.points.windows(2) {
+                let (from, to) = (center(segment[0]), center(segment[1]));
+                let steps = (to.0 - from.0).abs().max((to.1 - from.1).abs()).max(1);
+                for step in 0..=steps {
+                    let y = from.0 + (to.0 - from.0) * step / steps;
+                    let x = from.1 + (to.1 - from.1) * step / steps;
+                    for dy in -(width / 2)..width - width / 2 {
+                        for dx in -(width / 2)..width - width / 2 {
+                            if let Some(pixel) = pixels.get_point_mut(Point::new(y + dy, x + dx)) {
+                                *pixel = path.color;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        pixels
+    }
+}
+
+fn hex(color: Color) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+/// Appends a PNG chunk: its length, type, data and the CRC of the type and data.
+fn png_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend((data.len() as u32).to_be_bytes());
+    png.extend(kind);
+    png.extend(data);
+    png.extend(crc32(kind.iter().chain(data)).to_be_bytes());
+}
+
+/// `data` as a zlib stream of uncompressed deflate blocks.
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // Deflate with a 32K window and no preset dictionary; 0x7801 is a multiple of 31 as required.
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(u16::MAX as usize).peekable();
+    if blocks.peek().is_none() {
+        stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        let length = block.len() as u16;
+        stream.push(u8::from(last));
+        stream.extend(length.to_le_bytes());
+        stream.extend((!length).to_le_bytes());
+        stream.extend(block);
+    }
+    stream.extend(adler32(data).to_be_bytes());
+    stream
+}
+
+fn crc32<'a>(bytes: impl IntoIterator<Item = &'a u8>) -> u32 {
+    let mut crc = u32::MAX;
+    for &byte in bytes {
+        crc ^= u32::from(byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image() -> Image {
+        let tiles = Grid::parse_with("#.\n.#\n", |c| match c {
+            '#' => Color::RED,
+            _ => Color::BLUE,
+        })
+        .unwrap();
+        Image::new(tiles)
+    }
+
+    /// The data of every chunk of `png`, by type, checking each CRC.
+    fn chunks(png: &[u8]) -> Vec<(String, Vec<u8>)> {
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        let mut chunks = Vec::new();
+        let mut rest = &png[8..];
+        while !rest.is_empty() {
+            let length = u32::from_be_bytes(rest[..4].try_into().unwrap()) as usize;
+            let (kind, data) = (&rest[4..8], &rest[8..8 + length]);
+            let crc = u32::from_be_bytes(rest[8 + length..12 + length].try_into().unwrap());
+            assert_eq!(crc, crc32(kind.iter().chain(data)));
+            chunks.push((String::from_utf8(kind.to_vec()).unwrap(), data.to_vec()));
+            rest = &rest[12 + length..];
+        }
+        chunks
+    }
+
+    /// Inflates a zlib stream made of stored blocks only.
+    fn inflate_stored(stream: &[u8]) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut rest = &stream[2..];
+        loop {
+            let last = rest[0] & 1 == 1;
+            assert_eq!(rest[0] >> 1, 0, "only stored blocks are expected");
+            let length = u16::from_le_bytes([rest[1], rest[2]]) as usize;
+            data.extend(&rest[5..5 + length]);
+            rest = &rest[5 + length..];
+            if last {
+                break;
+            }
+        }
+        assert_eq!(rest, adler32(&data).to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn test_checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn test_ppm_scales_tiles() {
+        let ppm = image().to_ppm(2);
+        let header = b"P6\n4 4\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+
+        let pixels = &ppm[header.len()..];
+        assert_eq!(pixels.len(), 4 * 4 * 3);
+        assert_eq!(&pixels[..6], &[220, 50, 47, 220, 50, 47]);
+        assert_eq!(&pixels[6..9], &[50, 120, 220]);
+    }
+
+    #[test]
+    fn test_png_holds_the_scanlines() {
+        let png = image().to_png(1);
+        let chunks = chunks(&png);
+        let kinds: Vec<&str> = chunks.iter().map(|(kind, _)| kind.as_str()).collect();
+        assert_eq!(kinds, ["IHDR", "IDAT", "IEND"]);
+        assert_eq!(chunks[0].1, [0, 0, 0, 2, 0, 0, 0, 2, 8, 2, 0, 0, 0]);
+        assert_eq!(
+            inflate_stored(&chunks[1].1),
+            [0, 220, 50, 47, 50, 120, 220, 0, 50, 120, 220, 220, 50, 47]
+        );
+    }
+
+    #[test]
+    fn test_large_png_is_split_into_blocks() {
+        let tiles = Grid::new(200, 200, Color::GREEN);
+        let png = Image::new(tiles).to_png(1);
+        let data = inflate_stored(&chunks(&png)[1].1);
+        assert_eq!(data.len(), 200 * (1 + 3 * 200));
+    }
+
+    #[test]
+    fn test_svg_merges_runs_and_draws_paths() {
+        let tiles = Grid::new(1, 3, Color::BLUE);
+        let svg = Image::new(tiles)
+            .with_path(vec![Point::new(0, 0), Point::new(0, 2)], Color::YELLOW)
+            .to_svg();
+
+        assert!(svg.contains(".t0{fill:#3278dc}"));
+        assert!(svg.contains("<rect class=\"t0\" x=\"0\" y=\"0\" width=\"3\" height=\"1\"/>"));
+        assert!(svg.contains("points=\"0.5,0.5 2.5,0.5\""));
+        assert!(svg.contains("stroke=\"#e6be28\""));
+    }
+
+    #[test]
+    fn test_paths_are_rasterized_over_tiles() {
+        let tiles = Grid::new(1, 3, Color::BLUE);
+        let image =
+            Image::new(tiles).with_path(vec![Point::new(0, 0), Point::new(0, 2)], Color::RED);
+        let ppm = image.to_ppm(3);
+        let header_length = b"P6\n9 3\n255\n".len();
+        let pixel = |row: usize, col: usize| {
+            let start = header_length + 3 * (row * 9 + col);
+            Color::rgb(ppm[start], ppm[start + 1], ppm[start + 2])
+        };
+
+        assert_eq!(pixel(1, 1), Color::RED);
+        assert_eq!(pixel(1, 7), Color::RED);
+        assert_eq!(pixel(0, 4), Color::BLUE);
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        assert_eq!(
+            ImageFormat::from_path(Path::new("maze.PNG")),
+            Some(ImageFormat::Png)
+        );
+        assert_eq!(ImageFormat::from_path(Path::new("maze.gif")), None);
+    }
+}
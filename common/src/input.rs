@@ -0,0 +1,384 @@
+use std::{
+    error, fmt, fs,
+    io::{self, Read},
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use crate::SolveError;
+
+/// Why a puzzle input could not be loaded.
+#[derive(Debug)]
+pub enum InputError {
+    /// The input file does not exist.
+    NotFound(PathBuf),
+    /// The input exists but reading it failed.
+    Io { source: String, error: io::Error },
+    /// The input is empty or only contains whitespace.
+    Empty,
+    /// The input is not valid UTF-8; `line` is 1-based.
+    InvalidUtf8 { line: usize },
+    /// A single-line input has more content after its first line; `line` is 1-based.
+    TrailingContent { line: usize },
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => write!(f, "input not found at {}", path.display()),
+            InputError::Io { source, error } => write!(f, "failed to read {}: {}", source, error),
+            InputError::Empty => write!(f, "input is empty"),
+            InputError::InvalidUtf8 { line } => {
+                write!(f, "input is not valid UTF-8 (line {})", line)
+            }
+            InputError::TrailingContent { line } => {
+                write!(f, "unexpected content after the first line (line {})", line)
+            }
+        }
+    }
+}
+
+impl error::Error for InputError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            InputError::Io { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Where to read a puzzle input from: `-` means stdin, anything else is a path.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum InputSource {
+    Stdin,
+    Path(PathBuf),
+}
+
+impl InputSource {
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            InputSource::Stdin => read_stdin(),
+            InputSource::Path(path) => read_path(path),
+        }
+    }
+}
+
+impl FromStr for InputSource {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == "-" {
+            Ok(InputSource::Stdin)
+        } else {
+            Ok(InputSource::Path(PathBuf::from(value)))
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::Path(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+/// Reads the whole file at `path` as a non-empty UTF-8 puzzle input.
+pub fn read_path(path: impl AsRef<Path>) -> Result<String, InputError> {
+    let path = path.as_ref();
+    let bytes = fs::read(path).map_err(|error| match error.kind() {
+        io::ErrorKind::NotFound => InputError::NotFound(path.to_path_buf()),
+        _ => InputError::Io {
+            source: path.display().to_string(),
+            error,
+        },
+    })?;
+    from_bytes(bytes)
+}
+
+/// Reads stdin until EOF as a non-empty UTF-8 puzzle input.
+pub fn read_stdin() -> Result<String, InputError> {
+    let mut bytes = Vec::new();
+    io::stdin()
+        .read_to_end(&mut bytes)
+        .map_err(|error| InputError::Io {
+            source: "stdin".to_string(),
+            error,
+        })?;
+    from_bytes(bytes)
+}
+
+fn from_bytes(bytes: Vec<u8>) -> Result<String, InputError> {
+    let input = String::from_utf8(bytes).map_err(|error| {
+        let valid_up_to = error.utf8_error().valid_up_to();
+        let line = error.as_bytes()[..valid_up_to]
+            .iter()
+            .filter(|&&byte| byte == b'\n')
+            .count()
+            + 1;
+        InputError::InvalidUtf8 { line }
+    })?;
+
+    if input.trim().is_empty() {
+        return Err(InputError::Empty);
+    }
+    Ok(input)
+}
+
+/// A malformed part of a puzzle input, shown with the line it is on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line number.
+    pub line: usize,
+    /// 1-based column, in characters, of the first offending character.
+    pub col: usize,
+    pub message: String,
+    /// The text of the offending line.
+    pub excerpt: String,
+}
+
+/// Characters of a long line shown on each side of the offending column.
+const EXCERPT_CONTEXT: usize = 30;
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.col, self.message
+        )?;
+
+        // Long lines such as day 9's disk map are cut down to the part around the column.
+        let chars: Vec<char> = self.excerpt.chars().collect();
+        let start = self.col.saturating_sub(1 + EXCERPT_CONTEXT);
+        let end = chars.len().min(self.col + EXCERPT_CONTEXT);
+        let prefix = if start > 0 { "..." } else { "" };
+        let suffix = if end < chars.len() { "..." } else { "" };
+        let shown: String = chars[start.min(end)..end].iter().collect();
+        let caret = prefix.len() + self.col.saturating_sub(1) - start;
+        write!(
+            f,
+            "\n    {}{}{}\n    {:>width$}",
+            prefix,
+            shown,
+            suffix,
+            "^",
+            width = caret + 1
+        )
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// A line of a puzzle input with its 1-based number, so that parse errors can point into it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// An error pointing at the 1-based character column `col` of this line.
+    pub fn error_at_column(&self, col: usize, message: impl Into<String>) -> SolveError {
+        SolveError::Parse(ParseError {
+            line: self.number,
+            col,
+            message: message.into(),
+            excerpt: self.text.to_string(),
+        })
+    }
+
+    /// An error pointing at `part`, a slice of this line, or at the start of the line when `part`
+    /// is not taken from it.
+    pub fn error_at(&self, part: &str, message: impl Into<String>) -> SolveError {
+        self.error_at_column(self.column_of(part), message)
+    }
+
+    /// An error about the line as a whole, pointing at its first character.
+    pub fn error(&self, message: impl Into<String>) -> SolveError {
+        self.error_at_column(1, message)
+    }
+
+    /// Parses `part`, a slice of this line, ignoring surrounding whitespace and pointing at it
+    /// when it is malformed.
+    pub fn parse<T: FromStr>(&self, part: &str) -> Result<T, SolveError> {
+        let value = part.trim();
+        value.parse().map_err(|_| {
+            let message = if value.is_empty() {
+                format!("expected a {}, found nothing", std::any::type_name::<T>())
+            } else {
+                format!("'{}' is not a valid {}", value, std::any::type_name::<T>())
+            };
+            self.error_at(value, message)
+        })
+    }
+
+    /// The 1-based character column where `part` starts in this line.
+    fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+}
+
+/// The lines of `input`, numbered from 1.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(|(index, text)| Line {
+        number: index + 1,
+        text,
+    })
+}
+
+/// Splits the input into its blank-line separated sections.
+pub fn sections(input: &str) -> Vec<Vec<&str>> {
+    numbered_sections(input)
+        .into_iter()
+        .map(|section| section.into_iter().map(|line| line.text).collect())
+        .collect()
+}
+
+/// Like [`sections`], but keeps the number of every line for diagnostics.
+pub fn numbered_sections(input: &str) -> Vec<Vec<Line<'_>>> {
+    let mut sections = vec![Vec::new()];
+    for line in lines(input) {
+        if line.text.trim().is_empty() {
+            if !sections.last().unwrap().is_empty() {
+                sections.push(Vec::new());
+            }
+        } else {
+            sections.last_mut().unwrap().push(line);
+        }
+    }
+    if sections.len() > 1 && sections.last().unwrap().is_empty() {
+        sections.pop();
+    }
+    sections
+}
+
+/// Returns the only line of an input such as day 9's disk map or day 11's stones.
+pub fn single_line(input: &str) -> Result<&str, InputError> {
+    let mut lines = input.lines().enumerate();
+    let first_line = match lines.next() {
+        Some((_, line)) if !line.trim().is_empty() => line,
+        _ => return Err(InputError::Empty),
+    };
+
+    match lines.find(|(_, line)| !line.trim().is_empty()) {
+        Some((index, _)) => Err(InputError::TrailingContent { line: index + 1 }),
+        None => Ok(first_line),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sections() {
+        let input = "47|53\n97|13\n\n75,47\n61,13,29\n";
+        assert_eq!(
+            sections(input),
+            vec![vec!["47|53", "97|13"], vec!["75,47", "61,13,29"]]
+        );
+    }
+
+    #[test]
+    fn test_sections_collapse_repeated_blank_lines() {
+        assert_eq!(sections("a\r\n\r\n\r\nb\n\n"), vec![vec!["a"], vec!["b"]]);
+        assert_eq!(sections("a\nb"), vec![vec!["a", "b"]]);
+        let numbers: Vec<Vec<usize>> = numbered_sections("a\n\n\nb\nc")
+            .iter()
+            .map(|section| section.iter().map(|line| line.number).collect())
+            .collect();
+        assert_eq!(numbers, vec![vec![1], vec![4, 5]]);
+    }
+
+    #[test]
+    fn test_single_line() {
+        assert_eq!(single_line("125 17\n").unwrap(), "125 17");
+        assert_eq!(
+            single_line("2333133121414131402\n\n").unwrap(),
+            "2333133121414131402"
+        );
+        assert!(matches!(single_line(""), Err(InputError::Empty)));
+        assert!(matches!(
+            single_line("125 17\n\n0 1\n"),
+            Err(InputError::TrailingContent { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_errors() {
+        assert!(matches!(
+            from_bytes(b" \n\n".to_vec()),
+            Err(InputError::Empty)
+        ));
+        assert!(matches!(
+            from_bytes(b"ok\nstill ok\n\xff\n".to_vec()),
+            Err(InputError::InvalidUtf8 { line: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_line_parse_points_at_the_value() {
+        let line = Line {
+            number: 7,
+            text: "p=0,4 v=300,-3",
+        };
+        assert_eq!(line.parse::<u8>(" 42").unwrap(), 42);
+
+        let Err(SolveError::Parse(error)) = line.parse::<u8>(&line.text[8..11]) else {
+            panic!("300 does not fit in a u8");
+        };
+        assert_eq!((error.line, error.col), (7, 9));
+        assert_eq!(
+            error.to_string(),
+            "line 7, column 9: '300' is not a valid u8\n    p=0,4 v=300,-3\n            ^"
+        );
+
+        let Err(SolveError::Parse(error)) = line.parse::<u8>("elsewhere") else {
+            panic!("letters are not a u8");
+        };
+        assert_eq!(error.col, 1);
+    }
+
+    #[test]
+    fn test_long_excerpts_are_cut_around_the_column() {
+        let text = format!("{}x{}", "1".repeat(100), "2".repeat(100));
+        let line = Line {
+            number: 1,
+            text: &text,
+        };
+        let error = line.error_at(&text[100..], "'x' is not a digit");
+        let shown = format!("...{}x{}...", "1".repeat(30), "2".repeat(30));
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "line 1, column 101: 'x' is not a digit\n    {}\n    {:>34}",
+                shown, "^"
+            )
+        );
+    }
+
+    #[test]
+    fn test_read_missing_path() {
+        assert!(matches!(
+            read_path("does/not/exist.txt"),
+            Err(InputError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_parse_input_source() {
+        assert_eq!("-".parse::<InputSource>(), Ok(InputSource::Stdin));
+        assert_eq!(
+            "day07/challenge.txt".parse::<InputSource>(),
+            Ok(InputSource::Path(PathBuf::from("day07/challenge.txt")))
+        );
+    }
+}
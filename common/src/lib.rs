@@ -0,0 +1,30 @@
+pub mod animate;
+pub mod answer;
+pub mod differential;
+pub mod example;
+pub mod fuzz;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod logging;
+pub mod memo;
+pub mod panics;
+pub mod params;
+pub mod render;
+pub mod search;
+pub mod solution;
+
+pub use animate::{Animate, Recorder};
+pub use answer::Answer;
+pub use example::Example;
+pub use generate::{Generator, Rng};
+pub use geometry::{Direction, Point};
+pub use grid::Grid;
+pub use image::{Draw, Image};
+pub use input::{InputError, InputSource};
+pub use memo::Memo;
+pub use params::{NoParams, ParamTable};
+pub use render::{Render, Scene};
+pub use solution::{solve, Part, PartAnswers, Solution, SolveError};
@@ -0,0 +1,40 @@
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::{filter::ParseError, EnvFilter};
+
+/// Level used when no filter is given: solvers only log at `debug` and `trace`, so stdout stays
+/// limited to the answers.
+const DEFAULT_FILTER: &str = "warn";
+
+/// Sends the solvers' diagnostics to stderr, filtered by `directives` such as `debug` or
+/// `day17=trace,day21=debug`. Every day logs under its crate name.
+pub fn init(directives: &str) -> Result<(), ParseError> {
+    install(EnvFilter::try_new(directives)?);
+    Ok(())
+}
+
+/// Like [`init`] with the directives from `RUST_LOG`, falling back to warnings only when it is
+/// unset or invalid.
+pub fn init_from_env() {
+    install(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER)));
+}
+
+fn install(filter: EnvFilter) {
+    // A second initialisation, e.g. from another test, keeps the first subscriber.
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .try_init();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reject_invalid_directives() {
+        assert!(init("day17=loud").is_err());
+    }
+}
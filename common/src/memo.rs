@@ -0,0 +1,188 @@
+//! Memoizing recursive counting: a [`Memo`] stores results by key in a map with a fast,
+//! non-cryptographic hash, optionally bounded, and counts how often it saved a computation.
+//! Recursive functions wrap their body in [`Memo::call`], which hands the memo back to them for
+//! the recursive calls.
+
+use std::{fmt, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+/// Results by key, with hit and miss counts.
+#[derive(Clone, Debug)]
+pub struct Memo<K, V> {
+    entries: FxHashMap<K, V>,
+    capacity: Option<usize>,
+    hits: u64,
+    misses: u64,
+}
+
+/// How much a [`Memo`] was used.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+impl MemoStats {
+    /// The share of lookups that found their result, 0 before any lookup.
+    pub fn hit_rate(&self) -> f64 {
+        match self.hits + self.misses {
+            0 => 0.0,
+            lookups => self.hits as f64 / lookups as f64,
+        }
+    }
+}
+
+impl fmt::Display for MemoStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} hits, {} misses ({:.1}% hit rate), {} entries",
+            self.hits,
+            self.misses,
+            100.0 * self.hit_rate(),
+            self.entries
+        )
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Memo<K, V> {
+    pub fn new() -> Self {
+        Memo {
+            entries: FxHashMap::default(),
+            capacity: None,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// A memo that never holds more than `capacity` results: it forgets all of them when it is
+    /// full, which keeps it cheap while the results that matter are computed again quickly.
+    pub fn bounded(capacity: usize) -> Self {
+        Memo {
+            capacity: Some(capacity.max(1)),
+            ..Memo::new()
+        }
+    }
+
+    /// The result stored for `key`, counting the lookup as a hit or a miss.
+    pub fn get(&mut self, key: &K) -> Option<V> {
+        let value = self.entries.get(key).cloned();
+        match value {
+            Some(_) => self.hits += 1,
+            None => self.misses += 1,
+        }
+        value
+    }
+
+    /// Stores `value` for `key`. A full bounded memo forgets its results first, unless `key`
+    /// already has one to replace.
+    pub fn insert(&mut self, key: K, value: V) {
+        if self
+            .capacity
+            .is_some_and(|capacity| self.entries.len() >= capacity)
+            && !self.entries.contains_key(&key)
+        {
+            self.entries.clear();
+        }
+        self.entries.insert(key, value);
+    }
+
+    /// The result for `key`: the stored one, or the one `compute` returns, which is stored.
+    /// `compute` is given the memo so that it can make its recursive calls through it.
+    pub fn call(&mut self, key: K, compute: impl FnOnce(&mut Self) -> V) -> V {
+        if let Some(value) = self.get(&key) {
+            return value;
+        }
+        let value = compute(self);
+        self.insert(key, value.clone());
+        value
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        MemoStats {
+            hits: self.hits,
+            misses: self.misses,
+            entries: self.entries.len(),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: Clone> Default for Memo<K, V> {
+    fn default() -> Self {
+        Memo::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fibonacci(n: u64, memo: &mut Memo<u64, u64>) -> u64 {
+        if n < 2 {
+            return n;
+        }
+        memo.call(n, |memo| fibonacci(n - 1, memo) + fibonacci(n - 2, memo))
+    }
+
+    #[test]
+    fn test_call_computes_each_key_once() {
+        let mut memo = Memo::new();
+
+        assert_eq!(fibonacci(90, &mut memo), 2_880_067_194_370_816_120);
+        // Every key from 2 to 90 misses once, and those up to 88 are hit once when reached again.
+        assert_eq!(
+            memo.stats(),
+            MemoStats {
+                hits: 87,
+                misses: 89,
+                entries: 89,
+            }
+        );
+    }
+
+    #[test]
+    fn test_bounded_memo_forgets_when_full() {
+        let mut memo = Memo::bounded(3);
+        for key in 0..3 {
+            memo.insert(key, key * 10);
+        }
+        assert_eq!(memo.get(&2), Some(20));
+
+        memo.insert(1, 11);
+        assert_eq!(memo.len(), 3);
+        assert_eq!(memo.get(&1), Some(11));
+
+        memo.insert(3, 30);
+        assert_eq!(memo.len(), 1);
+        assert_eq!(memo.get(&0), None);
+        assert_eq!(memo.get(&3), Some(30));
+
+        let mut memo = Memo::bounded(16);
+        assert_eq!(fibonacci(60, &mut memo), 1_548_008_755_920);
+        assert!(memo.len() <= 16);
+    }
+
+    #[test]
+    fn test_stats_display() {
+        let stats = MemoStats {
+            hits: 3,
+            misses: 1,
+            entries: 1,
+        };
+        assert_eq!(
+            stats.to_string(),
+            "3 hits, 1 misses (75.0% hit rate), 1 entries"
+        );
+        assert_eq!(MemoStats::default().hit_rate(), 0.0);
+    }
+}
@@ -0,0 +1,38 @@
+//! Surviving solver bugs: a panic is caught and turned into its message, so that a runner can
+//! report it next to the other answers instead of stopping.
+
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+};
+
+/// Runs `job`, returning the message it panicked with if it does.
+pub fn catch<T>(job: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(job)).map_err(|payload| message(&*payload))
+}
+
+fn message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "panicked".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch_keeps_the_message() {
+        assert_eq!(catch(|| 42), Ok(42));
+        assert_eq!(
+            catch(|| -> u8 { panic!("no {}", "luck") }),
+            Err("no luck".to_string())
+        );
+        assert_eq!(
+            catch(|| std::panic::panic_any(7)),
+            Err("panicked".to_string())
+        );
+    }
+}
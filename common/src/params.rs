@@ -0,0 +1,78 @@
+use serde::{de::DeserializeOwned, Deserialize};
+
+use crate::SolveError;
+
+/// Parameter overrides for one day, e.g. its `[dayNN]` table from `aoc.toml`. Keys that are
+/// missing keep their default value.
+pub type ParamTable = toml::Table;
+
+/// The parameters of a day whose puzzle has none.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NoParams {}
+
+/// Parses TOML such as `width = 11` into a table of overrides.
+pub fn parse_table(source: &str) -> Result<ParamTable, SolveError> {
+    source
+        .parse()
+        .map_err(|error: toml::de::Error| SolveError::Params(error.message().to_string()))
+}
+
+/// Builds the parameters `P` from their defaults and the overrides in `table`.
+pub fn from_table<P: DeserializeOwned>(table: &ParamTable) -> Result<P, SolveError> {
+    toml::Value::Table(table.clone())
+        .try_into()
+        .map_err(|error: toml::de::Error| SolveError::Params(error.message().to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Room {
+        width: usize,
+        height: usize,
+    }
+
+    impl Default for Room {
+        fn default() -> Self {
+            Room {
+                width: 101,
+                height: 103,
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_keys_keep_their_default() {
+        let table = parse_table("width = 11").unwrap();
+        assert_eq!(
+            from_table::<Room>(&table).unwrap(),
+            Room {
+                width: 11,
+                height: 103
+            }
+        );
+        assert_eq!(
+            from_table::<Room>(&ParamTable::new()).unwrap(),
+            Room::default()
+        );
+    }
+
+    #[test]
+    fn test_unknown_and_mistyped_keys_are_rejected() {
+        let unknown = from_table::<Room>(&parse_table("depth = 2").unwrap()).unwrap_err();
+        assert!(unknown
+            .to_string()
+            .starts_with("invalid parameters: unknown field `depth`"));
+
+        let mistyped = from_table::<Room>(&parse_table("width = \"wide\"").unwrap()).unwrap_err();
+        assert!(mistyped
+            .to_string()
+            .starts_with("invalid parameters: invalid type"));
+
+        assert!(from_table::<NoParams>(&parse_table("width = 1").unwrap()).is_err());
+    }
+}
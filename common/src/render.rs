@@ -0,0 +1,262 @@
+//! Drawing grid states for the terminal: a [`Scene`] is a grid of glyphs with colors, onto which
+//! days highlight the cells worth looking at, such as a path or a region, and place markers.
+
+use std::fmt::{self, Write};
+
+use crate::{params, Grid, ParamTable, Point, Solution, SolveError};
+
+/// A day that can draw its parsed input, together with what solving it finds, as a scene.
+pub trait Render: Solution {
+    fn render(input: &Self::Input) -> Result<Scene, SolveError>;
+}
+
+/// Parses `input` for `R`, with the defaults of `R::Params` overridden by `params`, and draws it.
+pub fn render<R: Render>(input: &str, params: &ParamTable) -> Result<Scene, SolveError> {
+    let params: R::Params = params::from_table(params)?;
+    R::render(&R::parse_with(input, &params)?)
+}
+
+/// A 24-bit color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const GRAY: Color = Color::rgb(110, 110, 110);
+    pub const RED: Color = Color::rgb(220, 50, 47);
+    pub const GREEN: Color = Color::rgb(80, 180, 60);
+    pub const YELLOW: Color = Color::rgb(230, 190, 40);
+    pub const BLUE: Color = Color::rgb(50, 120, 220);
+    pub const MAGENTA: Color = Color::rgb(200, 60, 170);
+    pub const CYAN: Color = Color::rgb(40, 170, 190);
+    pub const ORANGE: Color = Color::rgb(230, 120, 30);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color { r, g, b }
+    }
+}
+
+/// Background colors that tell neighbouring areas apart, e.g. the regions of day 12.
+pub const PALETTE: [Color; 8] = [
+    Color::rgb(120, 40, 40),
+    Color::rgb(40, 100, 40),
+    Color::rgb(40, 60, 130),
+    Color::rgb(120, 100, 20),
+    Color::rgb(100, 40, 110),
+    Color::rgb(20, 100, 110),
+    Color::rgb(130, 70, 20),
+    Color::rgb(70, 70, 70),
+];
+
+/// How a cell is drawn; unset colors keep the terminal's own.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+}
+
+impl Style {
+    pub const PLAIN: Style = Style {
+        fg: None,
+        bg: None,
+        bold: false,
+    };
+
+    pub const fn fg(color: Color) -> Style {
+        Style {
+            fg: Some(color),
+            ..Style::PLAIN
+        }
+    }
+
+    pub const fn bg(color: Color) -> Style {
+        Style {
+            bg: Some(color),
+            ..Style::PLAIN
+        }
+    }
+
+    /// This style with `color` behind the glyph.
+    pub const fn on(self, color: Color) -> Style {
+        Style {
+            bg: Some(color),
+            ..self
+        }
+    }
+
+    pub const fn bold(self) -> Style {
+        Style { bold: true, ..self }
+    }
+
+    /// `top` drawn over this style: its colors win where it sets them.
+    pub fn under(self, top: Style) -> Style {
+        Style {
+            fg: top.fg.or(self.fg),
+            bg: top.bg.or(self.bg),
+            bold: self.bold || top.bold,
+        }
+    }
+
+    /// The SGR escape sequence that switches the terminal to this style from the default one.
+    fn ansi(&self) -> String {
+        let mut codes = vec!["0".to_string()];
+        if self.bold {
+            codes.push("1".to_string());
+        }
+        if let Some(Color { r, g, b }) = self.fg {
+            codes.push(format!("38;2;{};{};{}", r, g, b));
+        }
+        if let Some(Color { r, g, b }) = self.bg {
+            codes.push(format!("48;2;{};{};{}", r, g, b));
+        }
+        format!("\x1b[{}m", codes.join(";"))
+    }
+}
+
+/// One drawn cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub glyph: char,
+    pub style: Style,
+}
+
+impl Cell {
+    pub const fn new(glyph: char, style: Style) -> Cell {
+        Cell { glyph, style }
+    }
+
+    pub const fn plain(glyph: char) -> Cell {
+        Cell::new(glyph, Style::PLAIN)
+    }
+}
+
+/// A grid state ready to be drawn. `Display` writes the glyphs alone, [`Scene::to_ansi`] adds
+/// the colors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scene {
+    cells: Grid<Cell>,
+}
+
+impl Scene {
+    /// Draws every cell of `grid` with `cell`.
+    pub fn new<T>(grid: &Grid<T>, cell: impl FnMut(&T) -> Cell) -> Scene {
+        Scene {
+            cells: grid.map(cell),
+        }
+    }
+
+    /// Draws the characters of `grid` with `style`, chosen per character.
+    pub fn from_chars(grid: &Grid<char>, mut style: impl FnMut(char) -> Style) -> Scene {
+        Scene::new(grid, |&c| Cell::new(c, style(c)))
+    }
+
+    /// Draws `style` over the cells at `points`, keeping their glyphs. Points outside of the
+    /// scene are ignored.
+    pub fn highlight(mut self, points: impl IntoIterator<Item = Point>, style: Style) -> Scene {
+        for point in points {
+            if let Some(cell) = self.cells.get_point_mut(point) {
+                cell.style = cell.style.under(style);
+            }
+        }
+        self
+    }
+
+    /// Replaces the cell at `point`, if it is inside of the scene, with `glyph` in `style`.
+    pub fn mark(mut self, point: Point, glyph: char, style: Style) -> Scene {
+        if let Some(cell) = self.cells.get_point_mut(point) {
+            *cell = Cell::new(glyph, cell.style.under(style));
+        }
+        self
+    }
+
+    pub fn cells(&self) -> &Grid<Cell> {
+        &self.cells
+    }
+
+    /// The scene with ANSI escape sequences for its colors, switching styles only between cells
+    /// that differ and resetting at the end of every row.
+    pub fn to_ansi(&self) -> String {
+        let mut out = String::new();
+        for row in self.cells.iter_rows() {
+            let mut current = Style::PLAIN;
+            for cell in row {
+                if cell.style != current {
+                    out.push_str(&cell.style.ansi());
+                    current = cell.style;
+                }
+                out.push(cell.glyph);
+            }
+            if current != Style::PLAIN {
+                out.push_str("\x1b[0m");
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl fmt::Display for Scene {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.iter_rows() {
+            for cell in row {
+                f.write_char(cell.glyph)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn scene() -> Scene {
+        let grid = Grid::parse("#..\n.#.\n").unwrap();
+        Scene::from_chars(&grid, |c| match c {
+            '#' => Style::fg(Color::GRAY),
+            _ => Style::PLAIN,
+        })
+    }
+
+    #[test]
+    fn test_display_writes_glyphs_only() {
+        let scene = scene().mark(Point::new(1, 2), 'E', Style::fg(Color::RED).bold());
+        assert_eq!(scene.to_string(), "#..\n.#E\n");
+    }
+
+    #[test]
+    fn test_highlight_keeps_glyph_and_foreground() {
+        let scene = scene().highlight(
+            [Point::new(0, 0), Point::new(0, 1), Point::new(5, 5)],
+            Style::bg(Color::BLUE),
+        );
+
+        assert_eq!(
+            scene.cells()[(0, 0)],
+            Cell::new('#', Style::fg(Color::GRAY).on(Color::BLUE))
+        );
+        assert_eq!(
+            scene.cells()[(0, 1)],
+            Cell::new('.', Style::bg(Color::BLUE))
+        );
+        assert_eq!(scene.cells()[(1, 0)], Cell::plain('.'));
+    }
+
+    #[test]
+    fn test_ansi_switches_only_between_styles() {
+        let scene = scene().highlight([Point::new(1, 2)], Style::fg(Color::GREEN).bold());
+
+        assert_eq!(
+            scene.to_ansi(),
+            "\x1b[0;38;2;110;110;110m#\x1b[0m..\n\
+             .\x1b[0;38;2;110;110;110m#\x1b[0;1;38;2;80;180;60m.\x1b[0m\n"
+        );
+    }
+}
@@ -0,0 +1,407 @@
+//! Searching grid mazes: a [`SearchSpace`] says which states a search can move between and what
+//! each move costs, and the functions below run the searches of the `pathfinding` crate over it.
+//! A plain maze only needs a cost per tile, through [`GridMaze`]; mazes whose moves depend on
+//! more than the position, such as a facing direction, implement the trait themselves.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::Hash,
+};
+
+use pathfinding::prelude as algorithms;
+
+use crate::{geometry::ORTHOGONAL, Grid, Point};
+
+/// The states a search moves between and the cost of every move.
+pub trait SearchSpace {
+    /// What a search tracks: a position, possibly along with more, like a facing direction.
+    type State: Clone + Eq + Hash;
+
+    /// Where `state` stands on the grid.
+    fn position(state: &Self::State) -> Point;
+
+    /// The states reachable from `state` in one move, with the cost of each move.
+    fn moves(&self, state: &Self::State) -> Vec<(Self::State, usize)>;
+
+    /// A lower bound of the cost from `state` to `goal`, which guides [`astar`]. The default,
+    /// the Manhattan distance, is a lower bound as long as every move to a neighbouring tile
+    /// costs at least 1.
+    fn estimate(&self, state: &Self::State, goal: Point) -> usize {
+        Self::position(state).manhattan_distance(goal)
+    }
+}
+
+/// A grid walked one orthogonal step at a time, where entering a tile costs what `cost` gives
+/// for it and tiles it gives `None` for cannot be entered.
+pub struct GridMaze<'a, T, F> {
+    grid: &'a Grid<T>,
+    cost: F,
+}
+
+impl<'a, T, F: Fn(&T) -> Option<usize>> GridMaze<'a, T, F> {
+    pub fn new(grid: &'a Grid<T>, cost: F) -> Self {
+        GridMaze { grid, cost }
+    }
+
+    pub fn grid(&self) -> &'a Grid<T> {
+        self.grid
+    }
+}
+
+impl<T, F: Fn(&T) -> Option<usize>> SearchSpace for GridMaze<'_, T, F> {
+    type State = Point;
+
+    fn position(state: &Point) -> Point {
+        *state
+    }
+
+    fn moves(&self, &position: &Point) -> Vec<(Point, usize)> {
+        ORTHOGONAL
+            .iter()
+            .map(|&offset| position + offset)
+            .filter_map(|next| Some((next, (self.cost)(self.grid.get_point(next)?)?)))
+            .collect()
+    }
+}
+
+/// One of the cheapest paths from `start` to a state `goal` accepts, both included, and its
+/// cost.
+pub fn dijkstra<S: SearchSpace>(
+    space: &S,
+    start: &S::State,
+    goal: impl FnMut(&S::State) -> bool,
+) -> Option<(Vec<S::State>, usize)> {
+    algorithms::dijkstra(start, |state| space.moves(state), goal)
+}
+
+/// One of the cheapest paths from `start` to a state at `goal`, found with fewer states explored
+/// than [`dijkstra`] thanks to [`SearchSpace::estimate`].
+pub fn astar<S: SearchSpace>(
+    space: &S,
+    start: &S::State,
+    goal: Point,
+) -> Option<(Vec<S::State>, usize)> {
+    algorithms::astar(
+        start,
+        |state| space.moves(state),
+        |state| space.estimate(state, goal),
+        |state| S::position(state) == goal,
+    )
+}
+
+/// Every cheapest path from `start` to a state at `goal`, and their cost.
+pub fn all_shortest_paths<S: SearchSpace>(
+    space: &S,
+    start: &S::State,
+    goal: Point,
+) -> Option<(Vec<Vec<S::State>>, usize)> {
+    algorithms::astar_bag_collect(
+        start,
+        |state| space.moves(state),
+        |state| space.estimate(state, goal),
+        |state| S::position(state) == goal,
+    )
+}
+
+/// The cheapest path from `start` to every state reachable from it.
+pub fn dijkstra_all<S: SearchSpace>(space: &S, start: &S::State) -> ShortestPaths<S::State> {
+    ShortestPaths {
+        start: start.clone(),
+        parents: algorithms::dijkstra_all(start, |state| space.moves(state)),
+    }
+}
+
+/// The result of [`dijkstra_all`]: the state each reachable state is best reached from, from
+/// which the paths are rebuilt.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<S: Eq + Hash> {
+    start: S,
+    parents: HashMap<S, (S, usize)>,
+}
+
+impl<S: Clone + Eq + Hash> ShortestPaths<S> {
+    /// The cost of the cheapest path to `state`, `None` if it cannot be reached.
+    pub fn cost(&self, state: &S) -> Option<usize> {
+        if *state == self.start {
+            return Some(0);
+        }
+        self.parents.get(state).map(|&(_, cost)| cost)
+    }
+
+    /// The cheapest path from the start to `state`, both included.
+    pub fn path(&self, state: &S) -> Option<Vec<S>> {
+        if *state == self.start {
+            return Some(vec![state.clone()]);
+        }
+        self.parents
+            .contains_key(state)
+            .then(|| algorithms::build_path(state, &self.parents))
+    }
+
+    /// The states that can be reached, other than the start, with the cost of reaching each.
+    pub fn reached(&self) -> impl Iterator<Item = (&S, usize)> {
+        self.parents.iter().map(|(state, &(_, cost))| (state, cost))
+    }
+}
+
+/// The number of orthogonal steps from a source to every tile of a grid, found in a single
+/// breadth-first pass.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DistanceMap {
+    source: Point,
+    steps: Grid<Option<usize>>,
+}
+
+impl DistanceMap {
+    /// The steps from `source` to every tile of `grid`, walking only on the tiles `passable`
+    /// accepts. A source outside of the grid reaches nothing.
+    pub fn new<T>(grid: &Grid<T>, source: Point, passable: impl Fn(&T) -> bool) -> DistanceMap {
+        let mut steps = Grid::new(grid.rows(), grid.cols(), None);
+        let mut queue = VecDeque::new();
+        if let Some(distance) = steps.get_point_mut(source) {
+            *distance = Some(0);
+            queue.push_back((source, 0));
+        }
+        while let Some((position, distance)) = queue.pop_front() {
+            for offset in ORTHOGONAL {
+                let next = position + offset;
+                if !grid.get_point(next).is_some_and(&passable) {
+                    continue;
+                }
+                if let Some(unseen @ None) = steps.get_point_mut(next) {
+                    *unseen = Some(distance + 1);
+                    queue.push_back((next, distance + 1));
+                }
+            }
+        }
+        DistanceMap { source, steps }
+    }
+
+    pub fn source(&self) -> Point {
+        self.source
+    }
+
+    /// The steps from the source to `point`, `None` if it cannot be reached.
+    pub fn get(&self, point: Point) -> Option<usize> {
+        self.steps.get_point(point).copied().flatten()
+    }
+
+    pub fn steps(&self) -> &Grid<Option<usize>> {
+        &self.steps
+    }
+
+    /// Every tile the source reaches, itself included, with its distance.
+    pub fn reachable(&self) -> impl Iterator<Item = (Point, usize)> + '_ {
+        self.steps
+            .iter()
+            .filter_map(|(position, &distance)| Some((Point::from(position), distance?)))
+    }
+
+    /// A shortest path from the source to `target`, both included, walked back from `target`
+    /// through tiles one step closer to the source each time.
+    pub fn path_to(&self, target: Point) -> Option<Vec<Point>> {
+        let mut distance = self.get(target)?;
+        let mut path = vec![target];
+        let mut position = target;
+        while distance > 0 {
+            position = ORTHOGONAL
+                .iter()
+                .map(|&offset| position + offset)
+                .find(|&previous| self.get(previous) == Some(distance - 1))?;
+            path.push(position);
+            distance -= 1;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// The distances across a grid from both its start and its end, which answer how long the
+/// shortest path between them is when it has to pass somewhere or may jump.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Route {
+    pub from_start: DistanceMap,
+    pub from_end: DistanceMap,
+}
+
+impl Route {
+    pub fn new<T>(
+        grid: &Grid<T>,
+        start: Point,
+        end: Point,
+        passable: impl Fn(&T) -> bool,
+    ) -> Route {
+        Route {
+            from_start: DistanceMap::new(grid, start, &passable),
+            from_end: DistanceMap::new(grid, end, &passable),
+        }
+    }
+
+    /// The steps of a shortest path from the start to the end, `None` if there is none.
+    pub fn length(&self) -> Option<usize> {
+        self.from_start.get(self.from_end.source())
+    }
+
+    /// The steps of the shortest path from the start to the end that passes through `point`.
+    pub fn via(&self, point: Point) -> Option<usize> {
+        Some(self.from_start.get(point)? + self.from_end.get(point)?)
+    }
+
+    /// Whether `point` is on at least one of the shortest paths from the start to the end.
+    pub fn on_shortest_path(&self, point: Point) -> bool {
+        self.length().is_some() && self.via(point) == self.length()
+    }
+
+    /// The steps of the path that walks from the start to `from`, jumps to `to` in `jump` steps
+    /// whatever lies between, and walks on to the end.
+    pub fn via_shortcut(&self, from: Point, to: Point, jump: usize) -> Option<usize> {
+        Some(self.from_start.get(from)? + jump + self.from_end.get(to)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Direction;
+
+    use super::*;
+
+    const MAZE: &str = "\
+S..#
+.#..
+...E
+";
+
+    fn open(c: &char) -> Option<usize> {
+        (*c != '#').then_some(1)
+    }
+
+    fn end() -> Point {
+        Point::new(2, 3)
+    }
+
+    /// Walking costs 1 and turning a quarter costs 10, as for the reindeer of day 16.
+    struct Turns(Grid<char>);
+
+    impl SearchSpace for Turns {
+        type State = (Point, Direction);
+
+        fn position(&(position, _): &Self::State) -> Point {
+            position
+        }
+
+        fn moves(&self, &(position, direction): &Self::State) -> Vec<(Self::State, usize)> {
+            let mut moves = vec![
+                ((position, direction.turn_left()), 10),
+                ((position, direction.turn_right()), 10),
+            ];
+            let ahead = position + direction.offset();
+            if self.0.get_point(ahead).is_some_and(|c| *c != '#') {
+                moves.push(((ahead, direction), 1));
+            }
+            moves
+        }
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar_agree() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let maze = GridMaze::new(&grid, open);
+        let (path, cost) = dijkstra(&maze, &Point::ZERO, |&position| position == end()).unwrap();
+
+        assert_eq!(cost, 5);
+        assert_eq!(path.first(), Some(&Point::ZERO));
+        assert_eq!(path.last(), Some(&end()));
+        assert_eq!(astar(&maze, &Point::ZERO, end()).unwrap().1, 5);
+    }
+
+    #[test]
+    fn test_tile_costs_add_up() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let muddy = GridMaze::new(&grid, |c| match c {
+            '#' => None,
+            'E' => Some(1),
+            _ => Some(3),
+        });
+        assert_eq!(astar(&muddy, &Point::ZERO, end()).unwrap().1, 13);
+    }
+
+    #[test]
+    fn test_all_shortest_paths() {
+        let grid = Grid::parse("S..\n...\n..E\n").unwrap();
+        let maze = GridMaze::new(&grid, open);
+        let (paths, cost) = all_shortest_paths(&maze, &Point::ZERO, Point::new(2, 2)).unwrap();
+
+        assert_eq!(cost, 4);
+        assert_eq!(paths.len(), 6);
+    }
+
+    #[test]
+    fn test_stateful_moves_pay_for_turns() {
+        let maze = Turns(Grid::parse(MAZE).unwrap());
+        let start = (Point::ZERO, Direction::Right);
+        let (path, cost) = astar(&maze, &start, end()).unwrap();
+
+        // Going down the left side or across the top and down the middle both turn twice, while
+        // the top route down the right side turns three times.
+        assert_eq!(cost, 5 + 2 * 10);
+        assert_eq!(path.last().unwrap().0, end());
+        let (paths, _) = all_shortest_paths(&maze, &start, end()).unwrap();
+        assert_eq!(paths.len(), 2);
+    }
+
+    #[test]
+    fn test_distance_map_reaches_open_tiles() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let distances = DistanceMap::new(&grid, Point::ZERO, |&c| c != '#');
+
+        assert_eq!(distances.get(end()), Some(5));
+        assert_eq!(distances.get(Point::new(1, 1)), None);
+        assert_eq!(distances.get(Point::new(-1, 0)), None);
+        assert_eq!(distances.reachable().count(), 10);
+
+        let path = distances.path_to(end()).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!((path[0], path[5]), (Point::ZERO, end()));
+        assert!(path
+            .windows(2)
+            .all(|step| step[0].manhattan_distance(step[1]) == 1));
+        assert_eq!(distances.path_to(Point::new(1, 1)), None);
+    }
+
+    #[test]
+    fn test_route_answers_detours_and_shortcuts() {
+        let grid = Grid::parse("S.#\n#..\n..E\n").unwrap();
+        let route = Route::new(&grid, Point::ZERO, end_of(&grid), |&c| c != '#');
+
+        assert_eq!(route.length(), Some(4));
+        assert!(route.on_shortest_path(Point::new(1, 1)));
+        assert!(!route.on_shortest_path(Point::new(2, 0)));
+        assert_eq!(route.via(Point::new(2, 0)), Some(6));
+        assert_eq!(route.via(Point::new(0, 2)), None);
+        // Jumping down the middle column saves nothing, since walking it takes two steps too.
+        assert_eq!(
+            route.via_shortcut(Point::new(0, 1), Point::new(2, 1), 2),
+            Some(4)
+        );
+        assert_eq!(route.via_shortcut(Point::ZERO, end_of(&grid), 2), Some(2));
+    }
+
+    fn end_of(grid: &Grid<char>) -> Point {
+        Point::new(grid.rows() as isize - 1, grid.cols() as isize - 1)
+    }
+
+    #[test]
+    fn test_dijkstra_all_rebuilds_paths() {
+        let grid = Grid::parse(MAZE).unwrap();
+        let paths = dijkstra_all(&GridMaze::new(&grid, open), &Point::ZERO);
+
+        assert_eq!(paths.cost(&Point::ZERO), Some(0));
+        assert_eq!(paths.path(&Point::ZERO), Some(vec![Point::ZERO]));
+        assert_eq!(paths.cost(&end()), Some(5));
+        let path = paths.path(&end()).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!(path[0], Point::ZERO);
+        assert_eq!(paths.path(&Point::new(0, 3)), None);
+        assert_eq!(paths.reached().count(), 9);
+    }
+}
@@ -0,0 +1,238 @@
+use std::{error, fmt, path::Path};
+
+use serde::de::DeserializeOwned;
+
+use crate::{
+    grid::GridError,
+    input::{self, InputError, ParseError},
+    params, Answer, Example, ParamTable,
+};
+
+/// One day of the calendar: the input is parsed once and both parts are solved from it.
+pub trait Solution {
+    type Input;
+
+    /// Puzzle constants that differ between the examples and the real input, such as a grid size;
+    /// [`NoParams`](crate::NoParams) for days without any.
+    type Params: Default + DeserializeOwned;
+
+    /// The examples from the puzzle text, checked by [`example_tests!`](crate::example_tests).
+    const EXAMPLES: &'static [Example] = &[];
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError>;
+
+    /// Parses `input` for the given parameters. Days with parameters override this and keep them
+    /// in their input; `parse` then uses the defaults.
+    fn parse_with(input: &str, _params: &Self::Params) -> Result<Self::Input, SolveError> {
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// Reads and parses the puzzle input stored at `path`.
+    fn parse_file(path: impl AsRef<Path>) -> Result<Self::Input, SolveError> {
+        Self::parse(&input::read_path(path)?)
+    }
+}
+
+/// Why a puzzle could not be solved.
+#[derive(Debug)]
+pub enum SolveError {
+    /// The input could not be loaded.
+    Input(InputError),
+    /// The input is not a valid grid.
+    Grid(GridError),
+    /// A line of the input is malformed.
+    Parse(ParseError),
+    /// The input is malformed as a whole, e.g. a map without its start tile.
+    Invalid(String),
+    /// The input is well-formed but has no answer, e.g. the exit cannot be reached.
+    NoSolution(String),
+    /// An intermediate value does not fit in the answer type.
+    Overflow,
+    /// The puzzle parameters do not match the day's parameter struct.
+    Params(String),
+}
+
+impl SolveError {
+    pub fn invalid(message: impl Into<String>) -> Self {
+        SolveError::Invalid(message.into())
+    }
+
+    pub fn no_solution(message: impl Into<String>) -> Self {
+        SolveError::NoSolution(message.into())
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::Input(error) => write!(f, "{}", error),
+            SolveError::Grid(error) => write!(f, "invalid grid: {}", error),
+            SolveError::Parse(error) => write!(f, "{}", error),
+            SolveError::Invalid(message) => write!(f, "invalid input: {}", message),
+            SolveError::NoSolution(message) => write!(f, "no solution: {}", message),
+            SolveError::Overflow => write!(f, "the answer overflowed"),
+            SolveError::Params(message) => write!(f, "invalid parameters: {}", message),
+        }
+    }
+}
+
+impl error::Error for SolveError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            SolveError::Input(error) => Some(error),
+            SolveError::Grid(error) => Some(error),
+            SolveError::Parse(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<InputError> for SolveError {
+    fn from(error: InputError) -> Self {
+        SolveError::Input(error)
+    }
+}
+
+impl From<GridError> for SolveError {
+    fn from(error: GridError) -> Self {
+        match error.into_parse_error() {
+            Ok(error) => SolveError::Parse(error),
+            Err(error) => SolveError::Grid(error),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const BOTH: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
+
+/// The answer, or the reason there is none, for each solved part.
+pub type PartAnswers = Vec<(Part, Result<Answer, SolveError>)>;
+
+/// Parses `input` once, with the defaults of `S::Params` overridden by `params`, and solves each
+/// of the requested `parts` of `S`.
+pub fn solve<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    params: &ParamTable,
+) -> Result<PartAnswers, SolveError> {
+    let parsed = S::parse_with(input, &params::from_table(params)?)?;
+    Ok(parts
+        .iter()
+        .map(|part| {
+            let answer = match part {
+                Part::One => S::part1(&parsed),
+                Part::Two => S::part2(&parsed),
+            };
+            (*part, answer)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Sum;
+
+    impl Solution for Sum {
+        type Input = Vec<i32>;
+        type Params = crate::NoParams;
+
+        fn parse(input: &str) -> Result<Self::Input, SolveError> {
+            input::lines(input)
+                .map(|line| line.parse(line.text))
+                .collect()
+        }
+
+        fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+            Ok(input.iter().sum::<i32>().into())
+        }
+
+        fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+            if input.is_empty() {
+                return Err(SolveError::no_solution("there are no numbers"));
+            }
+            Ok(input.len().into())
+        }
+    }
+
+    fn answers(answers: PartAnswers) -> Vec<(Part, String)> {
+        answers
+            .into_iter()
+            .map(|(part, answer)| {
+                let answer = match answer {
+                    Ok(answer) => answer.to_string(),
+                    Err(error) => error.to_string(),
+                };
+                (part, answer)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_solve_both_parts() {
+        let answers = answers(solve::<Sum>("1\n2\n3\n", &Part::BOTH, &ParamTable::new()).unwrap());
+        assert_eq!(
+            answers,
+            vec![(Part::One, "6".to_string()), (Part::Two, "3".to_string())]
+        );
+    }
+
+    #[test]
+    fn test_solve_single_part() {
+        let answers = answers(solve::<Sum>("4\n5\n", &[Part::Two], &ParamTable::new()).unwrap());
+        assert_eq!(answers, vec![(Part::Two, "2".to_string())]);
+    }
+
+    #[test]
+    fn test_parse_error_names_the_line() {
+        let error = solve::<Sum>("4\nfive\n", &Part::BOTH, &ParamTable::new()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 1: 'five' is not a valid i32\n    five\n    ^"
+        );
+    }
+
+    #[test]
+    fn test_unknown_params_are_rejected() {
+        let params = params::parse_table("width = 11").unwrap();
+        let error = solve::<Sum>("1\n", &Part::BOTH, &params).unwrap_err();
+        assert!(matches!(error, SolveError::Params(_)));
+    }
+
+    #[test]
+    fn test_part_errors_are_reported_per_part() {
+        let answers = answers(solve::<Sum>("", &Part::BOTH, &ParamTable::new()).unwrap());
+        assert_eq!(
+            answers,
+            vec![
+                (Part::One, "0".to_string()),
+                (Part::Two, "no solution: there are no numbers".to_string())
+            ]
+        );
+    }
+}
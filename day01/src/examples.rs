@@ -0,0 +1,19 @@
+use common::Example;
+
+/// The two location lists from the puzzle text.
+pub const EXAMPLE: &str = "\
+3   4
+4   3
+2   5
+1   3
+3   9
+3   3
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "lists",
+    input: EXAMPLE,
+    part1: Some("11"),
+    part2: Some("31"),
+    params: None,
+}];
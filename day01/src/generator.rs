@@ -0,0 +1,23 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day01;
+
+impl Generator for Day01 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// `size` pairs of five-digit location IDs. About a quarter of the right IDs repeat a left
+    /// one, so that part 2 has similarities to add up.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let left: Vec<usize> = (0..size).map(|_| rng.usize_in(10000..=99999)).collect();
+        left.iter()
+            .map(|&left_id| {
+                let right_id = if rng.chance(1, 4) {
+                    *rng.pick(&left)
+                } else {
+                    rng.usize_in(10000..=99999)
+                };
+                format!("{}   {}\n", left_id, right_id)
+            })
+            .collect()
+    }
+}
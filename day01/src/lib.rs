@@ -0,0 +1,78 @@
+use std::collections::HashMap;
+use std::iter::zip;
+
+use common::{input, Answer, Example, NoParams, Solution, SolveError};
+
+mod examples;
+mod generator;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part1((left, right): &Self::Input) -> Result<Answer, SolveError> {
+        let mut left = left.clone();
+        let mut right = right.clone();
+
+        left.sort();
+        right.sort();
+
+        Ok(zip(left.iter(), right.iter())
+            .map(|(l, r)| if l > r { l - r } else { r - l })
+            .collect::<Vec<i32>>()
+            .iter()
+            .sum::<i32>()
+            .into())
+    }
+
+    #[allow(clippy::map_entry)]
+    fn part2((left, right): &Self::Input) -> Result<Answer, SolveError> {
+        let mut right_ocurrences: HashMap<i32, i32> = HashMap::new();
+
+        Ok(left
+            .iter()
+            .map(|&e| {
+                // Dereference e immediately when using it
+                if right_ocurrences.contains_key(&e) {
+                    right_ocurrences.get(&e).unwrap() * e // Dereference properly
+                } else {
+                    let count: i32 = right.iter().filter(|&&x| x == e).count() as i32;
+                    right_ocurrences.insert(e, count); // Use e directly (it is an i32 now)
+                    count * e // No need for dereferencing
+                }
+            })
+            .sum::<i32>() // Sum up the results from the map
+            .into())
+    }
+}
+
+/// The left and right lists of location IDs, one pair per line.
+pub fn parse_input(input: &str) -> Result<(Vec<i32>, Vec<i32>), SolveError> {
+    input::lines(input)
+        .map(|line| {
+            let mut parts = line.text.split_whitespace();
+            let (Some(first), Some(second), None) = (parts.next(), parts.next(), parts.next())
+            else {
+                return Err(line.error("expected two location IDs separated by whitespace"));
+            };
+            Ok((line.parse::<i32>(first)?, line.parse::<i32>(second)?))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day01);
+    common::generator_tests!(Day01, 50);
+    common::parser_fuzz_tests!(Day01, 50);
+}
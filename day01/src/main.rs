@@ -1,6 +1,10 @@
-mod day01;
+use common::{Solution, SolveError};
+use day01::Day01;
 
-fn main() {
-    println!("Day 1 - Part1: {}", day01::part1("challenge_input.txt"));
-    println!("Day 1 - Part2: {}", day01::part2("challenge_input.txt"));
+fn main() -> Result<(), SolveError> {
+    common::logging::init_from_env();
+    let input = Day01::parse_file("challenge_input.txt")?;
+    println!("Day 1 - Part1: {}", Day01::part1(&input)?);
+    println!("Day 1 - Part2: {}", Day01::part2(&input)?);
+    Ok(())
 }
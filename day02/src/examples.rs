@@ -0,0 +1,19 @@
+use common::Example;
+
+/// The six reports from the puzzle text.
+pub const EXAMPLE: &str = "\
+7 6 4 2 1
+1 2 7 8 9
+9 7 6 2 1
+1 3 2 4 5
+8 6 4 4 1
+1 3 6 7 9
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "reports",
+    input: EXAMPLE,
+    part1: Some("2"),
+    part2: Some("4"),
+    params: None,
+}];
@@ -0,0 +1,32 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day02;
+
+impl Generator for Day02 {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// `size` reports of 5 to 8 levels. Most change steadily by 1 to 3 in one direction, and
+    /// some get one level disturbed so the Problem Dampener has work to do.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let length = rng.usize_in(5..=8);
+                let direction = if rng.chance(1, 2) { 1 } else { -1 };
+                let mut level = rng.i64_in(20..=70);
+                let mut levels: Vec<i64> = (0..length)
+                    .map(|_| {
+                        level += direction * rng.i64_in(1..=3);
+                        level
+                    })
+                    .collect();
+                if rng.chance(1, 2) {
+                    let index = rng.usize_in(0..=length - 1);
+                    levels[index] += rng.i64_in(-4..=4);
+                }
+
+                let levels: Vec<String> = levels.iter().map(i64::to_string).collect();
+                format!("{}\n", levels.join(" "))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,313 @@
+use common::{
+    input::{self, Line},
+    Answer, Example, NoParams, Solution, SolveError,
+};
+
+mod examples;
+mod generator;
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Vec<i32>>;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        input::lines(input).map(parse_report).collect()
+    }
+
+    fn part1(reports: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(reports
+            .iter()
+            .filter(|report| report_is_valid(report))
+            .count()
+            .into())
+    }
+
+    fn part2(reports: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(reports
+            .iter()
+            .filter(|report| report_is_valid_using_problem_dampener(report))
+            .count()
+            .into())
+    }
+}
+
+/// Parses the levels of the report on `line`; it needs at least two of them.
+pub fn parse_report(line: Line) -> Result<Vec<i32>, SolveError> {
+    let levels = line
+        .text
+        .split(" ")
+        .map(|element| line.parse::<i32>(element))
+        .collect::<Result<Vec<i32>, SolveError>>()?;
+    if levels.len() < 2 {
+        return Err(line.error("a report needs at least two levels"));
+    }
+    Ok(levels)
+}
+
+/// Whether the levels all increase or all decrease, by 1 to 3 at each step.
+pub(crate) fn report_is_valid(parts: &[i32]) -> bool {
+    let first_number = parts[0];
+    let second_number = parts[1];
+
+    if first_number == second_number {
+        return false;
+    }
+
+    if first_number < second_number {
+        validate_increasing(parts)
+    } else {
+        validate_decreasing(parts)
+    }
+}
+
+fn validate_increasing(parts: &[i32]) -> bool {
+    let mut iter = parts.iter().copied();
+    if let Some(mut previous_number) = iter.next() {
+        for number in iter {
+            if number <= previous_number || number - previous_number > 3 {
+                return false;
+            }
+            previous_number = number;
+        }
+    }
+    true
+}
+
+fn validate_decreasing(parts: &[i32]) -> bool {
+    let mut iter = parts.iter().copied();
+    if let Some(mut previous_number) = iter.next() {
+        for number in iter {
+            if number >= previous_number || previous_number - number > 3 {
+                return false;
+            }
+            previous_number = number;
+        }
+    }
+    true
+}
+
+/// Whether the report is safe once at most one bad level is removed.
+pub(crate) fn report_is_valid_using_problem_dampener(parts: &[i32]) -> bool {
+    let first_number = parts[0];
+    let second_number = parts[1];
+
+    if first_number == second_number {
+        report_is_valid(&parts[1..]);
+    }
+
+    if first_number < second_number {
+        validate_increasing_using_problem_dampener(parts.to_vec())
+    } else {
+        validate_decreasing_using_problem_dampener(parts.to_vec())
+    }
+}
+
+fn validate_increasing_using_problem_dampener(mut parts: Vec<i32>) -> bool {
+    for i in 1..=parts.len() - 2 {
+        let previous_number = parts[i - 1];
+        let number = parts[i];
+        let next_number = parts[i + 1];
+
+        if (number <= previous_number || number - previous_number > 3)
+            || (next_number <= number || next_number - number > 3)
+        {
+            let mut parts_clone = parts.clone();
+            let mut parts_clone2 = parts.clone();
+            parts.remove(i);
+            parts_clone.remove(i + 1);
+            parts_clone2.remove(i - 1);
+            let result1 = report_is_valid(&parts);
+            let result2 = report_is_valid(&parts_clone);
+            let result3 = report_is_valid(&parts_clone2);
+
+            return result1 || result2 || result3;
+        }
+    }
+
+    true
+}
+
+fn validate_decreasing_using_problem_dampener(mut parts: Vec<i32>) -> bool {
+    for i in 1..=parts.len() - 2 {
+        let previous_number = parts[i - 1];
+        let number = parts[i];
+        let next_number = parts[i + 1];
+
+        if (number >= previous_number || previous_number - number > 3)
+            || (next_number >= number || next_number - number > 3)
+        {
+            let mut parts_clone = parts.clone();
+            let mut parts_clone2 = parts.clone();
+            parts.remove(i);
+            parts_clone.remove(i + 1);
+            parts_clone2.remove(i - 1);
+            let result1 = report_is_valid(&parts);
+            let result2 = report_is_valid(&parts_clone);
+            let result3 = report_is_valid(&parts_clone2);
+
+            return result1 || result2 || result3;
+        }
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day02);
+    common::generator_tests!(Day02, 50);
+    common::parser_fuzz_tests!(Day02, 50);
+
+    #[test]
+    fn test_validate_report_cases() {
+        let cases = vec![
+            (
+                "7 6 4 2 1",
+                true,
+                "Safe because the levels are all decreasing by 1 or 2.",
+            ),
+            (
+                "1 2 7 8 9",
+                false,
+                "Unsafe because 2 7 is an increase of 5.",
+            ),
+            ("9 7 6 2 1", false, "Unsafe because 6 2 is a decrease of 4."),
+            (
+                "1 3 2 4 5",
+                false,
+                "Unsafe because 1 3 is increasing but 3 2 is decreasing.",
+            ),
+            (
+                "8 6 4 4 1",
+                false,
+                "Unsafe because 4 4 is neither an increase or a decrease.",
+            ),
+            (
+                "1 3 6 7 9",
+                true,
+                "Safe because the levels are all increasing by 1, 2, or 3.",
+            ),
+        ];
+
+        for (line_without_current, expected, reason) in cases {
+            assert_eq!(
+                report_is_valid(
+                    &parse_report(Line {
+                        number: 1,
+                        text: line_without_current
+                    })
+                    .unwrap()
+                ),
+                expected,
+                "Failed on input '{}': {}",
+                line_without_current,
+                reason
+            );
+        }
+    }
+
+    #[test]
+    fn test_report_is_valid_using_problem_dampener() {
+        let line_without_current = "1 3 2 4 5";
+        assert!(report_is_valid_using_problem_dampener(
+            &parse_report(Line {
+                number: 1,
+                text: line_without_current
+            })
+            .unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_validate_report_using_problem_dampener_cases() {
+        let cases = vec![
+            (
+                "7 6 4 2 1",
+                true,
+                "Safe because the levels are all decreasing by 1 or 2.",
+            ),
+            (
+                "1 2 7 8 9",
+                false,
+                "Unsafe because 2 7 is an increase of 5.",
+            ),
+            ("9 7 6 2 1", false, "Unsafe because 6 2 is a decrease of 4."),
+            (
+                "1 3 2 4 5",
+                true,
+                "Unsafe because 1 3 is increasing but 3 2 is decreasing.",
+            ),
+            (
+                "8 6 4 4 1",
+                true,
+                "Unsafe because 4 4 is neither an increase or a decrease.",
+            ),
+            (
+                "1 3 6 7 9",
+                true,
+                "Safe because the levels are all increasing by 1, 2, or 3.",
+            ),
+        ];
+
+        for (line_without_current, expected, reason) in cases {
+            assert_eq!(
+                report_is_valid_using_problem_dampener(
+                    &parse_report(Line {
+                        number: 1,
+                        text: line_without_current
+                    })
+                    .unwrap()
+                ),
+                expected,
+                "Failed on input '{}': {}",
+                line_without_current,
+                reason
+            );
+        }
+    }
+
+    #[test]
+    fn test_line_part2() {
+        let line = "21 24 21 19 17 14";
+        assert!(report_is_valid_using_problem_dampener(
+            &parse_report(Line {
+                number: 1,
+                text: line
+            })
+            .unwrap()
+        ));
+
+        let line = "30 32 29 27 25 20";
+        assert!(!report_is_valid_using_problem_dampener(
+            &parse_report(Line {
+                number: 1,
+                text: line
+            })
+            .unwrap()
+        ));
+
+        let line = "80 80 78 75 74 72 69 71";
+        assert!(!report_is_valid_using_problem_dampener(
+            &parse_report(Line {
+                number: 1,
+                text: line
+            })
+            .unwrap()
+        ));
+
+        let line = "45 45 44 42 40 38 41 41";
+        assert!(!report_is_valid_using_problem_dampener(
+            &parse_report(Line {
+                number: 1,
+                text: line
+            })
+            .unwrap()
+        ));
+    }
+}
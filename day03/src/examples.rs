@@ -0,0 +1,28 @@
+use common::Example;
+
+/// The corrupted memory from part 1.
+pub const MULTIPLICATIONS: &str = "\
+xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))
+";
+
+/// The corrupted memory from part 2, with `do()` and `don't()` instructions.
+pub const CONDITIONALS: &str = "\
+xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "multiplications",
+        input: MULTIPLICATIONS,
+        part1: Some("161"),
+        part2: None,
+        params: None,
+    },
+    Example {
+        name: "conditionals",
+        input: CONDITIONALS,
+        part1: None,
+        part2: Some("48"),
+        params: None,
+    },
+];
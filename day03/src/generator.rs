@@ -0,0 +1,63 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day03;
+
+/// Characters scattered between the instructions; some of them start instructions that never
+/// complete, such as `mul(4*` or `do_not`.
+const NOISE: &[&str] = &[
+    "%",
+    "&",
+    "*",
+    "@",
+    "^",
+    "!",
+    "[",
+    "]",
+    "<",
+    ">",
+    "+",
+    "?",
+    " ",
+    "who()",
+    "select()",
+    "mul(4*",
+    "mul ( 2 , 4 )",
+    "mul[3,7]",
+    "do_not",
+    "from()",
+    "'",
+    ",",
+    ")",
+    "(",
+    "where()",
+];
+
+impl Generator for Day03 {
+    const DEFAULT_SIZE: usize = 800;
+
+    /// `size` instructions, mostly `mul(X,Y)` with one to three digit operands and a few
+    /// `do()` and `don't()`, surrounded by corrupted characters and split over lines of about
+    /// 100 instructions.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut memory = String::new();
+        for index in 0..size {
+            for _ in 0..rng.usize_in(0..=3) {
+                memory.push_str(rng.pick::<&str>(NOISE));
+            }
+            match rng.usize_in(0..=19) {
+                0 => memory.push_str("do()"),
+                1 => memory.push_str("don't()"),
+                _ => memory.push_str(&format!(
+                    "mul({},{})",
+                    rng.usize_in(1..=999),
+                    rng.usize_in(1..=999)
+                )),
+            }
+            if index % 100 == 99 {
+                memory.push('\n');
+            }
+        }
+        memory.push('\n');
+        memory
+    }
+}
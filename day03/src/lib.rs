@@ -0,0 +1,82 @@
+use common::{Answer, Example, NoParams, Solution, SolveError};
+use regex::Regex;
+
+mod examples;
+mod generator;
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// The corrupted memory, with its lines joined back together.
+    type Input = String;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.lines().collect())
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        let pattern = r"mul\(\d{1,3},\d{1,3}\)"; // Regex pattern
+
+        // Create regex object
+        let re = Regex::new(pattern).expect("Invalid regex pattern");
+
+        // Find all matches
+        Ok(re
+            .find_iter(input)
+            .map(|m| i64::from(parse_and_multiply(m.as_str())))
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let pattern = r"(mul\(\d{1,3},\d{1,3}\)|do\(\)|don't\(\))"; // Regex pattern
+
+        // Create regex object
+        let re = Regex::new(pattern).expect("Invalid regex pattern");
+        let mut matches: Vec<&str> = re.find_iter(input).map(|m| m.as_str()).collect();
+
+        let mut mul_enabled = true;
+        let mut sum: i64 = 0;
+        for item in matches.iter_mut() {
+            if mul_enabled {
+                if item.contains("mul") {
+                    sum += i64::from(parse_and_multiply(item));
+                } else if *item == "don't()" {
+                    mul_enabled = false;
+                }
+            }
+
+            if *item == "do()" {
+                mul_enabled = true;
+            }
+        }
+
+        Ok(sum.into())
+    }
+}
+
+/// The product of the two numbers in a `mul(X,Y)` instruction.
+pub(crate) fn parse_and_multiply(m: &str) -> i32 {
+    let result = m.replace("mul(", "").replace(")", "");
+    let (x, y) = result.split_once(",").unwrap();
+    x.parse::<i32>().unwrap_or(0) * y.parse::<i32>().unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day03);
+    common::generator_tests!(Day03, 50);
+    common::parser_fuzz_tests!(Day03, 50);
+
+    #[test]
+    fn test_numbers_have_at_most_three_digits() {
+        let memory = Day03::parse("mul(99999,99999)mul(999,999)mul(1234,2)").unwrap();
+        assert_eq!(Day03::part1(&memory).unwrap().to_string(), "998001");
+        assert_eq!(Day03::part2(&memory).unwrap().to_string(), "998001");
+    }
+}
@@ -0,0 +1,23 @@
+use common::Example;
+
+/// The word search from the puzzle text.
+pub const EXAMPLE: &str = "\
+MMMSXXMASM
+MSAMXMSMSA
+AMXSXMAAMM
+MSAMASMSMX
+XMASAMXAMM
+XXAMMXXAMA
+SMSMSASXSS
+SAXAMASAAA
+MAMMMXMMMM
+MXMXAXMASX
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "word search",
+    input: EXAMPLE,
+    part1: Some("18"),
+    part2: Some("9"),
+    params: None,
+}];
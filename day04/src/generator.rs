@@ -0,0 +1,20 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day04;
+
+impl Generator for Day04 {
+    const DEFAULT_SIZE: usize = 140;
+
+    /// A `size` by `size` word search of the letters X, M, A and S only.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let mut row: String = (0..size)
+                    .map(|_| *rng.pick(&['X', 'M', 'A', 'S']))
+                    .collect();
+                row.push('\n');
+                row
+            })
+            .collect()
+    }
+}
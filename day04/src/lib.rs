@@ -0,0 +1,102 @@
+use common::{
+    geometry::ALL_DIRECTIONS, Answer, Example, Grid, NoParams, Point, Solution, SolveError,
+};
+
+mod examples;
+mod generator;
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<char>;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(matrix: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(matrix
+            .find_all(&'X')
+            .map(|(row, col)| find_xmas(matrix, &row, &col))
+            .sum::<i32>()
+            .into())
+    }
+
+    fn part2(matrix: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(matrix
+            .find_all(&'A')
+            .filter(|&(row, col)| {
+                row != 0 && row != matrix.rows() - 1 && col != 0 && col != matrix.cols() - 1
+            })
+            .map(|(row, col)| find_mas_in_x_shape(matrix, &row, &col))
+            .sum::<i32>()
+            .into())
+    }
+}
+
+/// The number of directions in which XMAS is spelled from the X at `row`, `col`.
+pub fn find_xmas(matrix: &Grid<char>, row: &usize, col: &usize) -> i32 {
+    let mut count = 0;
+    let search_count = 3;
+    for direction in ALL_DIRECTIONS {
+        let mut current = Point::from((*row, *col)) + direction;
+        let mut next_letters = vec!['M', 'A', 'S'].into_iter();
+
+        for i in 0..search_count {
+            let Some(&current_letter) = matrix.get_point(current) else {
+                break;
+            };
+            let next_letter = next_letters.next().unwrap();
+            if current_letter != next_letter {
+                break;
+            } else if current_letter == 'S' && i == search_count - 1 {
+                count += 1;
+            }
+            current += direction;
+        }
+    }
+    count
+}
+
+/// 1 if the A at `row`, `col` is the middle of two crossed MAS, 0 otherwise.
+pub(crate) fn find_mas_in_x_shape(matrix: &Grid<char>, row: &usize, col: &usize) -> i32 {
+    let center = Point::from((*row, *col));
+
+    let top_left_letter = matrix[center + Point::UP_LEFT];
+    if top_left_letter == 'M' || top_left_letter == 'S' {
+        let expected_bottom_right_letter = if top_left_letter == 'M' { 'S' } else { 'M' };
+        let bottom_right_letter = matrix[center + Point::DOWN_RIGHT];
+
+        if bottom_right_letter == expected_bottom_right_letter {
+            let top_right_letter = matrix[center + Point::UP_RIGHT];
+
+            if top_right_letter == 'M' || top_right_letter == 'S' {
+                let expected_bottom_left_letter = if top_right_letter == 'M' { 'S' } else { 'M' };
+
+                if matrix[center + Point::DOWN_LEFT] == expected_bottom_left_letter {
+                    return 1;
+                }
+            }
+        }
+    }
+
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day04);
+    common::generator_tests!(Day04, 10);
+    common::parser_fuzz_tests!(Day04, 10);
+
+    #[test]
+    fn test_parse_input_to_matrix_using_example() {
+        let input = Day04::parse(examples::EXAMPLE).unwrap();
+        assert_eq!(input.dimensions(), (10, 10));
+    }
+}
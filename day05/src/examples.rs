@@ -0,0 +1,41 @@
+use common::Example;
+
+/// The page ordering rules and updates from the puzzle text.
+pub const EXAMPLE: &str = "\
+47|53
+97|13
+97|61
+97|47
+75|29
+61|13
+75|53
+29|13
+97|29
+53|29
+61|53
+97|53
+61|29
+47|13
+75|47
+97|75
+47|61
+75|61
+47|29
+75|13
+53|13
+
+75,47,61,53,29
+97,61,53,29,13
+75,29,13
+75,97,47,61,53
+61,13,29
+97,13,75,29,47
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "print queue",
+    input: EXAMPLE,
+    part1: Some("143"),
+    part2: Some("123"),
+    params: None,
+}];
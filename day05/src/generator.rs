@@ -0,0 +1,48 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day05;
+
+/// Pages the rules and updates are drawn from, as in the real puzzle.
+const PAGES: usize = 49;
+
+impl Generator for Day05 {
+    const DEFAULT_SIZE: usize = 200;
+
+    /// `size` updates. The rules order every pair of 49 two-digit pages following one random
+    /// order, so they never form a cycle; about half of the updates follow that order.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut candidates: Vec<usize> = (10..=99).collect();
+        rng.shuffle(&mut candidates);
+        let order = &candidates[..PAGES];
+
+        let mut rules: Vec<(usize, usize)> = (0..PAGES)
+            .flat_map(|first| (first + 1..PAGES).map(move |second| (first, second)))
+            .map(|(first, second)| (order[first], order[second]))
+            .collect();
+        rng.shuffle(&mut rules);
+
+        let mut input: String = rules
+            .iter()
+            .map(|(before, after)| format!("{}|{}\n", before, after))
+            .collect();
+        input.push('\n');
+
+        for _ in 0..size {
+            let length = 2 * rng.usize_in(2..=11) + 1;
+            let mut positions: Vec<usize> = (0..PAGES).collect();
+            rng.shuffle(&mut positions);
+            let positions = &mut positions[..length];
+            if rng.chance(1, 2) {
+                positions.sort();
+            }
+
+            let update: Vec<String> = positions
+                .iter()
+                .map(|&position| order[position].to_string())
+                .collect();
+            input.push_str(&update.join(","));
+            input.push('\n');
+        }
+        input
+    }
+}
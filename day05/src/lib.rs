@@ -0,0 +1,457 @@
+use std::collections::{HashMap, HashSet};
+
+use common::{
+    input::{self, Line},
+    Answer, Example, NoParams, Solution, SolveError,
+};
+use graph::prelude::*;
+
+mod examples;
+mod generator;
+#[cfg(test)]
+mod reference;
+
+pub struct PrintQueue {
+    pub page_ordering_rules: Vec<(i32, i32)>,
+    pub pages_to_produce: Vec<Vec<i32>>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = PrintQueue;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let sections = input::numbered_sections(input);
+        let page_ordering_rules = &sections[0];
+        let pages_to_produce = sections.get(1).map(Vec::as_slice).unwrap_or_default();
+        Ok(PrintQueue {
+            page_ordering_rules: parse_page_ordering_rules_to_list_of_tuples(page_ordering_rules)?,
+            pages_to_produce: parse_pages_to_produce_vec_of_vec(pages_to_produce)?,
+        })
+    }
+
+    fn part1(print_queue: &Self::Input) -> Result<Answer, SolveError> {
+        let page_ordering_rules = &print_queue.page_ordering_rules;
+        Ok(print_queue
+            .pages_to_produce
+            .iter()
+            .filter_map(|x| {
+                if validate_line(x, page_ordering_rules) {
+                    Some(get_middle_element(x))
+                } else {
+                    None
+                }
+            })
+            .sum::<i32>()
+            .into())
+    }
+
+    fn part2(print_queue: &Self::Input) -> Result<Answer, SolveError> {
+        let page_ordering_rules = &print_queue.page_ordering_rules;
+        Ok(print_queue
+            .pages_to_produce
+            .iter()
+            .filter_map(|x| {
+                if !validate_line(x, page_ordering_rules) {
+                    let fixed_line = fix_invalid_line(x, page_ordering_rules);
+                    Some(get_middle_element(&fixed_line))
+                } else {
+                    None
+                }
+            })
+            .sum::<i32>()
+            .into())
+    }
+}
+
+pub fn part1_using_is_sorted_by(print_queue: &PrintQueue) -> i32 {
+    let page_ordering_rules = &print_queue.page_ordering_rules;
+    print_queue
+        .pages_to_produce
+        .iter()
+        .filter_map(|page| {
+            let is_sorted_using_rules = page.iter().is_sorted_by(|a, b| {
+                page_ordering_rules
+                    .iter()
+                    .find(|(x, y)| **a == *x && **b == *y)
+                    .is_some()
+            });
+
+            if is_sorted_using_rules {
+                Some(get_middle_element(page))
+            } else {
+                None
+            }
+        })
+        .sum()
+}
+
+/// The `X|Y` rules as pairs: page X must be printed before page Y.
+pub fn parse_page_ordering_rules_to_list_of_tuples(
+    page_ordering_rules: &[Line],
+) -> Result<Vec<(i32, i32)>, SolveError> {
+    page_ordering_rules
+        .iter()
+        .map(|line| {
+            let Some(string_tuple) = line.text.split_once("|") else {
+                return Err(line.error("expected a 'X|Y' ordering rule"));
+            };
+            Ok((
+                line.parse::<i32>(string_tuple.0)?,
+                line.parse::<i32>(string_tuple.1)?,
+            ))
+        })
+        .collect()
+}
+
+/// The page numbers of each update.
+pub fn parse_pages_to_produce_vec_of_vec(
+    pages_to_produce: &[Line],
+) -> Result<Vec<Vec<i32>>, SolveError> {
+    pages_to_produce
+        .iter()
+        .map(|&line| parse_page_line_to_vector(line))
+        .collect()
+}
+
+fn parse_page_line_to_vector(line: Line) -> Result<Vec<i32>, SolveError> {
+    line.text.split(',').map(|s| line.parse::<i32>(s)).collect()
+}
+
+/// The rules that mention only pages of the update.
+pub fn find_applicable_rules_by_page_line(
+    page_line: &[i32],
+    page_ordering_rules: &[(i32, i32)],
+) -> Vec<(i32, i32)> {
+    let mut applicable_rules = Vec::new();
+    let page_line_hashset: HashSet<i32> = page_line.iter().cloned().collect();
+    for rule in page_ordering_rules {
+        if page_line.contains(&rule.0) && page_line_hashset.contains(&rule.1) {
+            applicable_rules.push(*rule);
+        }
+    }
+
+    applicable_rules
+}
+
+/// Whether the update prints its pages in an order the rules allow.
+pub fn validate_line(page_line: &[i32], page_ordering_rules: &[(i32, i32)]) -> bool {
+    let applicable_rules = find_applicable_rules_by_page_line(page_line, page_ordering_rules);
+    let mut valid = true;
+    for rule in applicable_rules {
+        let index_first_page = page_line.iter().position(|&x| x == rule.0).unwrap();
+        let index_second_page = page_line.iter().position(|&x| x == rule.1).unwrap();
+        if index_first_page > index_second_page {
+            valid = false;
+            break;
+        }
+    }
+
+    valid
+}
+
+fn get_middle_element(vec: &[i32]) -> i32 {
+    let mid_index = vec.len() / 2;
+
+    // If you want the "lower" middle in case of an even-length vector
+    vec[mid_index]
+}
+
+/// The pages of the update put in an order the rules allow.
+pub fn fix_invalid_line(page_line: &[i32], page_ordering_rules: &[(i32, i32)]) -> Vec<i32> {
+    let applicable_rules = find_applicable_rules_by_page_line(page_line, page_ordering_rules);
+    let sorted_vertexes = topological_sort(applicable_rules);
+    let mut new_page_line = Vec::with_capacity(page_line.len());
+    for vertex in sorted_vertexes {
+        if page_line.contains(&vertex) {
+            new_page_line.push(vertex);
+        }
+    }
+
+    new_page_line
+}
+
+/// The pages ordered so that each rule's first page comes before its second.
+pub fn topological_sort(applicable_rules: Vec<(i32, i32)>) -> Vec<i32> {
+    let graph: DirectedCsrGraph<i32> = GraphBuilder::new()
+        .csr_layout(CsrLayout::Sorted)
+        .edges(applicable_rules.iter().cloned())
+        .build();
+
+    let mut vertexes_with_in_degree_zero: Vec<i32> = Vec::new();
+    let mut graph_hashmap: HashMap<i32, i32> = HashMap::new();
+
+    for rule in applicable_rules
+        .iter()
+        .flat_map(|(x, y)| vec![*x, *y])
+        .collect::<HashSet<_>>()
+    {
+        if graph.in_degree(rule) == 0 {
+            vertexes_with_in_degree_zero.push(rule);
+        } else {
+            graph_hashmap.insert(rule, graph.in_degree(rule));
+        }
+    }
+
+    let mut sorted_vertexes: Vec<i32> = Vec::with_capacity(graph_hashmap.len());
+
+    while !vertexes_with_in_degree_zero.is_empty() {
+        let current_vertex = vertexes_with_in_degree_zero.remove(0);
+        sorted_vertexes.push(current_vertex);
+
+        let adjacent_vertexes = graph
+            .out_neighbors(current_vertex)
+            .cloned()
+            .collect::<Vec<i32>>();
+
+        for adjacent_vertex in adjacent_vertexes {
+            if let Some(in_degree) = graph_hashmap.get_mut(&adjacent_vertex) {
+                *in_degree -= 1;
+                if *in_degree == 0 {
+                    vertexes_with_in_degree_zero.push(adjacent_vertex);
+                }
+            }
+        }
+    }
+    sorted_vertexes
+}
+
+#[cfg(test)]
+mod tests {
+    use common::differential::check_agreement;
+
+    use super::*;
+
+    common::example_tests!(Day05);
+    common::generator_tests!(Day05, 10);
+    common::parser_fuzz_tests!(Day05, 10);
+
+    #[test]
+    fn test_part1_variants_agree_with_brute_force() {
+        check_agreement::<Day05, _>(
+            8,
+            |queue| reference::part1(queue).into(),
+            |queue| Day05::part1(queue).unwrap(),
+        );
+        check_agreement::<Day05, _>(8, reference::part1, part1_using_is_sorted_by);
+    }
+
+    #[test]
+    fn test_part2_agrees_with_brute_force() {
+        check_agreement::<Day05, _>(
+            8,
+            |queue| reference::part2(queue).into(),
+            |queue| Day05::part2(queue).unwrap(),
+        );
+    }
+
+    #[test]
+    fn test_malformed_update_names_its_line() {
+        let Err(error) = Day05::parse("47|53\n97|13\n\n75,x,61\n") else {
+            panic!("the update on line 4 is malformed");
+        };
+        assert_eq!(
+            error.to_string(),
+            "line 4, column 4: 'x' is not a valid i32\n    75,x,61\n       ^"
+        );
+    }
+
+    #[test]
+    fn test_find_applicable_rules_by_page_line() {
+        let page_line = parse_page_line_to_vector(Line {
+            number: 1,
+            text: "75,47,61,53,29",
+        })
+        .unwrap();
+        let page_ordering_rules = vec![
+            (47, 53),
+            (97, 13),
+            (97, 61),
+            (97, 47),
+            (75, 29),
+            (61, 13),
+            (75, 53),
+            (29, 13),
+            (97, 29),
+            (53, 29),
+            (61, 53),
+            (97, 53),
+            (61, 29),
+            (47, 13),
+            (75, 47),
+            (97, 75),
+            (47, 61),
+            (75, 61),
+            (47, 29),
+            (75, 13),
+            (53, 13),
+        ];
+        let applicable_rules = find_applicable_rules_by_page_line(&page_line, &page_ordering_rules);
+        let expected_rules = vec![
+            (47, 53),
+            (75, 29),
+            (75, 53),
+            (53, 29),
+            (61, 53),
+            (61, 29),
+            (75, 47),
+            (47, 61),
+            (75, 61),
+            (47, 29),
+        ];
+        assert!(applicable_rules.len() == expected_rules.len());
+        assert!(applicable_rules == expected_rules);
+    }
+
+    #[test]
+    fn test_validate_line_that_is_valid() {
+        let page_line = parse_page_line_to_vector(Line {
+            number: 1,
+            text: "75,47,61,53,29",
+        })
+        .unwrap();
+        let page_ordering_rules = vec![
+            (47, 53),
+            (97, 13),
+            (97, 61),
+            (97, 47),
+            (75, 29),
+            (61, 13),
+            (75, 53),
+            (29, 13),
+            (97, 29),
+            (53, 29),
+            (61, 53),
+            (97, 53),
+            (61, 29),
+            (47, 13),
+            (75, 47),
+            (97, 75),
+            (47, 61),
+            (75, 61),
+            (47, 29),
+            (75, 13),
+            (53, 13),
+        ];
+        assert!(validate_line(&page_line, &page_ordering_rules));
+    }
+
+    #[test]
+    fn test_validate_line_that_is_invalid() {
+        let page_line = parse_page_line_to_vector(Line {
+            number: 1,
+            text: "75,97,47,61,53",
+        })
+        .unwrap();
+        let page_ordering_rules = vec![
+            (47, 53),
+            (97, 13),
+            (97, 61),
+            (97, 47),
+            (75, 29),
+            (61, 13),
+            (75, 53),
+            (29, 13),
+            (97, 29),
+            (53, 29),
+            (61, 53),
+            (97, 53),
+            (61, 29),
+            (47, 13),
+            (75, 47),
+            (97, 75),
+            (47, 61),
+            (75, 61),
+            (47, 29),
+            (75, 13),
+            (53, 13),
+        ];
+        assert!(!validate_line(&page_line, &page_ordering_rules));
+    }
+
+    #[test]
+    fn test_fix_invalid_line() {
+        let page_ordering_rules = vec![
+            (47, 53),
+            (97, 13),
+            (97, 61),
+            (97, 47),
+            (75, 29),
+            (61, 13),
+            (75, 53),
+            (29, 13),
+            (97, 29),
+            (53, 29),
+            (61, 53),
+            (97, 53),
+            (61, 29),
+            (47, 13),
+            (75, 47),
+            (97, 75),
+            (47, 61),
+            (75, 61),
+            (47, 29),
+            (75, 13),
+            (53, 13),
+        ];
+
+        let page_line = parse_page_line_to_vector(Line {
+            number: 1,
+            text: "75,97,47,61,53",
+        })
+        .unwrap();
+        let expected_fixed_page_line = vec![97, 75, 47, 61, 53];
+        assert_eq!(
+            fix_invalid_line(&page_line, &page_ordering_rules),
+            expected_fixed_page_line
+        );
+
+        let page_line = parse_page_line_to_vector(Line {
+            number: 1,
+            text: "61,13,29",
+        })
+        .unwrap();
+        let expected_fixed_page_line = vec![61, 29, 13];
+        assert_eq!(
+            fix_invalid_line(&page_line, &page_ordering_rules),
+            expected_fixed_page_line
+        );
+
+        let page_line = parse_page_line_to_vector(Line {
+            number: 1,
+            text: "97,13,75,29,47",
+        })
+        .unwrap();
+        let expected_fixed_page_line = vec![97, 75, 47, 29, 13];
+        assert_eq!(
+            fix_invalid_line(&page_line, &page_ordering_rules),
+            expected_fixed_page_line
+        );
+    }
+
+    #[test]
+    #[ignore = "needs the personal puzzle input in challenge.txt"]
+    fn test_fix_invalid_line_from_challenge() {
+        let page_ordering_rules = Day05::parse_file("challenge.txt")
+            .unwrap()
+            .page_ordering_rules;
+        let page_line = vec![
+            93, 36, 64, 57, 94, 66, 13, 32, 37, 78, 73, 19, 25, 84, 17, 31, 87, 47, 42, 59, 81, 91,
+            95,
+        ];
+        let fixed_page_line = fix_invalid_line(&page_line, &page_ordering_rules);
+        assert_eq!(fixed_page_line.len(), page_line.len());
+    }
+
+    #[test]
+    fn test_part1_using_is_sorted_by() {
+        assert_eq!(
+            part1_using_is_sorted_by(&Day05::parse(examples::EXAMPLE).unwrap()),
+            143
+        );
+    }
+}
@@ -0,0 +1,38 @@
+//! Brute-force answers for differential tests of the optimized solver.
+
+use crate::PrintQueue;
+
+/// Whether no later page of `update` must come before an earlier one, checking every pair.
+fn is_ordered(update: &[i32], rules: &[(i32, i32)]) -> bool {
+    (0..update.len()).all(|first| {
+        (first + 1..update.len()).all(|second| !rules.contains(&(update[second], update[first])))
+    })
+}
+
+pub fn part1(print_queue: &PrintQueue) -> i32 {
+    print_queue
+        .pages_to_produce
+        .iter()
+        .filter(|update| is_ordered(update, &print_queue.page_ordering_rules))
+        .map(|update| update[update.len() / 2])
+        .sum()
+}
+
+/// Orders each unordered update by swapping neighbouring pages that break a rule until none do.
+pub fn part2(print_queue: &PrintQueue) -> i32 {
+    let rules = &print_queue.page_ordering_rules;
+    print_queue
+        .pages_to_produce
+        .iter()
+        .filter(|update| !is_ordered(update, rules))
+        .map(|update| {
+            let mut update = update.clone();
+            while let Some(index) =
+                (1..update.len()).find(|&index| rules.contains(&(update[index], update[index - 1])))
+            {
+                update.swap(index - 1, index);
+            }
+            update[update.len() / 2]
+        })
+        .sum()
+}
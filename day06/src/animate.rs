@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+use common::{
+    render::{Color, Style},
+    Animate, Direction, Image, Recorder, Scene, SolveError,
+};
+
+use crate::{is_obstruction, Day06, Lab};
+
+impl Animate for Day06 {
+    /// The guard's patrol one move or turn at a time, with the tiles patrolled so far in yellow
+    /// and the guard in red.
+    fn animate(lab: &Lab, recorder: &mut Recorder) -> Result<(), SolveError> {
+        let mut position = lab.guard_position;
+        let mut direction = Direction::Up;
+        let mut visited: HashSet<_> = HashSet::from([position]);
+        while !recorder.is_full() {
+            recorder.record(|| {
+                let scene = Scene::from_chars(&lab.map, |c| match c {
+                    '#' => Style::fg(Color::GRAY),
+                    _ => Style::PLAIN,
+                })
+                .highlight(visited.iter().copied(), Style::bg(Color::YELLOW))
+                .mark(position, '^', Style::bg(Color::RED));
+                Image::from_scene(&scene)
+            });
+
+            let next = position + direction.offset();
+            if is_obstruction(&lab.map, next) {
+                direction = direction.turn_right();
+            } else if lab.map.contains(next) {
+                position = next;
+                visited.insert(position);
+            } else {
+                break;
+            }
+        }
+        Ok(())
+    }
+}
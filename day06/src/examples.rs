@@ -0,0 +1,23 @@
+use common::Example;
+
+/// The lab map from the puzzle text.
+pub const EXAMPLE: &str = "\
+....#.....
+.........#
+..........
+..#.......
+.......#..
+..........
+.#..^.....
+........#.
+#.........
+......#...
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "lab",
+    input: EXAMPLE,
+    part1: Some("41"),
+    part2: Some("6"),
+    params: None,
+}];
@@ -0,0 +1,43 @@
+use std::collections::HashSet;
+
+use common::{Direction, Generator, Grid, NoParams, Point, Rng};
+
+use crate::Day06;
+
+impl Generator for Day06 {
+    const DEFAULT_SIZE: usize = 130;
+
+    /// A `size` by `size` lab with obstructions on about one tile in twenty and the guard
+    /// facing up. Maps on which the guard would patrol forever are drawn again, since part 1
+    /// needs the guard to leave.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        loop {
+            let mut map = Grid::new(size, size, '.');
+            for position in map.positions().collect::<Vec<_>>() {
+                if rng.chance(1, 20) {
+                    map[position] = '#';
+                }
+            }
+            let guard = (rng.usize_in(0..=size - 1), rng.usize_in(0..=size - 1));
+            map[guard] = '^';
+
+            if guard_leaves(&map, Point::from(guard)) {
+                return map.to_string();
+            }
+        }
+    }
+}
+
+fn guard_leaves(map: &Grid<char>, mut position: Point) -> bool {
+    let mut direction = Direction::Up;
+    let mut seen = HashSet::new();
+    while seen.insert((position, direction)) {
+        let next = position + direction.offset();
+        match map.get_point(next) {
+            None => return true,
+            Some('#') => direction = direction.turn_right(),
+            Some(_) => position = next,
+        }
+    }
+    false
+}
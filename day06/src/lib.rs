@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+
+use common::{Answer, Direction, Example, Grid, NoParams, Point, Solution, SolveError};
+
+mod animate;
+mod examples;
+mod generator;
+mod render;
+
+pub struct Lab {
+    pub map: Grid<char>,
+    pub guard_position: Point,
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Lab;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let map = Grid::parse(input)?;
+        let guard_position = map
+            .find(&'^')
+            .map(Point::from)
+            .ok_or_else(|| SolveError::invalid("the map has no guard '^'"))?;
+
+        Ok(Lab {
+            map,
+            guard_position,
+        })
+    }
+
+    fn part1(lab: &Self::Input) -> Result<Answer, SolveError> {
+        let mut guard_position = lab.guard_position;
+
+        let visited = traverse_until_left_area(&lab.map, &mut guard_position);
+
+        Ok(visited.len().into())
+    }
+
+    fn part2(lab: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_loop_obstruction_positions(lab).into())
+    }
+}
+
+/// The positions the guard visits until leaving the map, leaving `guard_position` on the last one.
+pub fn traverse_until_left_area(map: &Grid<char>, guard_position: &mut Point) -> HashSet<Point> {
+    let mut visited: HashSet<Point> = HashSet::new();
+    visited.insert(*guard_position);
+    let mut guard_left_area: bool = false;
+    let mut guard_direction = Direction::Up;
+    while !guard_left_area {
+        let mut position = *guard_position;
+
+        loop {
+            position += guard_direction.offset();
+
+            if is_obstruction(map, position) {
+                *guard_position = position - guard_direction.offset();
+                break;
+            }
+
+            if !map.contains(position) {
+                guard_left_area = true;
+                break;
+            }
+
+            visited.insert(position);
+        }
+        guard_direction = guard_direction.turn_right();
+    }
+    visited
+}
+
+/// The number of positions where one new obstruction traps the guard in a loop.
+pub fn count_loop_obstruction_positions(lab: &Lab) -> u32 {
+    let Lab {
+        map,
+        guard_position,
+    } = lab;
+    let mut guard_position = *guard_position;
+    let initial_guard_position = guard_position;
+    let visited = traverse_until_left_area(map, &mut guard_position);
+
+    let mut loops_count: u32 = 0;
+
+    for new_obstruction in visited {
+        if new_obstruction == initial_guard_position {
+            continue;
+        }
+
+        let mut guard_position = initial_guard_position;
+        let mut guard_direction = Direction::Up;
+        let mut loop_detector: HashSet<(Direction, Point)> = HashSet::new();
+
+        while map.contains(guard_position) {
+            let next_position = guard_position + guard_direction.offset();
+
+            if is_obstruction(map, next_position) || next_position == new_obstruction {
+                guard_direction = guard_direction.turn_right();
+            } else {
+                guard_position = next_position;
+
+                if !loop_detector.insert((guard_direction, next_position)) {
+                    loops_count += 1;
+                    break;
+                }
+            }
+        }
+    }
+
+    loops_count
+}
+
+fn is_obstruction(map: &Grid<char>, position: Point) -> bool {
+    map.get_point(position) == Some(&'#')
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{animate::FrameLimits, render::Color, Animate, Image, Recorder, Render};
+
+    use super::*;
+
+    common::example_tests!(Day06);
+    common::generator_tests!(Day06, 10);
+    common::parser_fuzz_tests!(Day06, 10);
+
+    #[test]
+    fn test_animation_follows_the_guard_out() {
+        let lab = Day06::parse(examples::EXAMPLE).unwrap();
+        let mut recorder = Recorder::new(FrameLimits {
+            stride: 1,
+            max_frames: 1000,
+        });
+        Day06::animate(&lab, &mut recorder).unwrap();
+        let frames = recorder.frames();
+        let yellow = |image: &Image| {
+            image
+                .tiles()
+                .iter()
+                .filter(|&(_, &tile)| tile == Color::YELLOW)
+                .count()
+        };
+
+        // The start, 44 moves over 41 tiles and 10 turns.
+        assert_eq!(frames.len(), 55);
+        assert_eq!(frames[0].tiles()[(6, 4)], Color::RED);
+        assert_eq!(yellow(&frames[0]), 0);
+        assert_eq!(yellow(frames.last().unwrap()), 40);
+    }
+
+    #[test]
+    fn test_render_shows_the_patrol() {
+        let scene = Day06::render(&Day06::parse(examples::EXAMPLE).unwrap()).unwrap();
+        let patrolled = scene
+            .cells()
+            .iter()
+            .filter(|(_, cell)| cell.style.bg.is_some())
+            .count();
+
+        assert_eq!(patrolled, 41);
+        assert_eq!(scene.to_string(), examples::EXAMPLE);
+    }
+
+    #[test]
+    #[ignore = "needs the personal puzzle input in challenge.txt"]
+    fn test_part2_challenge_input() {
+        assert_eq!(
+            Day06::part2(&Day06::parse_file("challenge.txt").unwrap()).unwrap(),
+            2165
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use common::{
+    render::{Color, Style},
+    Render, Scene, SolveError,
+};
+
+use crate::{traverse_until_left_area, Day06, Lab};
+
+impl Render for Day06 {
+    /// The lab with the tiles the guard patrols before leaving in yellow and the guard's start.
+    fn render(lab: &Lab) -> Result<Scene, SolveError> {
+        let mut guard_position = lab.guard_position;
+        let visited = traverse_until_left_area(&lab.map, &mut guard_position);
+
+        Ok(Scene::from_chars(&lab.map, |c| match c {
+            '#' => Style::fg(Color::GRAY),
+            _ => Style::PLAIN,
+        })
+        .highlight(visited, Style::fg(Color::BLACK).on(Color::YELLOW))
+        .mark(lab.guard_position, '^', Style::fg(Color::RED).bold()))
+    }
+}
@@ -0,0 +1,22 @@
+use common::Example;
+
+/// The calibration equations from the puzzle text.
+pub const EXAMPLE: &str = "\
+190: 10 19
+3267: 81 40 27
+83: 17 5
+156: 15 6
+7290: 6 8 6 15
+161011: 16 10 13
+192: 17 8 14
+21037: 9 7 18 13
+292: 11 6 16 20
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "calibrations",
+    input: EXAMPLE,
+    part1: Some("3749"),
+    part2: Some("11387"),
+    params: None,
+}];
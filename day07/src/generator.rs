@@ -0,0 +1,34 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day07;
+
+impl Generator for Day07 {
+    const DEFAULT_SIZE: usize = 850;
+
+    /// `size` equations of two to five numbers below 1000. Most test values come from combining
+    /// the numbers with random operators, so they can be calibrated; the rest are off by one.
+    /// The numbers are small enough that no intermediate result overflows an `i64`.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| {
+                let numbers: Vec<i64> = (0..rng.usize_in(2..=5))
+                    .map(|_| rng.i64_in(1..=999))
+                    .collect();
+                let mut total = numbers[0];
+                for &number in &numbers[1..] {
+                    total = match rng.usize_in(0..=2) {
+                        0 => total + number,
+                        1 => total * number,
+                        _ => format!("{}{}", total, number).parse().unwrap(),
+                    };
+                }
+                if rng.chance(1, 4) {
+                    total += 1;
+                }
+
+                let numbers: Vec<String> = numbers.iter().map(i64::to_string).collect();
+                format!("{}: {}\n", total, numbers.join(" "))
+            })
+            .collect()
+    }
+}
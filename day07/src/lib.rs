@@ -0,0 +1,106 @@
+use common::{input, Answer, Example, NoParams, Solution, SolveError};
+
+mod examples;
+mod generator;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    /// Each calibration equation as its test value and the numbers to combine.
+    type Input = Vec<(i64, Vec<i64>)>;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        input::lines(input)
+            .map(|line| {
+                let Some((total, numbers)) = line.text.split_once(':') else {
+                    return Err(line.error("expected 'total: numbers'"));
+                };
+                let operators = numbers
+                    .split_whitespace()
+                    .map(|operator| line.parse::<i64>(operator))
+                    .collect::<Result<Vec<i64>, SolveError>>()?;
+                if operators.is_empty() {
+                    return Err(line.error_at(numbers, "the equation has no numbers"));
+                }
+                Ok((line.parse::<i64>(total)?, operators))
+            })
+            .collect()
+    }
+
+    fn part1(equations: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(equations
+            .iter()
+            .filter_map(|(total, operators)| {
+                let total = *total;
+                let mut operators_iter = operators.iter();
+                let mut temp_results: Vec<i64> = vec![*operators_iter.next().unwrap()];
+
+                for operator in operators_iter {
+                    let mut new_temp_results = Vec::new();
+                    for temp_result in temp_results {
+                        if temp_result + operator <= total {
+                            new_temp_results.push(temp_result + operator);
+                        }
+                        if temp_result * operator <= total {
+                            new_temp_results.push(temp_result * operator);
+                        }
+                    }
+                    temp_results = new_temp_results;
+                }
+
+                temp_results.contains(&total).then_some(total)
+            })
+            .sum::<i64>()
+            .into())
+    }
+
+    fn part2(equations: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(equations
+            .iter()
+            .filter_map(|(total, operators)| {
+                let total = *total;
+                let mut operators_iter = operators.iter();
+                let mut temp_results: Vec<i64> = vec![*operators_iter.next().unwrap()];
+
+                for operator in operators_iter {
+                    let mut new_temp_results = Vec::new();
+                    for temp_result in temp_results {
+                        if temp_result + operator <= total {
+                            new_temp_results.push(temp_result + operator);
+                        }
+                        if temp_result * operator <= total {
+                            new_temp_results.push(temp_result * operator);
+                        }
+
+                        // Convert numbers to strings
+                        let joined_string = format!("{}{}", temp_result, operator);
+
+                        // Convert the concatenated string back to an integer; one that does not
+                        // fit in an i64 is larger than any test value anyway
+                        if let Ok(joined_number) = joined_string.parse::<i64>() {
+                            if joined_number <= total {
+                                new_temp_results.push(joined_number);
+                            }
+                        }
+                    }
+                    temp_results = new_temp_results;
+                }
+
+                temp_results.contains(&total).then_some(total)
+            })
+            .sum::<i64>()
+            .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day07);
+    common::generator_tests!(Day07, 20);
+    common::parser_fuzz_tests!(Day07, 20);
+}
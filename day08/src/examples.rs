@@ -0,0 +1,90 @@
+use common::Example;
+
+/// The antenna map from the puzzle text.
+pub const ANTENNAS: &str = "\
+............
+........0...
+.....0......
+.......0....
+....0.......
+......A.....
+............
+............
+........A...
+.........A..
+............
+............
+";
+
+/// Two antennas of the same frequency, creating two antinodes.
+pub const TWO_ANTENNAS: &str = "\
+..........
+..........
+..........
+....a.....
+..........
+.....a....
+..........
+..........
+..........
+..........
+";
+
+/// Three antennas of the same frequency; two of their antinodes fall off the map.
+pub const THREE_ANTENNAS: &str = "\
+..........
+..........
+..........
+....a.....
+........a.
+.....a....
+..........
+..........
+..........
+..........
+";
+
+/// Three `T` antennas whose resonant harmonics line up.
+pub const RESONANT_HARMONICS: &str = "\
+T.........
+...T......
+.T........
+..........
+..........
+..........
+..........
+..........
+..........
+..........
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "antennas",
+        input: ANTENNAS,
+        part1: Some("14"),
+        part2: Some("34"),
+        params: None,
+    },
+    Example {
+        name: "two antennas",
+        input: TWO_ANTENNAS,
+        part1: Some("2"),
+        part2: None,
+        params: None,
+    },
+    Example {
+        name: "three antennas",
+        input: THREE_ANTENNAS,
+        part1: Some("4"),
+        part2: None,
+        params: None,
+    },
+    Example {
+        name: "resonant harmonics",
+        input: RESONANT_HARMONICS,
+        part1: None,
+        part2: Some("9"),
+        params: None,
+    },
+];
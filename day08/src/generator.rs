@@ -0,0 +1,26 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day08;
+
+/// The characters antennas are tuned to.
+const FREQUENCIES: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+impl Generator for Day08 {
+    const DEFAULT_SIZE: usize = 50;
+
+    /// A `size` by `size` map with an antenna of a random frequency on about one tile in twelve.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut map = String::with_capacity(size * (size + 1));
+        for _ in 0..size {
+            for _ in 0..size {
+                map.push(if rng.chance(1, 12) {
+                    char::from(*rng.pick(FREQUENCIES))
+                } else {
+                    '.'
+                });
+            }
+            map.push('\n');
+        }
+        map
+    }
+}
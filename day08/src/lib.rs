@@ -0,0 +1,129 @@
+use common::{Answer, Example, Grid, NoParams, Point, Solution, SolveError};
+use itertools::Itertools;
+use std::{
+    char,
+    collections::{HashMap, HashSet},
+};
+
+mod examples;
+mod generator;
+
+pub struct Day08;
+
+/// The roof map and the positions of the antennas of each frequency.
+pub struct AntennaMap {
+    pub map: Grid<char>,
+    pub antennas: HashMap<String, Vec<Point>>,
+}
+
+impl Solution for Day08 {
+    type Input = AntennaMap;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let map = Grid::parse(input)?;
+        let antennas = parse_input_to_map(&map);
+        Ok(AntennaMap { map, antennas })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_antinodes(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_resonant_antinodes(input).into())
+    }
+}
+
+/// The number of positions on the map with an antinode of some frequency.
+pub fn count_antinodes(input: &AntennaMap) -> i32 {
+    let (grid, map) = (&input.map, &input.antennas);
+
+    let combination_size = 2;
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    map.iter().for_each(|(_key, value)| {
+        // Generate all combinations
+        let combinations = value.iter().combinations(combination_size);
+
+        for combination in combinations {
+            let &[&first, &second] = combination.as_slice() else {
+                continue;
+            };
+
+            // Stepping by the distance between the antennas lands just past each of them.
+            let diff = second - first;
+
+            if grid.contains(first - diff) {
+                antinodes.insert(first - diff);
+            }
+
+            if grid.contains(second + diff) {
+                antinodes.insert(second + diff);
+            }
+        }
+    });
+
+    antinodes.len() as i32
+}
+
+/// The number of positions on the map with an antinode, counting resonant harmonics.
+pub fn count_resonant_antinodes(input: &AntennaMap) -> i32 {
+    let (grid, map) = (&input.map, &input.antennas);
+
+    let combination_size = 2;
+    let mut antinodes: HashSet<Point> = HashSet::new();
+
+    map.iter().for_each(|(_key, value)| {
+        // Generate all combinations
+        let combinations = value.iter().combinations(combination_size);
+
+        for combination in combinations {
+            let &[&first, &second] = combination.as_slice() else {
+                continue;
+            };
+            antinodes.insert(first);
+            antinodes.insert(second);
+
+            let diff = second - first;
+
+            let mut antinode = first - diff;
+            while grid.contains(antinode) {
+                antinodes.insert(antinode);
+                antinode -= diff;
+            }
+
+            let mut antinode = second + diff;
+            while grid.contains(antinode) {
+                antinodes.insert(antinode);
+                antinode += diff;
+            }
+        }
+    });
+
+    antinodes.len() as i32
+}
+
+/// The positions of the antennas of each frequency.
+pub fn parse_input_to_map(grid: &Grid<char>) -> HashMap<String, Vec<Point>> {
+    let mut map = HashMap::new();
+    for (position, char) in grid.iter() {
+        if *char != '.' {
+            map.entry(char.to_string())
+                .or_insert_with(Vec::new)
+                .push(Point::from(position));
+        }
+    }
+    map
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day08);
+    common::generator_tests!(Day08, 12);
+    common::parser_fuzz_tests!(Day08, 12);
+}
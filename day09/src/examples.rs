@@ -0,0 +1,14 @@
+use common::Example;
+
+/// The disk map from the puzzle text.
+pub const DISK_MAP: &str = "\
+2333133121414131402
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "disk map",
+    input: DISK_MAP,
+    part1: Some("1928"),
+    part2: Some("2858"),
+    params: None,
+}];
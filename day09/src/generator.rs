@@ -0,0 +1,21 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day09;
+
+impl Generator for Day09 {
+    const DEFAULT_SIZE: usize = 10000;
+
+    /// A dense disk map of `size` files of one to nine blocks, with up to nine free blocks
+    /// between consecutive files.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut disk_map = String::with_capacity(2 * size);
+        for file in 0..size {
+            if file > 0 {
+                disk_map.push_str(&rng.usize_in(0..=9).to_string());
+            }
+            disk_map.push_str(&rng.usize_in(1..=9).to_string());
+        }
+        disk_map.push('\n');
+        disk_map
+    }
+}
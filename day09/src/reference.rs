@@ -0,0 +1,55 @@
+//! Brute-force answers for differential tests of the optimized solver.
+
+/// One entry per block: the ID of the file it belongs to, or `None` when it is free.
+fn blocks(disk_map: &str) -> Vec<Option<usize>> {
+    disk_map
+        .chars()
+        .enumerate()
+        .flat_map(|(index, length)| {
+            let block = (index % 2 == 0).then_some(index / 2);
+            std::iter::repeat_n(block, length.to_digit(10).unwrap() as usize)
+        })
+        .collect()
+}
+
+fn checksum(blocks: &[Option<usize>]) -> usize {
+    blocks
+        .iter()
+        .enumerate()
+        .map(|(position, block)| position * block.unwrap_or(0))
+        .sum()
+}
+
+/// Moves the last file block to the first free block until no free block is left of a file.
+pub fn part1(disk_map: &str) -> usize {
+    let mut blocks = blocks(disk_map);
+    loop {
+        let free = blocks.iter().position(Option::is_none);
+        let last = blocks.iter().rposition(Option::is_some);
+        match (free, last) {
+            (Some(free), Some(last)) if free < last => blocks.swap(free, last),
+            _ => return checksum(&blocks),
+        }
+    }
+}
+
+/// Moves each whole file, highest ID first, to the leftmost free span before it that fits it.
+pub fn part2(disk_map: &str) -> usize {
+    let mut blocks = blocks(disk_map);
+    let files = disk_map.len().div_ceil(2);
+    for id in (0..files).rev() {
+        let Some(start) = blocks.iter().position(|&block| block == Some(id)) else {
+            continue;
+        };
+        let length = blocks.iter().filter(|&&block| block == Some(id)).count();
+        let span = (0..start).find(|&free| {
+            free + length <= start && blocks[free..free + length].iter().all(Option::is_none)
+        });
+        if let Some(free) = span {
+            for offset in 0..length {
+                blocks.swap(free + offset, start + offset);
+            }
+        }
+    }
+    checksum(&blocks)
+}
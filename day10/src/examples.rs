@@ -0,0 +1,38 @@
+use common::Example;
+
+/// The first map from the puzzle text, with a single trailhead.
+pub const SMALL: &str = "\
+0123
+1234
+8765
+9876
+";
+
+/// The larger map from the puzzle text, with nine trailheads.
+pub const LARGER: &str = "\
+89010123
+78121874
+87430965
+96549874
+45678903
+32019012
+01329801
+10456732
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "small",
+        input: SMALL,
+        part1: Some("1"),
+        part2: None,
+        params: None,
+    },
+    Example {
+        name: "larger",
+        input: LARGER,
+        part1: Some("36"),
+        part2: Some("81"),
+        params: None,
+    },
+];
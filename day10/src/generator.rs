@@ -0,0 +1,28 @@
+use common::{Generator, Grid, NoParams, Rng};
+
+use crate::Day10;
+
+impl Generator for Day10 {
+    const DEFAULT_SIZE: usize = 45;
+    /// A trail needs a neighbouring tile to climb to.
+    const MIN_SIZE: usize = 2;
+
+    /// A `size` by `size` map of random heights with about one hiking trail per row carved into
+    /// it, each a walk climbing from 0 to 9 one step at a time.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut map = Grid::new(size, size, 0);
+        for position in map.positions().collect::<Vec<_>>() {
+            map[position] = rng.usize_in(0..=9);
+        }
+
+        for _ in 0..size {
+            let mut position = (rng.usize_in(0..=size - 1), rng.usize_in(0..=size - 1));
+            for height in 0..=9 {
+                map[position] = height;
+                let next: Vec<(usize, usize)> = map.neighbors4(position).collect();
+                position = *rng.pick(&next);
+            }
+        }
+        map.to_string()
+    }
+}
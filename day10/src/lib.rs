@@ -0,0 +1,149 @@
+use common::{Answer, Example, Grid, NoParams, Solution, SolveError};
+use std::collections::HashSet;
+
+mod examples;
+mod generator;
+mod render;
+
+pub struct Day10;
+
+/// The topographic map together with the trailheads (height 0) found on it.
+pub struct HikingArea {
+    pub map: Grid<usize>,
+    pub zero_positions: HashSet<(usize, usize)>,
+}
+
+impl Solution for Day10 {
+    type Input = HikingArea;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let map = Grid::try_parse_with(input, |c| c.to_digit(10).map(|height| height as usize))?;
+        let zero_positions = map.find_all(&0).collect();
+        Ok(HikingArea {
+            map,
+            zero_positions,
+        })
+    }
+
+    fn part1(area: &Self::Input) -> Result<Answer, SolveError> {
+        let score = area.zero_positions.iter().fold(0, |acc, zero_position| {
+            acc + find_trailhead_score(zero_position, &area.map)
+        });
+        Ok(score.into())
+    }
+
+    fn part2(area: &Self::Input) -> Result<Answer, SolveError> {
+        let rating = area.zero_positions.iter().fold(0, |acc, zero_position| {
+            acc + find_trailhead_rating(zero_position, &area.map)
+        });
+        Ok(rating.into())
+    }
+}
+
+/// The number of height 9 positions reachable from the trailhead.
+pub fn find_trailhead_score(zero_position: &(usize, usize), map: &Grid<usize>) -> i32 {
+    let mut stack = vec![(*zero_position, 0)];
+    let mut trailhead_score: HashSet<(usize, usize)> = HashSet::new();
+
+    while let Some((current_position, value)) = stack.pop() {
+        if value == 9 {
+            trailhead_score.insert(current_position);
+            continue;
+        }
+
+        map.neighbors4(current_position)
+            .for_each(|neighbor_position| {
+                let neighbor_value = map[neighbor_position];
+
+                if neighbor_value == value + 1 {
+                    stack.push((neighbor_position, neighbor_value));
+                }
+            });
+    }
+
+    trailhead_score.len() as i32
+}
+
+/// The number of distinct hiking trails from the trailhead to a height 9 position.
+pub fn find_trailhead_rating(zero_position: &(usize, usize), map: &Grid<usize>) -> i32 {
+    let mut stack = vec![(*zero_position, 0)];
+    let mut traihead_rating: i32 = 0;
+
+    while let Some((current_position, value)) = stack.pop() {
+        if value == 9 {
+            traihead_rating += 1;
+            continue;
+        }
+
+        map.neighbors4(current_position)
+            .for_each(|neighbor_position| {
+                let neighbor_value = map[neighbor_position];
+
+                if neighbor_value == value + 1 {
+                    stack.push((neighbor_position, neighbor_value));
+                }
+            });
+    }
+
+    traihead_rating
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{render::Color, Render};
+
+    use super::*;
+
+    common::example_tests!(Day10);
+    common::generator_tests!(Day10, 10);
+    common::parser_fuzz_tests!(Day10, 10);
+
+    #[test]
+    fn test_render_highlights_trails() {
+        let scene = Day10::render(&Day10::parse(examples::SMALL).unwrap()).unwrap();
+        let cells = scene.cells();
+
+        assert!(cells
+            .iter()
+            .all(|(_, cell)| cell.style.fg != Some(Color::GRAY)));
+        assert_eq!(cells.iter().filter(|(_, cell)| cell.style.bold).count(), 2);
+        assert_eq!(scene.to_string(), examples::SMALL);
+    }
+
+    #[test]
+    fn test_part1_example() {
+        let HikingArea { map, .. } = Day10::parse(examples::LARGER).unwrap();
+
+        let trailhead_score = find_trailhead_score(&(6, 6), &map);
+        assert_eq!(trailhead_score, 3);
+
+        let trailhead_score = find_trailhead_score(&(0, 2), &map);
+        assert_eq!(trailhead_score, 5);
+
+        let trailhead_score = find_trailhead_score(&(0, 4), &map);
+        assert_eq!(trailhead_score, 6);
+
+        let trailhead_score = find_trailhead_score(&(2, 4), &map);
+        assert_eq!(trailhead_score, 5);
+    }
+
+    #[test]
+    fn test_part2_example() {
+        let HikingArea { map, .. } = Day10::parse(examples::LARGER).unwrap();
+
+        let trailhead_score = find_trailhead_rating(&(6, 6), &map);
+        assert_eq!(trailhead_score, 8);
+
+        let trailhead_score = find_trailhead_rating(&(0, 2), &map);
+        assert_eq!(trailhead_score, 20);
+
+        let trailhead_score = find_trailhead_rating(&(0, 4), &map);
+        assert_eq!(trailhead_score, 24);
+
+        let trailhead_score = find_trailhead_rating(&(2, 4), &map);
+        assert_eq!(trailhead_score, 10);
+    }
+}
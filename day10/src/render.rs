@@ -0,0 +1,53 @@
+use std::collections::HashSet;
+
+use common::{
+    render::{Cell, Color, Style},
+    Grid, Point, Render, Scene, SolveError,
+};
+
+use crate::{Day10, HikingArea};
+
+impl Render for Day10 {
+    /// The heights, with the positions on a hiking trail from a trailhead to a summit in green
+    /// and the trailheads and summits they join in bold.
+    fn render(area: &HikingArea) -> Result<Scene, SolveError> {
+        let climbed = reachable(&area.map, area.zero_positions.iter().copied(), 1);
+        let summits: Vec<(usize, usize)> = area.map.find_all(&9).collect();
+        let descended = reachable(&area.map, summits.iter().copied(), -1);
+        let on_trail: HashSet<(usize, usize)> = climbed.intersection(&descended).copied().collect();
+
+        let scene = Scene::new(&area.map, |&height| {
+            let glyph = char::from_digit(height as u32, 10).unwrap_or('?');
+            Cell::new(glyph, Style::fg(Color::GRAY))
+        })
+        .highlight(
+            on_trail.iter().map(|&position| position.into()),
+            Style::fg(Color::GREEN),
+        );
+        let ends = area.zero_positions.iter().chain(&summits);
+        Ok(scene.highlight(
+            ends.filter(|position| on_trail.contains(position))
+                .map(|&position| Point::from(position)),
+            Style::fg(Color::YELLOW).bold(),
+        ))
+    }
+}
+
+/// Positions reached from `starts` by steps that change the height by exactly `step`.
+fn reachable(
+    map: &Grid<usize>,
+    starts: impl Iterator<Item = (usize, usize)>,
+    step: isize,
+) -> HashSet<(usize, usize)> {
+    let mut reached: HashSet<(usize, usize)> = starts.collect();
+    let mut stack: Vec<(usize, usize)> = reached.iter().copied().collect();
+    while let Some(position) = stack.pop() {
+        let height = map[position] as isize;
+        for neighbor in map.neighbors4(position) {
+            if map[neighbor] as isize == height + step && reached.insert(neighbor) {
+                stack.push(neighbor);
+            }
+        }
+    }
+    reached
+}
@@ -0,0 +1,23 @@
+use common::Example;
+
+/// The initial arrangement from the puzzle text.
+pub const STONES: &str = "\
+125 17
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "stones",
+        input: STONES,
+        part1: Some("55312"),
+        part2: None,
+        params: None,
+    },
+    Example {
+        name: "stones after six blinks",
+        input: STONES,
+        part1: Some("22"),
+        part2: Some("22"),
+        params: Some("part1_blinks = 6\npart2_blinks = 6"),
+    },
+];
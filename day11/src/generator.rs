@@ -0,0 +1,18 @@
+use common::{Generator, Rng};
+
+use crate::{BlinkParams, Day11};
+
+impl Generator for Day11 {
+    const DEFAULT_SIZE: usize = 8;
+
+    /// A line of `size` stones engraved with numbers of up to seven digits.
+    fn generate(size: usize, _params: &BlinkParams, rng: &mut Rng) -> String {
+        let stones: Vec<String> = (0..size)
+            .map(|_| {
+                let digits = rng.usize_in(1..=7) as u32;
+                rng.usize_in(0..=10usize.pow(digits) - 1).to_string()
+            })
+            .collect();
+        format!("{}\n", stones.join(" "))
+    }
+}
@@ -0,0 +1,185 @@
+use common::{
+    input::{self, Line},
+    Answer, Example, Memo, Solution, SolveError,
+};
+use serde::Deserialize;
+use std::vec;
+
+mod examples;
+mod generator;
+
+pub struct Day11;
+
+/// How many times each part blinks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BlinkParams {
+    pub part1_blinks: usize,
+    pub part2_blinks: usize,
+}
+
+impl Default for BlinkParams {
+    fn default() -> Self {
+        BlinkParams {
+            part1_blinks: 25,
+            part2_blinks: 75,
+        }
+    }
+}
+
+pub struct Stones {
+    /// The engravings on the stones, in order.
+    pub stones: Vec<String>,
+    pub params: BlinkParams,
+}
+
+impl Solution for Day11 {
+    type Input = Stones;
+    type Params = BlinkParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Self::parse_with(input, &BlinkParams::default())
+    }
+
+    fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, SolveError> {
+        let line = Line {
+            number: 1,
+            text: input::single_line(input)?,
+        };
+        let stones = line
+            .text
+            .split_whitespace()
+            .map(|s| {
+                line.parse::<u64>(s)?;
+                Ok(s.to_string())
+            })
+            .collect::<Result<_, SolveError>>()?;
+
+        Ok(Stones {
+            stones,
+            params: *params,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(blink(&input.stones, input.params.part1_blinks)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        let line_vec: Vec<u64> = input
+            .stones
+            .iter()
+            .map(|chunk| chunk.parse::<u64>().unwrap())
+            .collect();
+        let mut cache = Memo::new();
+        let mut sum = 0;
+
+        for n in line_vec {
+            sum = count(n, input.params.part2_blinks, &mut cache)
+                .and_then(|stones| u64::checked_add(sum, stones))
+                .ok_or(SolveError::Overflow)?;
+        }
+
+        Ok(sum.into())
+    }
+}
+
+/// Number of stones after blinking `blinking_times` times, simulating every stone.
+pub fn blink(stones: &[String], blinking_times: usize) -> Result<usize, SolveError> {
+    let mut line_vec: Vec<String> = stones.to_vec();
+
+    for _ in 0..blinking_times {
+        line_vec = line_vec
+            .iter()
+            .map(|rock| {
+                if *rock == "0" {
+                    Ok(vec![String::from("1")])
+                } else if rock.len() % 2 == 0 {
+                    let rock_split = rock.split_at(rock.len() / 2);
+                    Ok(vec![
+                        remove_leading_zeros(rock_split.0),
+                        remove_leading_zeros(rock_split.1),
+                    ])
+                } else {
+                    let rock_value = rock
+                        .parse::<u64>()
+                        .ok()
+                        .and_then(|rock| rock.checked_mul(2024))
+                        .ok_or(SolveError::Overflow)?;
+                    Ok(vec![rock_value.to_string()])
+                }
+            })
+            .collect::<Result<Vec<_>, SolveError>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+    }
+    Ok(line_vec.len())
+}
+
+// Got this from https://github.com/javorszky/adventofcode2024/blob/main/day11/src/part2.rs
+/// The number of stones `number` turns into after blinking `steps` more times, or `None` when
+/// that number or one of the stones' numbers does not fit in a `u64`.
+pub fn count(number: u64, steps: usize, memo: &mut Memo<(u64, usize), Option<u64>>) -> Option<u64> {
+    // we have reached the end of this branch, there is only one number, and it's the one we're
+    // looking at, except we don't care what the number is
+    if steps == 0 {
+        return Some(1);
+    }
+
+    memo.call((number, steps), |memo| {
+        let number_as_string = format!("{:?}", number);
+
+        if number == 0 {
+            count(1, steps - 1, memo)
+        } else if number_as_string.len() % 2 == 0 {
+            let left = number_as_string[..number_as_string.len() / 2]
+                .parse::<u64>()
+                .unwrap();
+            let right = number_as_string[number_as_string.len() / 2..]
+                .parse::<u64>()
+                .unwrap();
+
+            count(left, steps - 1, memo)?.checked_add(count(right, steps - 1, memo)?)
+        } else {
+            count(number.checked_mul(2024)?, steps - 1, memo)
+        }
+    })
+}
+
+fn remove_leading_zeros(rock: &str) -> String {
+    let mut rock_updated = rock.trim_start_matches('0');
+    if rock_updated.is_empty() {
+        rock_updated = "0";
+    }
+
+    rock_updated.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day11);
+    common::generator_tests!(Day11, 3);
+    common::parser_fuzz_tests!(Day11, 3);
+
+    #[test]
+    fn test_overflow_is_an_error() {
+        let stones = Day11::parse_with(
+            "125 17",
+            &BlinkParams {
+                part1_blinks: 1,
+                part2_blinks: 300,
+            },
+        )
+        .unwrap();
+        assert!(matches!(Day11::part2(&stones), Err(SolveError::Overflow)));
+
+        let huge = Day11::parse("9999999999999999999").unwrap();
+        assert!(matches!(Day11::part1(&huge), Err(SolveError::Overflow)));
+        assert!(matches!(Day11::part2(&huge), Err(SolveError::Overflow)));
+    }
+}
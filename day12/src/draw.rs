@@ -0,0 +1,10 @@
+use common::{Draw, Grid, Image, Render, SolveError};
+
+use crate::Day12;
+
+impl Draw for Day12 {
+    /// The regions in the colors of the rendered garden.
+    fn draw(map: &Grid<char>) -> Result<Image, SolveError> {
+        Ok(Image::from_scene(&Day12::render(map)?))
+    }
+}
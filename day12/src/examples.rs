@@ -0,0 +1,116 @@
+use common::Example;
+
+/// The first garden from the puzzle text, with five regions.
+pub const SMALL: &str = "\
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+/// Four `X` regions inside a single `O` region.
+pub const NESTED: &str = "\
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+/// The larger garden from the puzzle text.
+pub const LARGER: &str = "\
+RRRRIICCFF
+RRRRIICCCF
+VVRRRCCFFF
+VVRCCCJFFF
+VVVVCJJCFE
+VVIVCCJJEE
+VVIIICJJEE
+MIIIIIJJEE
+MIIISIJEEE
+MMMISSJEEE
+";
+
+/// An E-shaped region with twelve sides.
+pub const E_SHAPE: &str = "\
+EEEEE
+EXXXX
+EEEEE
+EXXXX
+EEEEE
+";
+
+/// Two `B` regions that touch the outer `A` region's inner sides diagonally.
+pub const DIAGONAL_REGIONS: &str = "\
+AAAAAA
+AAABBA
+AAABBA
+ABBAAA
+ABBAAA
+AAAAAA
+";
+
+/// A single rectangular region with four sides.
+pub const RECTANGLE: &str = "\
+AAAA
+AAAA
+";
+
+/// A region with a hole, whose inner sides count too.
+pub const ONE_INNER_AREA: &str = "\
+AAA
+ABA
+AAA
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "small",
+        input: SMALL,
+        part1: Some("140"),
+        part2: Some("80"),
+        params: None,
+    },
+    Example {
+        name: "nested",
+        input: NESTED,
+        part1: Some("772"),
+        part2: Some("436"),
+        params: None,
+    },
+    Example {
+        name: "larger",
+        input: LARGER,
+        part1: Some("1930"),
+        part2: Some("1206"),
+        params: None,
+    },
+    Example {
+        name: "e shape",
+        input: E_SHAPE,
+        part1: None,
+        part2: Some("236"),
+        params: None,
+    },
+    Example {
+        name: "diagonal regions",
+        input: DIAGONAL_REGIONS,
+        part1: None,
+        part2: Some("368"),
+        params: None,
+    },
+    Example {
+        name: "rectangle",
+        input: RECTANGLE,
+        part1: None,
+        part2: Some("32"),
+        params: None,
+    },
+    Example {
+        name: "one inner area",
+        input: ONE_INNER_AREA,
+        part1: None,
+        part2: Some("68"),
+        params: None,
+    },
+];
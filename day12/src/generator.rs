@@ -0,0 +1,25 @@
+use common::{Generator, Grid, NoParams, Rng};
+
+use crate::Day12;
+
+/// The plants regions are made of; fewer than the alphabet so that separate regions of the same
+/// plant are common.
+const PLANTS: &[u8] = b"ABCDEFGHIJKL";
+
+impl Generator for Day12 {
+    const DEFAULT_SIZE: usize = 140;
+
+    /// A `size` by `size` garden. Most plots copy the plant above or to their left, which grows
+    /// irregular regions with holes and shared corners.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut garden = Grid::new(size, size, 'A');
+        for (row, col) in garden.positions().collect::<Vec<_>>() {
+            garden[(row, col)] = match rng.usize_in(0..=5) {
+                0..=1 if row > 0 => garden[(row - 1, col)],
+                2..=3 if col > 0 => garden[(row, col - 1)],
+                _ => char::from(*rng.pick(PLANTS)),
+            };
+        }
+        garden.to_string()
+    }
+}
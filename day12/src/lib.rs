@@ -0,0 +1,271 @@
+use std::collections::HashSet;
+
+use common::{geometry::ORTHOGONAL, Answer, Example, Grid, NoParams, Point, Solution, SolveError};
+
+mod draw;
+mod examples;
+mod generator;
+mod render;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    /// The garden plots and the plant type growing on each of them.
+    type Input = Grid<char>;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(Grid::parse(input)?)
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(fence_price(map).into())
+    }
+
+    fn part2(map: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(bulk_discount_fence_price(map).into())
+    }
+}
+
+/// The sum of each region's area times its perimeter.
+pub fn fence_price(map: &Grid<char>) -> usize {
+    find_area_and_perimeter(map)
+        .iter()
+        .map(|(area, perimeter)| area * perimeter)
+        .sum()
+}
+
+/// The sum of each region's area times its number of sides.
+pub fn bulk_discount_fence_price(map: &Grid<char>) -> i32 {
+    let areas = get_areas(map);
+    let areas_sides: Vec<(usize, usize)> = areas
+        .iter()
+        .map(|area| {
+            if area.len() == 1 {
+                return (1, 4);
+            }
+
+            let mut sides_count = 0;
+
+            for position in area.iter() {
+                let top_left_neighbor = *position + Point::UP_LEFT;
+                let top_neighbor = *position + Point::UP;
+                let top_right_neighbor = *position + Point::UP_RIGHT;
+                let right_neighbor = *position + Point::RIGHT;
+                let bottom_right_neighbor = *position + Point::DOWN_RIGHT;
+                let bottom_neighbor = *position + Point::DOWN;
+                let bottom_left_neighbor = *position + Point::DOWN_LEFT;
+                let left_neighbor = *position + Point::LEFT;
+
+                //check top left corner
+                if !area.contains(&left_neighbor)
+                    && !area.contains(&top_left_neighbor)
+                    && !area.contains(&top_neighbor)
+                {
+                    sides_count += 1;
+                }
+
+                //check top right corner
+                if !area.contains(&top_neighbor)
+                    && !area.contains(&top_right_neighbor)
+                    && !area.contains(&right_neighbor)
+                {
+                    sides_count += 1;
+                }
+
+                //check bottom right corner
+                if !area.contains(&right_neighbor)
+                    && !area.contains(&bottom_right_neighbor)
+                    && !area.contains(&bottom_neighbor)
+                {
+                    sides_count += 1;
+                }
+
+                //check bottom left corner
+                if !area.contains(&bottom_neighbor)
+                    && !area.contains(&bottom_left_neighbor)
+                    && !area.contains(&left_neighbor)
+                {
+                    sides_count += 1;
+                }
+
+                //check inner top left corner
+                if (area.contains(&right_neighbor)
+                    && area.contains(&bottom_neighbor)
+                    && !area.contains(&bottom_right_neighbor))
+                    || (!area.contains(&top_neighbor)
+                        && !area.contains(&right_neighbor)
+                        && area.contains(&top_right_neighbor))
+                {
+                    sides_count += 1;
+                }
+
+                //check inner top right corner
+                if (area.contains(&left_neighbor)
+                    && area.contains(&bottom_neighbor)
+                    && !area.contains(&bottom_left_neighbor))
+                    || (!area.contains(&top_neighbor)
+                        && !area.contains(&left_neighbor)
+                        && area.contains(&top_left_neighbor))
+                {
+                    sides_count += 1;
+                }
+
+                //check inner bottom right corner
+                if (area.contains(&left_neighbor)
+                    && area.contains(&top_neighbor)
+                    && !area.contains(&top_left_neighbor))
+                    || (!area.contains(&left_neighbor)
+                        && !area.contains(&bottom_neighbor)
+                        && area.contains(&bottom_left_neighbor))
+                {
+                    sides_count += 1;
+                }
+
+                //check inner bottom left corner
+                if (area.contains(&right_neighbor)
+                    && area.contains(&top_neighbor)
+                    && !area.contains(&top_right_neighbor))
+                    || (!area.contains(&right_neighbor)
+                        && !area.contains(&bottom_neighbor)
+                        && area.contains(&bottom_right_neighbor))
+                {
+                    sides_count += 1;
+                }
+            }
+
+            (area.len(), sides_count)
+        })
+        .collect();
+
+    areas_sides.iter().fold(0, |acc, (area_len, sides_count)| {
+        acc + area_len * sides_count
+    }) as i32
+}
+
+/// The area and perimeter of each region.
+pub fn find_area_and_perimeter(map: &Grid<char>) -> Vec<(usize, usize)> {
+    let mut areas_and_perimeters: Vec<(usize, usize)> = Vec::new();
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut area_identifier = ' ';
+    let mut area_size = 0;
+    let mut perimeter = 0;
+    let mut queue: Vec<(usize, usize)> = Vec::new();
+
+    for current_position in map.positions() {
+        if visited.contains(&current_position) {
+            continue;
+        }
+
+        if area_identifier == ' ' {
+            area_identifier = map[current_position];
+            queue.push(current_position);
+        }
+
+        while let Some(current_position) = queue.pop() {
+            let mut current_perimeter = 4;
+
+            if visited.contains(&current_position) {
+                continue;
+            }
+
+            visited.insert(current_position);
+            area_size += 1;
+
+            map.neighbors4(current_position).for_each(|neighbor| {
+                if map[neighbor] == area_identifier {
+                    // Add the neighbor to the queue if it has not been visited
+                    if !visited.contains(&neighbor) {
+                        queue.push(neighbor);
+                    }
+
+                    // Every time a neighbor of the same area is found decrease the parameter of the current area by 1
+                    current_perimeter -= 1;
+                }
+            });
+
+            perimeter += current_perimeter;
+        }
+
+        areas_and_perimeters.push((area_size, perimeter));
+        area_identifier = ' ';
+        area_size = 0;
+        perimeter = 0;
+    }
+
+    areas_and_perimeters
+}
+
+/// The positions in each region.
+pub fn get_areas(map: &Grid<char>) -> Vec<Vec<Point>> {
+    let mut areas: Vec<Vec<Point>> = Vec::new();
+    let mut visited: HashSet<Point> = HashSet::new();
+    let mut area_identifier = ' ';
+    let mut area: Vec<Point> = Vec::new();
+    let mut queue: Vec<Point> = Vec::new();
+
+    for position in map.positions() {
+        let current_position = Point::from(position);
+
+        if visited.contains(&current_position) {
+            continue;
+        }
+
+        if area_identifier == ' ' {
+            area_identifier = map[position];
+            queue.push(current_position);
+        }
+
+        while let Some(current_position) = queue.pop() {
+            if visited.contains(&current_position) {
+                continue;
+            }
+
+            visited.insert(current_position);
+            area.push(current_position);
+
+            ORTHOGONAL.iter().for_each(|dir| {
+                let neighbor = current_position + *dir;
+                // Add the neighbor to the queue if it is inside the bounds and it has not been visited
+                if map.get_point(neighbor) == Some(&area_identifier) && !visited.contains(&neighbor)
+                {
+                    queue.push(neighbor);
+                }
+            });
+        }
+
+        areas.push(area);
+        area_identifier = ' ';
+        area = Vec::new();
+    }
+
+    areas
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Render;
+
+    use super::*;
+
+    common::example_tests!(Day12);
+    common::generator_tests!(Day12, 12);
+    common::parser_fuzz_tests!(Day12, 12);
+
+    #[test]
+    fn test_render_colors_touching_regions_apart() {
+        let map = Day12::parse(examples::LARGER).unwrap();
+        let scene = Day12::render(&map).unwrap();
+        let cells = scene.cells();
+
+        for (position, cell) in cells.iter() {
+            for neighbor in cells.neighbors4(position) {
+                let same_region = map[position] == map[neighbor];
+                assert_eq!(cell.style.bg == cells[neighbor].style.bg, same_region);
+            }
+        }
+        assert_eq!(scene.to_string(), examples::LARGER);
+    }
+}
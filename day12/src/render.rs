@@ -0,0 +1,46 @@
+use std::collections::HashMap;
+
+use common::{
+    geometry::ORTHOGONAL,
+    render::{Color, Style, PALETTE},
+    Grid, Point, Render, Scene, SolveError,
+};
+
+use crate::{get_areas, Day12};
+
+impl Render for Day12 {
+    /// The garden with every region in its own color, picked so that no two regions that touch
+    /// share one while the palette lasts.
+    fn render(map: &Grid<char>) -> Result<Scene, SolveError> {
+        let areas = get_areas(map);
+        let region_of: HashMap<Point, usize> = areas
+            .iter()
+            .enumerate()
+            .flat_map(|(region, area)| area.iter().map(move |&position| (position, region)))
+            .collect();
+
+        let mut colors: Vec<Color> = Vec::with_capacity(areas.len());
+        for (region, area) in areas.iter().enumerate() {
+            let neighbor_colors: Vec<Color> = area
+                .iter()
+                .flat_map(|&position| ORTHOGONAL.map(|direction| position + direction))
+                .filter_map(|neighbor| region_of.get(&neighbor))
+                .filter(|&&neighbor| neighbor < region)
+                .map(|&neighbor| colors[neighbor])
+                .collect();
+            let color = PALETTE
+                .iter()
+                .find(|color| !neighbor_colors.contains(color))
+                .unwrap_or(&PALETTE[region % PALETTE.len()]);
+            colors.push(*color);
+        }
+
+        let scene = Scene::from_chars(map, |_| Style::fg(Color::WHITE));
+        Ok(areas
+            .into_iter()
+            .zip(colors)
+            .fold(scene, |scene, (area, color)| {
+                scene.highlight(area, Style::bg(color))
+            }))
+    }
+}
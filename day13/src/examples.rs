@@ -0,0 +1,37 @@
+use common::Example;
+
+/// The four claw machines from the puzzle text.
+pub const CLAW_MACHINES: &str = "\
+Button A: X+94, Y+34
+Button B: X+22, Y+67
+Prize: X=8400, Y=5400
+
+Button A: X+26, Y+66
+Button B: X+67, Y+21
+Prize: X=12748, Y=12176
+
+Button A: X+17, Y+86
+Button B: X+84, Y+37
+Prize: X=7870, Y=6450
+
+Button A: X+69, Y+23
+Button B: X+27, Y+71
+Prize: X=18641, Y=10279
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "claw machines",
+        input: CLAW_MACHINES,
+        part1: Some("480"),
+        part2: None,
+        params: None,
+    },
+    Example {
+        name: "claw machines without the prize offset",
+        input: CLAW_MACHINES,
+        part1: Some("480"),
+        part2: Some("480"),
+        params: Some("prize_offset = 0"),
+    },
+];
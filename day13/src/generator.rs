@@ -0,0 +1,39 @@
+use common::{Generator, Rng};
+
+use crate::{Day13, PrizeParams};
+
+impl Generator for Day13 {
+    const DEFAULT_SIZE: usize = 320;
+
+    /// `size` claw machines with buttons moving the claw 10 to 99 units along each axis, never in
+    /// the same direction as each other, as in the real puzzle. Half of
+    /// the prizes are reached by pressing each button at most 100 times; the others are random
+    /// and mostly out of reach.
+    fn generate(size: usize, _params: &PrizeParams, rng: &mut Rng) -> String {
+        let machines: Vec<String> = (0..size)
+            .map(|_| {
+                let a = (rng.i64_in(10..=99), rng.i64_in(10..=99));
+                let b = loop {
+                    let b = (rng.i64_in(10..=99), rng.i64_in(10..=99));
+                    if a.0 * b.1 != a.1 * b.0 {
+                        break b;
+                    }
+                };
+                let prize = if rng.chance(1, 2) {
+                    let (a_presses, b_presses) = (rng.i64_in(1..=100), rng.i64_in(1..=100));
+                    (
+                        a.0 * a_presses + b.0 * b_presses,
+                        a.1 * a_presses + b.1 * b_presses,
+                    )
+                } else {
+                    (rng.i64_in(1000..=20000), rng.i64_in(1000..=20000))
+                };
+                format!(
+                    "Button A: X+{}, Y+{}\nButton B: X+{}, Y+{}\nPrize: X={}, Y={}\n",
+                    a.0, a.1, b.0, b.1, prize.0, prize.1
+                )
+            })
+            .collect();
+        machines.join("\n")
+    }
+}
@@ -0,0 +1,241 @@
+use common::{
+    input::{self, Line},
+    Answer, Example, Solution, SolveError,
+};
+use regex::Regex;
+use serde::Deserialize;
+
+mod examples;
+mod generator;
+pub mod matrix_operations;
+#[cfg(test)]
+mod reference;
+
+pub struct Day13;
+
+/// One claw machine: the X and Y coefficients of buttons A and B followed by the prize position.
+pub struct ClawMachine {
+    pub x_values: Vec<i64>,
+    pub y_values: Vec<i64>,
+}
+
+/// How far part 2 moves every prize along both axes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PrizeParams {
+    pub prize_offset: i64,
+}
+
+impl Default for PrizeParams {
+    fn default() -> Self {
+        PrizeParams {
+            prize_offset: 10000000000000,
+        }
+    }
+}
+
+pub struct Arcade {
+    pub machines: Vec<ClawMachine>,
+    pub params: PrizeParams,
+}
+
+impl Solution for Day13 {
+    type Input = Arcade;
+    type Params = PrizeParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Self::parse_with(input, &PrizeParams::default())
+    }
+
+    fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, SolveError> {
+        let lines: Vec<Line> = input::lines(input)
+            .filter(|line| !line.text.is_empty())
+            .collect();
+
+        let re = Regex::new(r"^.+X[+=](\d+).+Y[+=](\d+)").unwrap();
+
+        let machines = lines
+            .chunks(3) // Borrow chunks of 3 elements
+            .map(|chunk| {
+                if chunk.len() < 3 {
+                    let last = chunk[chunk.len() - 1];
+                    return Err(last.error_at(
+                        &last.text[last.text.len()..],
+                        "the claw machine is missing its prize line",
+                    ));
+                }
+
+                let (x_values, y_values): (Vec<i64>, Vec<i64>) = chunk
+                    .iter()
+                    .map(|line| {
+                        let Some(captures) = re.captures(line.text) else {
+                            return Err(line.error("expected 'X..., Y...' values"));
+                        };
+                        // Extract and parse the captured groups
+                        let x_value = line.parse::<i64>(&captures[1])?;
+                        let y_value = line.parse::<i64>(&captures[2])?;
+
+                        Ok((x_value, y_value))
+                    })
+                    .collect::<Result<Vec<(i64, i64)>, SolveError>>()?
+                    .into_iter()
+                    .unzip();
+
+                Ok(ClawMachine { x_values, y_values })
+            })
+            .collect::<Result<_, SolveError>>()?;
+
+        Ok(Arcade {
+            machines,
+            params: *params,
+        })
+    }
+
+    fn part1(arcade: &Self::Input) -> Result<Answer, SolveError> {
+        let chunks: Vec<Option<(usize, usize)>> = arcade
+            .machines
+            .iter()
+            .map(|ClawMachine { x_values, y_values }| {
+                let eq1 = (x_values[0], x_values[1], x_values[2]);
+                let eq2 = (y_values[0], y_values[1], y_values[2]);
+                crate::matrix_operations::solve_by_substitution(eq1, eq2)
+            })
+            .collect();
+
+        total_tokens(chunks)
+    }
+
+    fn part2(arcade: &Self::Input) -> Result<Answer, SolveError> {
+        let offset = arcade.params.prize_offset as f64;
+        let chunks: Vec<Option<(usize, usize)>> = arcade
+            .machines
+            .iter()
+            .map(|ClawMachine { x_values, y_values }| {
+                let mut matrix = vec![
+                    vec![
+                        x_values[0] as f64,
+                        x_values[1] as f64,
+                        x_values[2] as f64 + offset,
+                    ],
+                    vec![
+                        y_values[0] as f64,
+                        y_values[1] as f64,
+                        y_values[2] as f64 + offset,
+                    ],
+                ];
+                crate::matrix_operations::gaussian_elimination(&mut matrix)
+            })
+            .collect();
+
+        total_tokens(chunks)
+    }
+}
+
+/// Sums the tokens spent on every winnable machine: 3 per A press and 1 per B press.
+fn total_tokens(presses: Vec<Option<(usize, usize)>>) -> Result<Answer, SolveError> {
+    presses
+        .into_iter()
+        .flatten()
+        .try_fold(0usize, |total, (a, b)| {
+            a.checked_mul(3)
+                .and_then(|tokens| tokens.checked_add(b))
+                .and_then(|tokens| total.checked_add(tokens))
+        })
+        .map(Answer::from)
+        .ok_or(SolveError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::differential::check_agreement;
+
+    use super::*;
+
+    common::example_tests!(Day13);
+    common::generator_tests!(Day13, 10);
+    common::parser_fuzz_tests!(Day13, 10);
+
+    fn each_machine(
+        arcade: &Arcade,
+        presses: impl Fn(&ClawMachine) -> Option<(usize, usize)>,
+    ) -> Vec<Option<(usize, usize)>> {
+        arcade.machines.iter().map(presses).collect()
+    }
+
+    fn gaussian_elimination(machine: &ClawMachine, offset: i64) -> Option<(usize, usize)> {
+        let offset = offset as f64;
+        let mut matrix = vec![
+            vec![
+                machine.x_values[0] as f64,
+                machine.x_values[1] as f64,
+                machine.x_values[2] as f64 + offset,
+            ],
+            vec![
+                machine.y_values[0] as f64,
+                machine.y_values[1] as f64,
+                machine.y_values[2] as f64 + offset,
+            ],
+        ];
+        matrix_operations::gaussian_elimination(&mut matrix)
+    }
+
+    #[test]
+    fn test_substitution_agrees_with_brute_force() {
+        check_agreement::<Day13, _>(
+            10,
+            |arcade| each_machine(arcade, reference::brute_force_presses),
+            |arcade| {
+                each_machine(arcade, |machine| {
+                    let (x, y) = (&machine.x_values, &machine.y_values);
+                    matrix_operations::solve_by_substitution((x[0], x[1], x[2]), (y[0], y[1], y[2]))
+                })
+            },
+        );
+    }
+
+    #[test]
+    fn test_gaussian_elimination_agrees_with_brute_force() {
+        check_agreement::<Day13, _>(
+            10,
+            |arcade| each_machine(arcade, reference::brute_force_presses),
+            |arcade| each_machine(arcade, |machine| gaussian_elimination(machine, 0)),
+        );
+    }
+
+    #[test]
+    fn test_gaussian_elimination_agrees_with_exact_solution_far_away() {
+        check_agreement::<Day13, _>(
+            10,
+            |arcade| {
+                each_machine(arcade, |machine| {
+                    reference::exact_presses(machine, arcade.params.prize_offset)
+                })
+            },
+            |arcade| {
+                each_machine(arcade, |machine| {
+                    gaussian_elimination(machine, arcade.params.prize_offset)
+                })
+            },
+        );
+    }
+
+    #[test]
+    #[ignore = "needs the personal puzzle input in challenge.txt"]
+    fn test_part1_challenge() {
+        assert_eq!(
+            Day13::part1(&Day13::parse_file("challenge.txt").unwrap()).unwrap(),
+            37128
+        );
+    }
+
+    #[test]
+    #[ignore = "needs the personal puzzle input in challenge.txt"]
+    fn test_part2_challenge() {
+        assert_eq!(
+            Day13::part2(&Day13::parse_file("challenge.txt").unwrap()).unwrap(),
+            74914228471331usize
+        );
+    }
+}
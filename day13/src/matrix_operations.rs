@@ -1,4 +1,12 @@
-pub fn gaussian_elimination(matrix: &mut Vec<Vec<f64>>) -> Option<(usize, usize)> {
+#[allow(clippy::needless_range_loop)]
+pub fn gaussian_elimination(matrix: &mut [Vec<f64>]) -> Option<(usize, usize)> {
+    let equations = [0, 1].map(|row| {
+        (
+            matrix[row][0] as i128,
+            matrix[row][1] as i128,
+            matrix[row][2] as i128,
+        )
+    });
     let pivot_row_1 = matrix[0][0];
 
     // Make the first pivot equals to 1 by dividing the row by the pivot, the pivot is the first element of the row
@@ -7,14 +15,14 @@ pub fn gaussian_elimination(matrix: &mut Vec<Vec<f64>>) -> Option<(usize, usize)
     }
 
     for i in 0..3 {
-        matrix[0][i] = matrix[0][i] / pivot_row_1;
+        matrix[0][i] /= pivot_row_1;
     }
 
     // Eliminate the first entry of the second row by subtracting the first row multiplied by the first entry of the second row
     let multiplier = matrix[1][0];
 
     for i in 0..3 {
-        matrix[1][i] = matrix[1][i] - multiplier * matrix[0][i];
+        matrix[1][i] -= multiplier * matrix[0][i];
     }
 
     // Make the second pivot equals to 1 by dividing the row by the pivot, the pivot is the second element of the second row
@@ -25,58 +33,42 @@ pub fn gaussian_elimination(matrix: &mut Vec<Vec<f64>>) -> Option<(usize, usize)
     }
 
     for i in 0..3 {
-        matrix[1][i] = matrix[1][i] / pivot_row_2;
+        matrix[1][i] /= pivot_row_2;
     }
 
     // Eliminate the second entry of the first row by subtracting the second row multiplied by the second entry of the first row
     let multiplier = matrix[0][1];
 
     for i in 0..3 {
-        matrix[0][i] = matrix[0][i] - multiplier * matrix[1][i];
+        matrix[0][i] -= multiplier * matrix[1][i];
     }
 
     let a = matrix[0][2];
     let b = matrix[1][2];
 
-    if a < 0.0 || b < 0.0 {
-        return None;
-    }
-
-    if a.fract().abs() == 0.0 || b.fract().abs() == 0.0 {
-        let a = a.trunc() as usize;
-        let b = b.trunc() as usize;
-
-        return Some((a, b));
-    }
-
-    return match round_if_zero_or_nine_pair(a, b) {
-        Some((rounded_a, rounded_b)) => Some((rounded_a, rounded_b)),
-        None => None,
-    };
+    presses_reaching_prize(a, b, &equations)
 }
 
-fn round_if_zero_or_nine_pair(a: f64, b: f64) -> Option<(usize, usize)> {
-    let a_rounded = round_if_zero_or_nine(a);
-    let b_rounded = round_if_zero_or_nine(b);
-
-    match (a_rounded, b_rounded) {
-        (Some(ra), Some(rb)) => Some((ra, rb)), // Both numbers rounded
-        (Some(ra), _) => Some((ra, b as usize)), // Only `a` rounded
-        (_, Some(rb)) => Some((a as usize, rb)), // Only `b` rounded
-        _ => None,                              // Neither rounded
-    }
-}
-
-fn round_if_zero_or_nine(number: f64) -> Option<usize> {
-    let integer_part = number.trunc();
-    let decimal_part = number - integer_part;
-
-    // Check if the decimal part is close to zero or one (sequence of nines or zeros).
-    if (decimal_part - 0.0).abs() < 1e-3 || (decimal_part - 1.0).abs() < 1e-3 {
-        return Some(number.round() as usize);
+/// `a` and `b` rounded to the nearest whole presses, if those reach the prize of both
+/// `(coefficient_a, coefficient_b, result)` equations exactly. Floating-point elimination lands
+/// next to whole numbers, on either side, so checking the rounded presses in integers is what
+/// tells a real solution from a near miss.
+fn presses_reaching_prize(
+    a: f64,
+    b: f64,
+    equations: &[(i128, i128, i128)],
+) -> Option<(usize, usize)> {
+    let (a, b) = (a.round(), b.round());
+    if a < 0.0 || b < 0.0 {
+        return None;
     }
-
-    None
+    let (a, b) = (a as i128, b as i128);
+    equations
+        .iter()
+        .all(|&(coefficient_a, coefficient_b, result)| {
+            coefficient_a * a + coefficient_b * b == result
+        })
+        .then_some((a as usize, b as usize))
 }
 
 // This worked for the solution of the challenge!!!
@@ -117,21 +109,11 @@ pub fn solve_by_substitution(eq1: (i64, i64, i64), eq2: (i64, i64, i64)) -> Opti
     // Substitute back to find A
     let a = (r1 - b1 * b) / a1;
 
-    if a.fract().abs() == 0.0 || b.fract().abs() == 0.0 {
-        let a = a.trunc() as usize;
-        let b = b.trunc() as usize;
-
-        return Some((a, b));
-    }
-
-    return None;
-
-    // Check if solution is reasonably close to integers
-    // if (a.round() - a).abs() < 1e-10 && (b.round() - b).abs() < 1e-10 {
-    //     return Some((a.round() as usize, b.round() as usize));
-    // } else {
-    //     return Some((a as usize, b as usize));
-    // }
+    presses_reaching_prize(
+        a,
+        b,
+        &[eq1, eq2].map(|(a, b, r)| (a.into(), b.into(), r.into())),
+    )
 }
 
 #[cfg(test)]
@@ -150,10 +132,7 @@ mod tests {
                 assert_eq!(a * 94 + b * 22, 8400);
                 assert_eq!(a * 34 + b * 67, 5400);
             }
-            None => {
-                println!("No unique solution exists.");
-                assert!(false);
-            }
+            None => panic!("No unique solution exists."),
         }
     }
 
@@ -178,10 +157,7 @@ mod tests {
                 assert_eq!(a * 17 + b * 84, 7870);
                 assert_eq!(a * 86 + b * 37, 6450);
             }
-            None => {
-                println!("No unique solution exists.");
-                assert!(false);
-            }
+            None => panic!("No unique solution exists."),
         }
     }
 
@@ -204,17 +180,14 @@ mod tests {
     fn test_substitution_method_on_example_2_part2() {
         let eq1 = (26, 67, 10000000012748);
         let eq2 = (66, 21, 10000000012176);
-        
+
         match solve_by_substitution(eq1, eq2) {
             Some((a, b)) => {
                 println!("Solution: A = {:.2}, B = {:.2}", a, b);
                 assert_eq!(a * 26 + b * 67, 10000000012748);
                 assert_eq!(a * 66 + b * 21, 10000000012176);
             }
-            None => {
-                println!("No unique solution exists.");
-                assert!(false);
-            }
+            None => panic!("No unique solution exists."),
         }
     }
 
@@ -235,10 +208,7 @@ mod tests {
                 assert_eq!(a * 69 + b * 27, 10000000018641);
                 assert_eq!(a * 23 + b * 71, 10000000010279);
             }
-            None => {
-                println!("No unique solution exists.");
-                assert!(false);
-            }
+            None => panic!("No unique solution exists."),
         }
     }
 
@@ -269,4 +239,25 @@ mod tests {
         let result: Option<(usize, usize)> = None;
         assert_eq!(gaussian_elimination(&mut matrix), result);
     }
+
+    #[test]
+    fn test_substitution_rejects_fractional_presses() {
+        // A comes out whole at 2483 presses, but B would need -2551.625.
+        assert_eq!(solve_by_substitution((98, 88, 18791), (26, 24, 3319)), None);
+    }
+
+    #[test]
+    fn test_gaussian_elimination_rounds_presses_just_below_a_whole_number() {
+        let mut matrix = vec![vec![95.0, 84.0, 5378.0], vec![94.0, 51.0, 4936.0]];
+        assert_eq!(gaussian_elimination(&mut matrix), Some((46, 12)));
+    }
+
+    #[test]
+    fn test_gaussian_elimination_rejects_near_misses_far_away() {
+        let mut matrix = vec![
+            vec![65.0, 72.0, 10000000009382.0],
+            vec![65.0, 58.0, 10000000008038.0],
+        ];
+        assert_eq!(gaussian_elimination(&mut matrix), None);
+    }
 }
@@ -0,0 +1,53 @@
+//! Reference answers for differential tests of the floating-point solvers.
+
+use crate::ClawMachine;
+
+/// A button's moves along one axis for A and B, then the prize's position along that axis.
+fn axis(values: &[i64]) -> (i64, i64, i64) {
+    match *values {
+        [a, b, prize] => (a, b, prize),
+        _ => panic!(
+            "expected two button moves and a prize position, found {:?}",
+            values
+        ),
+    }
+}
+
+/// The cheapest presses of A and B reaching the prize, trying every number of A presses.
+pub fn brute_force_presses(machine: &ClawMachine) -> Option<(usize, usize)> {
+    let (a_x, b_x, prize_x) = axis(&machine.x_values);
+    let (a_y, b_y, prize_y) = axis(&machine.y_values);
+    (0..=prize_x / a_x)
+        .filter_map(|a| {
+            let rest = prize_x - a * a_x;
+            let b = rest / b_x;
+            (rest % b_x == 0 && a * a_y + b * b_y == prize_y).then_some((a as usize, b as usize))
+        })
+        .min_by_key(|&(a, b)| 3 * a + b)
+}
+
+/// The presses reaching the prize moved by `offset`, by Cramer's rule in exact integers. Only
+/// meant for machines whose buttons move the claw in different directions, which then have at
+/// most one way to reach the prize.
+pub fn exact_presses(machine: &ClawMachine, offset: i64) -> Option<(usize, usize)> {
+    let (a_x, b_x, prize_x) = axis(&machine.x_values);
+    let (a_y, b_y, prize_y) = axis(&machine.y_values);
+    let [a_x, b_x, a_y, b_y] = [a_x, b_x, a_y, b_y].map(i128::from);
+    let (prize_x, prize_y) = (
+        i128::from(prize_x) + i128::from(offset),
+        i128::from(prize_y) + i128::from(offset),
+    );
+
+    let determinant = a_x * b_y - a_y * b_x;
+    assert_ne!(
+        determinant, 0,
+        "the buttons move the claw in the same direction"
+    );
+    let a = prize_x * b_y - prize_y * b_x;
+    let b = a_x * prize_y - a_y * prize_x;
+    if a % determinant != 0 || b % determinant != 0 {
+        return None;
+    }
+    let (a, b) = (a / determinant, b / determinant);
+    (a >= 0 && b >= 0).then_some((a as usize, b as usize))
+}
@@ -0,0 +1,23 @@
+use common::{Animate, Recorder, SolveError};
+
+use crate::{draw::robots_image, Bathroom, Day14};
+
+impl Animate for Day14 {
+    /// The robots at every second from the start until `seconds`, drawn as by `draw`.
+    fn animate(bathroom: &Bathroom, recorder: &mut Recorder) -> Result<(), SolveError> {
+        let params = &bathroom.params;
+        let mut robots = bathroom.robots.clone();
+        for second in 0..=params.seconds {
+            if recorder.is_full() {
+                break;
+            }
+            if second > 0 {
+                robots
+                    .iter_mut()
+                    .for_each(|robot| robot.navigate(params.height, params.width));
+            }
+            recorder.record(|| robots_image(&robots, params));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+use common::{render::Color, Draw, Grid, Image, SolveError};
+
+use crate::{patrol, Bathroom, Day14, Robot, RoomParams};
+
+/// The middle row and column, which part 1 leaves out of every quadrant.
+const MIDDLE: Color = Color::rgb(40, 40, 56);
+
+impl Draw for Day14 {
+    /// The bathroom once the robots have patrolled it for `seconds`: tiles with one robot in
+    /// green, with several in yellow, and the middle row and column darkened.
+    fn draw(bathroom: &Bathroom) -> Result<Image, SolveError> {
+        Ok(robots_image(
+            &patrol(&bathroom.robots, &bathroom.params),
+            &bathroom.params,
+        ))
+    }
+}
+
+/// The room with `robots` where they stand.
+pub(crate) fn robots_image(robots: &[Robot], params: &RoomParams) -> Image {
+    let RoomParams { width, height, .. } = *params;
+    let mut counts = Grid::new(height, width, 0);
+    for robot in robots {
+        if let Some(count) = counts.get_mut(robot.row, robot.col) {
+            *count += 1;
+        }
+    }
+
+    let mut tiles = counts.map(|&count| match count {
+        0 => common::image::BACKGROUND,
+        1 => Color::GREEN,
+        _ => Color::YELLOW,
+    });
+    for (row, col) in tiles.positions().collect::<Vec<_>>() {
+        if (row == height / 2 || col == width / 2) && counts[(row, col)] == 0 {
+            tiles[(row, col)] = MIDDLE;
+        }
+    }
+    Image::new(tiles)
+}
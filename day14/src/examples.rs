@@ -0,0 +1,25 @@
+use common::Example;
+
+/// The robots from the puzzle text, which patrol a room 11 tiles wide and 7 tiles tall.
+pub const ROBOTS: &str = "\
+p=0,4 v=3,-3
+p=6,3 v=-1,-3
+p=10,3 v=-1,2
+p=2,0 v=2,-1
+p=0,0 v=1,3
+p=3,0 v=-2,-2
+p=7,6 v=-1,-3
+p=3,0 v=-1,-2
+p=9,3 v=2,3
+p=7,3 v=-1,2
+p=2,4 v=2,-3
+p=9,5 v=-3,-3
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "robots",
+    input: ROBOTS,
+    part1: Some("12"),
+    part2: None,
+    params: Some("width = 11\nheight = 7"),
+}];
@@ -0,0 +1,31 @@
+use common::{Generator, Part, Rng, SolveError};
+
+use crate::{Day14, RoomParams};
+
+impl Generator for Day14 {
+    const DEFAULT_SIZE: usize = 500;
+
+    /// Random robots rarely all stand apart, so part 2 may have no answer.
+    const SOLVES: &'static [Part] = &[Part::One];
+
+    fn check_params(params: &RoomParams) -> Result<(), SolveError> {
+        crate::check_room(params)
+    }
+
+    /// `size` robots placed anywhere in the room given by `params`, each moving less than the
+    /// room's width and height per second.
+    fn generate(size: usize, params: &RoomParams, rng: &mut Rng) -> String {
+        let (width, height) = (params.width as i64, params.height as i64);
+        (0..size)
+            .map(|_| {
+                format!(
+                    "p={},{} v={},{}\n",
+                    rng.i64_in(0..=width - 1),
+                    rng.i64_in(0..=height - 1),
+                    rng.i64_in(1 - width..=width - 1),
+                    rng.i64_in(1 - height..=height - 1)
+                )
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,338 @@
+use common::{input, Answer, Example, Solution, SolveError};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+mod animate;
+mod draw;
+mod examples;
+mod generator;
+
+pub struct Day14;
+
+/// The size of the bathroom the robots patrol, in tiles, and how long they patrol it for part 1.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RoomParams {
+    pub width: usize,
+    pub height: usize,
+    pub seconds: usize,
+}
+
+impl Default for RoomParams {
+    fn default() -> Self {
+        RoomParams {
+            width: 101,
+            height: 103,
+            seconds: 100,
+        }
+    }
+}
+
+pub struct Bathroom {
+    pub robots: Vec<Robot>,
+    pub params: RoomParams,
+}
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pub row: usize,
+    pub col: usize,
+    pub row_velocity: isize,
+    pub col_velocity: isize,
+}
+
+impl Robot {
+    fn navigate(&mut self, rows: usize, cols: usize) {
+        self.row = Robot::handle_movement(self.row, rows, self.row_velocity);
+        self.col = Robot::handle_movement(self.col, cols, self.col_velocity);
+    }
+
+    /// The position one second later, wrapping around the edges however far the robot moves.
+    fn handle_movement(position: usize, tiles_number: usize, velocity: isize) -> usize {
+        let tiles_number = tiles_number as isize;
+        ((position as isize).rem_euclid(tiles_number) + velocity.rem_euclid(tiles_number))
+            .rem_euclid(tiles_number) as usize
+    }
+}
+
+/// Rejects rooms without tiles, and rooms too large for the robots' signed positions.
+fn check_room(params: &RoomParams) -> Result<(), SolveError> {
+    let tiles = 1..=isize::MAX as usize;
+    if !tiles.contains(&params.width) || !tiles.contains(&params.height) {
+        return Err(SolveError::Params(format!(
+            "the room is {}x{}, each side needs 1 to {} tiles",
+            params.width,
+            params.height,
+            isize::MAX
+        )));
+    }
+    Ok(())
+}
+
+impl Solution for Day14 {
+    type Input = Bathroom;
+    type Params = RoomParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Self::parse_with(input, &RoomParams::default())
+    }
+
+    fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, SolveError> {
+        check_room(params)?;
+
+        let robots = input::lines(input)
+            .map(|line| {
+                let mut parts = line.text.split_whitespace();
+                let (Some(position_part), Some(velocity_part), None) =
+                    (parts.next(), parts.next(), parts.next())
+                else {
+                    return Err(line.error("expected 'p=x,y v=x,y'"));
+                };
+
+                let (col, row) = position_part
+                    .strip_prefix("p=")
+                    .and_then(|position| position.split_once(','))
+                    .ok_or_else(|| line.error_at(position_part, "expected 'p=x,y'"))?;
+                let (col_velocity, row_velocity) = velocity_part
+                    .strip_prefix("v=")
+                    .and_then(|velocity| velocity.split_once(','))
+                    .ok_or_else(|| line.error_at(velocity_part, "expected 'v=x,y'"))?;
+
+                let (row, col) = (line.parse::<usize>(row)?, line.parse::<usize>(col)?);
+                if col >= params.width || row >= params.height {
+                    return Err(line.error_at(
+                        position_part,
+                        format!(
+                            "the robot is outside the {}x{} room",
+                            params.width, params.height
+                        ),
+                    ));
+                }
+
+                // Velocities may exceed the room's size: robots wrap around as often as needed.
+                Ok(Robot {
+                    row,
+                    col,
+                    row_velocity: line.parse::<isize>(row_velocity)?,
+                    col_velocity: line.parse::<isize>(col_velocity)?,
+                })
+            })
+            .collect::<Result<_, SolveError>>()?;
+
+        Ok(Bathroom {
+            robots,
+            params: *params,
+        })
+    }
+
+    fn part1(bathroom: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(safety_factor(&bathroom.robots, &bathroom.params)?.into())
+    }
+
+    fn part2(_bathroom: &Self::Input) -> Result<Answer, SolveError> {
+        Err(SolveError::NoSolution("part 2 is not solved".to_string()))
+    }
+}
+
+/// Product of the robots counted in each quadrant of the room after `params.seconds` seconds.
+pub fn safety_factor(robots: &[Robot], params: &RoomParams) -> Result<u64, SolveError> {
+    let robots = patrol(robots, params);
+    let room_area = (params.height, params.width);
+
+    let robots_count_map: HashMap<(usize, usize), usize> =
+        robots.iter().fold(HashMap::new(), |mut acc, robot| {
+            let count = acc.entry((robot.row, robot.col)).or_insert(0);
+            *count += 1;
+            acc
+        });
+
+    let center_position = (room_area.0 / 2, room_area.1 / 2);
+
+    let robots_count_quadrant1 = count_robots_by_quadrant(
+        &robots_count_map,
+        0,
+        0,
+        center_position.0,
+        center_position.1,
+    );
+    let robots_count_quadrant2 = count_robots_by_quadrant(
+        &robots_count_map,
+        0,
+        center_position.1 + 1,
+        center_position.0,
+        room_area.1,
+    );
+    let robots_count_quadrant3 = count_robots_by_quadrant(
+        &robots_count_map,
+        center_position.0 + 1,
+        0,
+        room_area.0,
+        center_position.1,
+    );
+    let robots_count_quadrant4 = count_robots_by_quadrant(
+        &robots_count_map,
+        center_position.0 + 1,
+        center_position.1 + 1,
+        room_area.0,
+        room_area.1,
+    );
+
+    [
+        robots_count_quadrant1,
+        robots_count_quadrant2,
+        robots_count_quadrant3,
+        robots_count_quadrant4,
+    ]
+    .into_iter()
+    .try_fold(1u64, |product, count| product.checked_mul(count as u64))
+    .ok_or(SolveError::Overflow)
+}
+
+/// The robots once they have patrolled the room for `params.seconds`.
+pub fn patrol(robots: &[Robot], params: &RoomParams) -> Vec<Robot> {
+    let mut robots = robots.to_vec();
+    for _ in 0..params.seconds {
+        robots
+            .iter_mut()
+            .for_each(|robot| robot.navigate(params.height, params.width));
+    }
+    robots
+}
+
+/// The number of robots in rows `start_row..end_row` and columns `start_col..end_col`.
+pub fn count_robots_by_quadrant(
+    robots_count_map: &HashMap<(usize, usize), usize>,
+    start_row: usize,
+    start_col: usize,
+    end_row: usize,
+    end_col: usize,
+) -> usize {
+    let mut robots_by_quadrant = 0;
+
+    for row in start_row..end_row {
+        for col in start_col..end_col {
+            if robots_count_map.contains_key(&(row, col)) {
+                robots_by_quadrant += robots_count_map.get(&(row, col)).unwrap();
+            }
+        }
+    }
+
+    robots_by_quadrant
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{animate::FrameLimits, render::Color, Animate, Draw, Recorder};
+
+    use super::*;
+
+    common::example_tests!(Day14);
+    common::generator_tests!(Day14, 20);
+    common::parser_fuzz_tests!(Day14, 20);
+
+    #[test]
+    fn test_params_must_fit_the_robots() {
+        let params = |width, height| RoomParams {
+            width,
+            height,
+            seconds: 100,
+        };
+
+        assert!(matches!(
+            Day14::parse_with("p=0,0 v=1,1", &params(0, 7)),
+            Err(SolveError::Params(_))
+        ));
+        assert!(Day14::parse_with("p=10,0 v=1,1", &params(11, 7)).is_ok());
+        assert!(Day14::parse_with("p=11,0 v=1,1", &params(11, 7)).is_err());
+        assert!(Day14::parse_with("p=0,7 v=1,1", &params(11, 7)).is_err());
+        assert!(Day14::parse_with("p=0,0 v=-50,30", &params(11, 7)).is_ok());
+    }
+
+    #[test]
+    fn test_safety_factor_of_many_robots() {
+        let params = RoomParams {
+            width: 3,
+            height: 3,
+            seconds: 0,
+        };
+        let corners = "p=0,0 v=0,0\np=2,0 v=0,0\np=0,2 v=0,0\np=2,2 v=0,0\n";
+        let robots = |count| Day14::parse_with(&corners.repeat(count), &params).unwrap();
+
+        assert_eq!(
+            safety_factor(&robots(50_000).robots, &params).unwrap(),
+            50_000u64.pow(4)
+        );
+        assert!(matches!(
+            safety_factor(&robots(70_000).robots, &params),
+            Err(SolveError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_robots_wrap_around_however_fast_they_move() {
+        assert_eq!(Robot::handle_movement(0, 11, -200), 9);
+        assert_eq!(Robot::handle_movement(10, 11, 200), 1);
+        assert_eq!(Robot::handle_movement(3, 11, -3), 0);
+    }
+
+    #[test]
+    fn test_draw_places_robots_after_patrol() {
+        let params = RoomParams {
+            width: 11,
+            height: 7,
+            seconds: 100,
+        };
+        let image = Day14::draw(&Day14::parse_with(examples::ROBOTS, &params).unwrap()).unwrap();
+        let tiles = image.tiles();
+        let count = |color| tiles.iter().filter(|&(_, &tile)| tile == color).count();
+
+        assert_eq!(tiles.dimensions(), (7, 11));
+        assert_eq!(count(Color::YELLOW), 2);
+        assert_eq!(count(Color::GREEN), 8);
+        assert_eq!(tiles[(0, 6)], Color::YELLOW);
+    }
+
+    #[test]
+    fn test_animation_ends_where_draw_does() {
+        let params = RoomParams {
+            width: 11,
+            height: 7,
+            seconds: 100,
+        };
+        let bathroom = Day14::parse_with(examples::ROBOTS, &params).unwrap();
+        let mut recorder = Recorder::new(FrameLimits {
+            stride: 10,
+            max_frames: 100,
+        });
+        Day14::animate(&bathroom, &mut recorder).unwrap();
+
+        assert_eq!(recorder.steps(), 101);
+        assert_eq!(recorder.frames().len(), 11);
+        assert_eq!(
+            recorder.frames().last(),
+            Some(&Day14::draw(&bathroom).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_movement_single_robot_by_five_seconds() {
+        let mut robot = Robot {
+            row: 4,
+            col: 2,
+            row_velocity: -3,
+            col_velocity: 2,
+        };
+
+        let rows = 7;
+        let cols = 11;
+
+        for _ in 0..5 {
+            robot.navigate(rows, cols);
+        }
+
+        assert_eq!(robot.row, 3);
+        assert_eq!(robot.col, 1);
+    }
+}
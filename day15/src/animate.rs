@@ -0,0 +1,22 @@
+use common::{Animate, Direction, Recorder, SolveError};
+
+use crate::{double_warehouse, draw::warehouse_image, get_warehouse_map, Day15, Warehouse};
+
+impl Animate for Day15 {
+    /// The doubled warehouse of part 2 before the first movement and after each one, drawn as
+    /// by `draw`.
+    fn animate(input: &Warehouse, recorder: &mut Recorder) -> Result<(), SolveError> {
+        let (mut robot, mut warehouse) =
+            get_warehouse_map(double_warehouse(input.warehouse.clone()));
+        recorder.record(|| warehouse_image(&warehouse));
+        for movement in input.movements.chars() {
+            if recorder.is_full() {
+                break;
+            }
+            let direction = Direction::from_arrow(movement).expect("Invalid movement");
+            robot.move_robot_part2(&mut warehouse, direction);
+            recorder.record(|| warehouse_image(&warehouse));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+use common::{image::BACKGROUND, render::Color, Draw, Grid, Image, SolveError};
+
+use crate::{double_warehouse, process_warehouse_movements, Day15, Warehouse};
+
+impl Draw for Day15 {
+    /// The doubled warehouse of part 2 after every movement, with walls in gray, boxes in
+    /// orange and the robot in red.
+    fn draw(input: &Warehouse) -> Result<Image, SolveError> {
+        Ok(warehouse_image(&process_warehouse_movements(
+            double_warehouse(input.warehouse.clone()),
+            input.movements.clone(),
+        )))
+    }
+}
+
+pub(crate) fn warehouse_image(warehouse: &Grid<char>) -> Image {
+    Image::new(warehouse.map(|&tile| match tile {
+        '#' => Color::GRAY,
+        '[' | ']' => Color::ORANGE,
+        '@' => Color::RED,
+        _ => BACKGROUND,
+    }))
+}
@@ -0,0 +1,41 @@
+use common::{Generator, Grid, NoParams, Rng};
+
+use crate::Day15;
+
+/// The robot's movements per line, as in the puzzle input.
+const MOVES_PER_LINE: usize = 1000;
+
+impl Generator for Day15 {
+    const DEFAULT_SIZE: usize = 50;
+    /// The robot needs an inner tile within the walls.
+    const MIN_SIZE: usize = 3;
+
+    /// A `size` by `size` warehouse surrounded by walls, with walls on about one inner tile in
+    /// ten and boxes on one in four, followed by `8 * size * size` random movements.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut warehouse = Grid::new(size, size, '#');
+        for (row, col) in warehouse.positions().collect::<Vec<_>>() {
+            if row == 0 || col == 0 || row == size - 1 || col == size - 1 {
+                continue;
+            }
+            warehouse[(row, col)] = match rng.usize_in(0..=19) {
+                0..=1 => '#',
+                2..=6 => 'O',
+                _ => '.',
+            };
+        }
+        let robot = (rng.usize_in(1..=size - 2), rng.usize_in(1..=size - 2));
+        warehouse[robot] = '@';
+
+        let movements: Vec<char> = (0..8 * size * size)
+            .map(|_| *rng.pick(&['^', '>', 'v', '<']))
+            .collect();
+        let mut input = warehouse.to_string();
+        for line in movements.chunks(MOVES_PER_LINE) {
+            input.push('\n');
+            input.extend(line);
+        }
+        input.push('\n');
+        input
+    }
+}
@@ -0,0 +1,297 @@
+use common::{input, Answer, Direction, Example, Grid, NoParams, Point, Solution, SolveError};
+
+pub use matrix::Robot;
+mod animate;
+mod draw;
+mod examples;
+mod generator;
+mod matrix;
+
+pub struct Day15;
+
+/// The warehouse layout and the robot's attempted moves.
+pub struct Warehouse {
+    pub warehouse: Grid<char>,
+    pub movements: String,
+}
+
+impl Solution for Day15 {
+    type Input = Warehouse;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let sections = input::numbered_sections(input);
+        let warehouse_lines: Vec<&str> = sections[0].iter().map(|line| line.text).collect();
+        let warehouse = Grid::try_parse_with(&warehouse_lines.join("\n"), |c| {
+            matches!(c, '#' | '.' | 'O' | '@').then_some(c)
+        })?;
+        match warehouse.find_all(&'@').count() {
+            0 => return Err(SolveError::invalid("the warehouse has no robot '@'")),
+            1 => (),
+            _ => {
+                return Err(SolveError::invalid(
+                    "the warehouse has more than one robot '@'",
+                ))
+            }
+        }
+
+        let Some(movement_lines) = sections.get(1) else {
+            return Err(SolveError::invalid("the robot's movements are missing"));
+        };
+        let mut movements = String::new();
+        for line in movement_lines {
+            if let Some((index, c)) = line
+                .text
+                .char_indices()
+                .find(|&(_, c)| Direction::from_arrow(c).is_none())
+            {
+                return Err(
+                    line.error_at(&line.text[index..], format!("'{}' is not a movement", c))
+                );
+            }
+            movements.push_str(line.text);
+        }
+
+        Ok(Warehouse {
+            warehouse,
+            movements,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(gps_coordinates_sum(input).into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(wide_gps_coordinates_sum(input).into())
+    }
+}
+
+/// The sum of the boxes' GPS coordinates once the robot has moved.
+pub fn gps_coordinates_sum(input: &Warehouse) -> i32 {
+    let (mut robot, mut warehouse_map) = get_warehouse_map(input.warehouse.clone());
+
+    for movement in input.movements.chars() {
+        let direction = Direction::from_arrow(movement).expect("Invalid movement");
+        robot.move_robot(&mut warehouse_map, direction);
+    }
+
+    warehouse_map.find_all(&'O').fold(0, |acc, position| {
+        acc + (100 * position.0 as i32 + position.1 as i32)
+    })
+}
+
+/// The sum of the wide boxes' GPS coordinates once the robot has moved in the doubled warehouse.
+pub fn wide_gps_coordinates_sum(input: &Warehouse) -> i32 {
+    let doubled_warehouse = double_warehouse(input.warehouse.clone());
+    let warehouse_map = process_warehouse_movements(doubled_warehouse, input.movements.clone());
+
+    warehouse_map.find_all(&'[').fold(0, |acc, position| {
+        acc + (100 * position.0 as i32 + position.1 as i32)
+    })
+}
+
+/// The doubled warehouse once the robot has made all its movements.
+pub fn process_warehouse_movements(warehouse: Grid<char>, movements: String) -> Grid<char> {
+    let (mut robot, mut warehouse_map) = get_warehouse_map(warehouse);
+
+    for movement in movements.chars() {
+        let direction = Direction::from_arrow(movement).expect("Invalid movement");
+        robot.move_robot_part2(&mut warehouse_map, direction);
+    }
+
+    warehouse_map
+}
+
+/// The warehouse with every tile twice as wide.
+pub fn double_warehouse(warehouse: Grid<char>) -> Grid<char> {
+    let rows = warehouse
+        .iter_rows()
+        .map(|line| {
+            line.iter()
+                .flat_map(|ch| {
+                    match ch {
+                        '#' => "##",
+                        'O' => "[]",
+                        '.' => "..",
+                        '@' => "@.",
+                        _ => "", // Using _ is the conventional way to match any remaining patterns
+                    }
+                    .chars()
+                })
+                .collect()
+        })
+        .collect();
+    Grid::from_rows(rows).unwrap()
+}
+
+/// The robot at the `@` tile, and the warehouse.
+pub(crate) fn get_warehouse_map(warehouse: Grid<char>) -> (Robot, Grid<char>) {
+    let position = warehouse.find(&'@').unwrap_or((0, 0));
+    (Robot::new(Point::from(position)), warehouse)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{animate::FrameLimits, render::Color, Animate, Draw, Recorder};
+
+    use super::*;
+
+    common::example_tests!(Day15);
+    common::generator_tests!(Day15, 8);
+    common::parser_fuzz_tests!(Day15, 8);
+
+    #[test]
+    fn test_draw_shows_the_wide_warehouse() {
+        let image = Day15::draw(&Day15::parse(examples::LARGER).unwrap()).unwrap();
+        let tiles = image.tiles();
+        let count = |color| tiles.iter().filter(|&(_, &tile)| tile == color).count();
+
+        assert_eq!(tiles.dimensions(), (10, 20));
+        assert_eq!(count(Color::ORANGE), 2 * 21);
+        assert_eq!(count(Color::RED), 1);
+    }
+
+    #[test]
+    fn test_animation_records_every_movement() {
+        let warehouse = Day15::parse(examples::SMALL_WIDE).unwrap();
+        let mut recorder = Recorder::new(FrameLimits::default());
+        Day15::animate(&warehouse, &mut recorder).unwrap();
+        let frames = recorder.frames();
+
+        assert_eq!(frames.len(), warehouse.movements.len() + 1);
+        assert_eq!(frames[0].tiles()[(3, 10)], Color::RED);
+        assert_eq!(frames.last(), Some(&Day15::draw(&warehouse).unwrap()));
+    }
+
+    /// A box right below the robot, with a wall one tile above where it can be pushed.
+    const BOX_BELOW_WALL: &str = "\
+#######
+#...#.#
+#.....#
+#...O.#
+#...@.#
+#.....#
+#######
+
+^
+";
+
+    #[test]
+    fn test_the_edge_of_a_warehouse_without_walls_blocks() {
+        let warehouse = Day15::parse("@\n\n<^>v\n").unwrap();
+        assert_eq!(Day15::part1(&warehouse).unwrap().to_string(), "0");
+        assert_eq!(Day15::part2(&warehouse).unwrap().to_string(), "0");
+
+        let warehouse = Day15::parse(".@O\n..O\n\n>v>^^\n").unwrap();
+        assert_eq!(Day15::part1(&warehouse).unwrap().to_string(), "104");
+        assert_eq!(Day15::part2(&warehouse).unwrap().to_string(), "108");
+    }
+
+    #[test]
+    fn test_rejects_unknown_tiles_and_extra_robots() {
+        assert!(Day15::parse("@x\n\n<\n").is_err());
+        assert!(Day15::parse("@@\n\n<\n").is_err());
+    }
+
+    #[test]
+    fn test_move_boxes_to_left_and_empty_down() {
+        let Warehouse { warehouse, .. } = Day15::parse(examples::SMALL_WIDE).unwrap();
+        let doubled_warehouse = double_warehouse(warehouse);
+        let movements = "<v".to_string();
+        let warehouse_map = process_warehouse_movements(doubled_warehouse, movements);
+        assert_eq!(warehouse_map.get(3, 5).unwrap(), &'[');
+        assert_eq!(warehouse_map.get(3, 6).unwrap(), &']');
+        assert_eq!(warehouse_map.get(3, 7).unwrap(), &'[');
+        assert_eq!(warehouse_map.get(3, 8).unwrap(), &']');
+        assert_eq!(warehouse_map.get(3, 9).unwrap(), &'.');
+        assert_eq!(warehouse_map.get(4, 9).unwrap(), &'@');
+    }
+
+    #[test]
+    fn test_move_one_box_up() {
+        let Warehouse { warehouse, .. } = Day15::parse(BOX_BELOW_WALL).unwrap();
+        let doubled_warehouse = double_warehouse(warehouse);
+        let movements = "^".to_string();
+        let warehouse_map = process_warehouse_movements(doubled_warehouse, movements);
+
+        assert_eq!(warehouse_map.get(2, 8).unwrap(), &'[');
+        assert_eq!(warehouse_map.get(2, 9).unwrap(), &']');
+
+        assert_eq!(warehouse_map.get(3, 8).unwrap(), &'@');
+        assert_eq!(warehouse_map.get(3, 9).unwrap(), &'.');
+
+        assert_eq!(warehouse_map.get(4, 8).unwrap(), &'.');
+    }
+
+    #[test]
+    fn test_no_box_moved() {
+        let Warehouse { warehouse, .. } = Day15::parse(BOX_BELOW_WALL).unwrap();
+        let doubled_warehouse = double_warehouse(warehouse);
+        let movements = "^^^".to_string();
+        let warehouse_map = process_warehouse_movements(doubled_warehouse, movements);
+
+        assert_eq!(warehouse_map.get(2, 8).unwrap(), &'[');
+        assert_eq!(warehouse_map.get(2, 9).unwrap(), &']');
+
+        assert_eq!(warehouse_map.get(3, 8).unwrap(), &'@');
+        assert_eq!(warehouse_map.get(3, 9).unwrap(), &'.');
+
+        assert_eq!(warehouse_map.get(4, 8).unwrap(), &'.');
+    }
+
+    #[test]
+    fn test_move_boxes_up() {
+        let Warehouse { warehouse, .. } = Day15::parse(examples::SMALL_WIDE).unwrap();
+        let doubled_warehouse = double_warehouse(warehouse);
+        let movements = "<vv<<^".to_string();
+        let warehouse_map = process_warehouse_movements(doubled_warehouse, movements);
+
+        assert_eq!(warehouse_map.get(2, 5).unwrap(), &'[');
+        assert_eq!(warehouse_map.get(2, 6).unwrap(), &']');
+        assert_eq!(warehouse_map.get(2, 7).unwrap(), &'[');
+        assert_eq!(warehouse_map.get(2, 8).unwrap(), &']');
+
+        assert_eq!(warehouse_map.get(3, 5).unwrap(), &'.');
+        assert_eq!(warehouse_map.get(3, 6).unwrap(), &'[');
+        assert_eq!(warehouse_map.get(3, 7).unwrap(), &']');
+        assert_eq!(warehouse_map.get(3, 8).unwrap(), &'.');
+
+        assert_eq!(warehouse_map.get(3, 9).unwrap(), &'.');
+        assert_eq!(warehouse_map.get(4, 9).unwrap(), &'.');
+        assert_eq!(warehouse_map.get(4, 8).unwrap(), &'.');
+        assert_eq!(warehouse_map.get(4, 7).unwrap(), &'@');
+    }
+
+    #[test]
+    fn test_move_boxes_up_with_string_comparison() {
+        let Warehouse {
+            warehouse,
+            movements,
+        } = Day15::parse(examples::LARGER).unwrap();
+        let doubled_warehouse = double_warehouse(warehouse);
+        let warehouse_map = process_warehouse_movements(doubled_warehouse, movements);
+
+        let expected_warehouse = vec![
+            "####################",
+            "##[].......[].[][]##",
+            "##[]...........[].##",
+            "##[]........[][][]##",
+            "##[]......[]....[]##",
+            "##..##......[]....##",
+            "##..[]............##",
+            "##..@......[].[][]##",
+            "##......[][]..[]..##",
+            "####################",
+        ];
+
+        let result_warehouse: Vec<String> = warehouse_map
+            .iter_rows()
+            .map(|row| row.iter().collect())
+            .collect();
+
+        assert_eq!(result_warehouse, expected_warehouse);
+    }
+}
@@ -1,87 +1,54 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashSet, VecDeque};
 
-#[derive(Eq, PartialEq, Hash, Copy, Clone)]
-pub struct Direction {
-    pub row: isize,
-    pub col: isize,
-}
+use common::{Direction, Grid, Point};
 
 #[derive(Eq, PartialEq, Hash, Copy, Clone)]
 pub struct Robot {
-    pub row: usize,
-    pub col: usize,
+    pub position: Point,
     pub is_stuck: bool,
     pub current_direction: Direction,
 }
 
 impl Robot {
-    pub fn new(row: usize, col: usize) -> Self {
+    pub fn new(position: Point) -> Self {
         Self {
-            row,
-            col,
+            position,
             is_stuck: false,
-            current_direction: Direction::RIGHT,
+            current_direction: Direction::Right,
         }
     }
 
-    pub fn move_robot(
-        &mut self,
-        warehouse_map: &mut HashMap<(usize, usize), char>,
-        direction: Direction,
-    ) -> Self {
+    pub fn move_robot(&mut self, warehouse_map: &mut Grid<char>, direction: Direction) -> Self {
         let new_robot_position = Self {
-            row: (self.row as isize + direction.row) as usize,
-            col: (self.col as isize + direction.col) as usize,
+            position: self.position + direction.offset(),
             is_stuck: false,
             current_direction: direction,
         };
 
-        let new_position_value = warehouse_map
-            .get_mut(&(new_robot_position.row, new_robot_position.col))
-            .unwrap();
+        // Past the edge of a warehouse without walls around it is as blocked as a wall.
+        let Some(new_position_value) = warehouse_map.get_point_mut(new_robot_position.position)
+        else {
+            return *self;
+        };
 
         match *new_position_value {
             '.' => {
                 *new_position_value = '@';
-                warehouse_map
-                    .entry((self.row, self.col))
-                    .and_modify(|v| *v = '.');
+                warehouse_map[self.position] = '.';
                 *self = new_robot_position;
             }
             'O' => {
-                let mut neighbor_position = Self {
-                    row: (new_robot_position.row as isize + direction.row) as usize,
-                    col: (new_robot_position.col as isize + direction.col) as usize,
-                    is_stuck: false,
-                    current_direction: direction,
-                };
-                while warehouse_map
-                    .get(&(neighbor_position.row, neighbor_position.col))
-                    .unwrap()
-                    == &'O'
-                {
-                    neighbor_position.row =
-                        (neighbor_position.row as isize + direction.row) as usize;
-                    neighbor_position.col =
-                        (neighbor_position.col as isize + direction.col) as usize;
+                let mut neighbor_position = new_robot_position.position + direction.offset();
+                while warehouse_map.get_point(neighbor_position) == Some(&'O') {
+                    neighbor_position += direction.offset();
                 }
 
-                match warehouse_map
-                    .get_mut(&(neighbor_position.row, neighbor_position.col))
-                    .unwrap()
-                {
-                    '.' => {
-                        *warehouse_map
-                            .get_mut(&(neighbor_position.row, neighbor_position.col))
-                            .unwrap() = 'O';
-                        *warehouse_map
-                            .get_mut(&(new_robot_position.row, new_robot_position.col))
-                            .unwrap() = '@';
-                        *warehouse_map.get_mut(&(self.row, self.col)).unwrap() = '.';
-
-                        *self = new_robot_position;
-                    }
-                    _ => (),
+                if warehouse_map.get_point(neighbor_position) == Some(&'.') {
+                    warehouse_map[neighbor_position] = 'O';
+                    warehouse_map[new_robot_position.position] = '@';
+                    warehouse_map[self.position] = '.';
+
+                    *self = new_robot_position;
                 }
             }
             _ => (),
@@ -92,7 +59,7 @@ impl Robot {
 
     pub fn move_robot_part2(
         &mut self,
-        warehouse_map: &mut HashMap<(usize, usize), char>,
+        warehouse_map: &mut Grid<char>,
         direction: Direction,
     ) -> Self {
         if self.is_stuck && direction == self.current_direction {
@@ -103,106 +70,85 @@ impl Robot {
         self.current_direction = direction;
 
         let new_robot_position = Self {
-            row: (self.row as isize + direction.row) as usize,
-            col: (self.col as isize + direction.col) as usize,
+            position: self.position + direction.offset(),
             is_stuck: false,
             current_direction: direction,
         };
 
-        let new_position_value = warehouse_map
-            .get(&(new_robot_position.row, new_robot_position.col))
-            .unwrap();
+        let Some(&new_position_value) = warehouse_map.get_point(new_robot_position.position) else {
+            return *self;
+        };
 
-        match *new_position_value {
+        match new_position_value {
             '.' => {
                 self.update_position(warehouse_map, new_robot_position);
             }
-            '[' | ']' => match direction {
-                Direction::LEFT | Direction::RIGHT => {
+            '[' | ']' => {
+                if direction.is_horizontal() {
                     self.process_horizontal_movement_part2(
                         warehouse_map,
                         direction,
                         new_robot_position,
                     );
-                }
-                Direction::TOP | Direction::BOTTOM => {
+                } else {
                     self.process_vertical_movement_part2(
                         warehouse_map,
                         direction,
                         new_robot_position,
                     );
                 }
-                _ => (),
-            },
+            }
             _ => (),
         }
 
         *self
     }
 
-    fn update_position(
-        &mut self,
-        warehouse_map: &mut HashMap<(usize, usize), char>,
-        new_robot_position: Robot,
-    ) {
-        warehouse_map
-            .entry((new_robot_position.row, new_robot_position.col))
-            .and_modify(|v| *v = '@');
+    fn update_position(&mut self, warehouse_map: &mut Grid<char>, new_robot_position: Robot) {
+        warehouse_map[new_robot_position.position] = '@';
 
-        warehouse_map
-            .entry((self.row, self.col))
-            .and_modify(|v| *v = '.');
+        warehouse_map[self.position] = '.';
 
         *self = new_robot_position;
     }
 
     fn process_horizontal_movement_part2(
         &mut self,
-        warehouse_map: &mut HashMap<(usize, usize), char>,
+        warehouse_map: &mut Grid<char>,
         direction: Direction,
         new_robot_position: Robot,
     ) {
-        let mut neighbor_position = Self {
-            row: (new_robot_position.row as isize + direction.row) as usize,
-            col: (new_robot_position.col as isize + direction.col) as usize,
-            is_stuck: false,
-            current_direction: direction,
-        };
+        let mut neighbor_position = new_robot_position.position + direction.offset();
 
-        let mut positions_to_move: Vec<(usize, usize)> = Vec::new();
-        positions_to_move.push((new_robot_position.row, new_robot_position.col));
+        let mut positions_to_move: Vec<Point> = Vec::new();
+        positions_to_move.push(new_robot_position.position);
 
-        while let Some(&ch) = warehouse_map.get(&(neighbor_position.row, neighbor_position.col)) {
+        while let Some(&ch) = warehouse_map.get_point(neighbor_position) {
             if ch == '[' || ch == ']' {
-                positions_to_move.push((neighbor_position.row, neighbor_position.col));
+                positions_to_move.push(neighbor_position);
 
-                neighbor_position.row = (neighbor_position.row as isize + direction.row) as usize;
-                neighbor_position.col = (neighbor_position.col as isize + direction.col) as usize;
+                neighbor_position += direction.offset();
             } else {
                 break;
             }
         }
 
-        if let Some(ch) = warehouse_map.get(&(neighbor_position.row, neighbor_position.col)) {
+        if let Some(ch) = warehouse_map.get_point(neighbor_position) {
             if *ch == '.' {
-                positions_to_move.push((neighbor_position.row, neighbor_position.col));
+                positions_to_move.push(neighbor_position);
 
-                while !positions_to_move.is_empty() {
-                    let (row, col) = positions_to_move.pop().unwrap();
+                while let Some(position) = positions_to_move.pop() {
                     match positions_to_move.last() {
-                        Some((previous_row, previous_col)) => {
-                            *warehouse_map.get_mut(&(row, col)).unwrap() =
-                                *warehouse_map.get(&(*previous_row, *previous_col)).unwrap();
+                        Some(&previous_position) => {
+                            warehouse_map[position] = warehouse_map[previous_position];
                         }
                         None => {
-                            *warehouse_map.get_mut(&(row, col)).unwrap() = '@';
+                            warehouse_map[position] = '@';
                         }
                     }
                 }
 
-                warehouse_map
-                    .entry((self.row, self.col))
-                    .and_modify(|v| *v = '.');
+                warehouse_map[self.position] = '.';
                 *self = new_robot_position;
             }
         }
@@ -210,62 +156,49 @@ impl Robot {
 
     fn process_vertical_movement_part2(
         &mut self,
-        warehouse_map: &mut HashMap<(usize, usize), char>,
+        warehouse_map: &mut Grid<char>,
         direction: Direction,
         new_robot_position: Robot,
     ) {
-        let mut positions_to_move: Vec<(usize, usize)> = Vec::new();
-        positions_to_move.push((new_robot_position.row, new_robot_position.col));
-
-        if warehouse_map
-            .get(&(new_robot_position.row, new_robot_position.col))
-            .unwrap()
-            == &'['
-        {
-            positions_to_move.push((new_robot_position.row, new_robot_position.col + 1));
+        let mut positions_to_move: Vec<Point> = Vec::new();
+        positions_to_move.push(new_robot_position.position);
+
+        if warehouse_map.get_point(new_robot_position.position) == Some(&'[') {
+            positions_to_move.push(new_robot_position.position + Point::RIGHT);
         } else {
-            positions_to_move.push((new_robot_position.row, new_robot_position.col - 1));
+            positions_to_move.push(new_robot_position.position + Point::LEFT);
         }
 
-        let mut queue: VecDeque<(usize, usize)> = VecDeque::from(positions_to_move.clone());
-        let mut visited_positions: HashSet<(usize, usize)> = positions_to_move.clone().into_iter().collect();
+        let mut queue: VecDeque<Point> = VecDeque::from(positions_to_move.clone());
+        let mut visited_positions: HashSet<Point> = positions_to_move.clone().into_iter().collect();
 
-        while !queue.is_empty() {
-            let (row, col) = queue.pop_front().unwrap();
+        while let Some(position) = queue.pop_front() {
+            let neighbor_position = position + direction.offset();
 
-            let neighbor_position = Self {
-                row: (row as isize + direction.row) as usize,
-                col: (col as isize + direction.col) as usize,
-                is_stuck: false,
-                current_direction: direction,
+            let Some(&ch) = warehouse_map.get_point(neighbor_position) else {
+                self.is_stuck = true;
+                break;
             };
-
-            if let Some(&ch) = warehouse_map.get(&(neighbor_position.row, neighbor_position.col)) {
-                match ch {
-                    '[' | ']' => {
-                        let mut other_side_of_box = neighbor_position.col + 1;
-                        if ch == ']' {
-                            other_side_of_box = neighbor_position.col - 1;
-                        } 
-
-                        if !visited_positions.contains(&(neighbor_position.row, neighbor_position.col)) {
-                            positions_to_move.push((neighbor_position.row, neighbor_position.col));
-                            queue.push_back((neighbor_position.row, neighbor_position.col));
-                            visited_positions.insert((neighbor_position.row, neighbor_position.col));
-                        }
-
-                        if !visited_positions.contains(&(neighbor_position.row, other_side_of_box)) {
-                            positions_to_move.push((neighbor_position.row, other_side_of_box));
-                            queue.push_back((neighbor_position.row, other_side_of_box));
-                            visited_positions.insert((neighbor_position.row, other_side_of_box));
+            match ch {
+                '[' | ']' => {
+                    let other_side_of_box = if ch == '[' {
+                        neighbor_position + Point::RIGHT
+                    } else {
+                        neighbor_position + Point::LEFT
+                    };
+
+                    for box_side in [neighbor_position, other_side_of_box] {
+                        if visited_positions.insert(box_side) {
+                            positions_to_move.push(box_side);
+                            queue.push_back(box_side);
                         }
                     }
-                    '#' => {
-                        self.is_stuck = true;
-                        break;
-                    }
-                    _ => (),
                 }
+                '#' => {
+                    self.is_stuck = true;
+                    break;
+                }
+                _ => (),
             }
         }
 
@@ -273,30 +206,14 @@ impl Robot {
             return;
         }
 
-        while !positions_to_move.is_empty() {
-            let (box_row, box_col) = positions_to_move.pop().unwrap();
-            let box_value = warehouse_map.get(&(box_row, box_col)).unwrap().clone();
-            let (new_row, new_col) = (
-                box_row as isize + direction.row,
-                box_col as isize + direction.col,
-            );
-
-            warehouse_map
-                .entry((new_row as usize, new_col as usize))
-                .and_modify(|v| *v = box_value);
-
-            warehouse_map
-                .entry((box_row, box_col))
-                .and_modify(|v| *v = '.');
+        while let Some(box_position) = positions_to_move.pop() {
+            let box_value = warehouse_map[box_position];
+
+            warehouse_map[box_position + direction.offset()] = box_value;
+
+            warehouse_map[box_position] = '.';
         }
 
         self.update_position(warehouse_map, new_robot_position);
     }
 }
-
-impl Direction {
-    pub const TOP: Self = Self { row: -1, col: 0 };
-    pub const BOTTOM: Self = Self { row: 1, col: 0 };
-    pub const LEFT: Self = Self { row: 0, col: -1 };
-    pub const RIGHT: Self = Self { row: 0, col: 1 };
-}
\ No newline at end of file
@@ -0,0 +1,14 @@
+use common::{render::Color, Draw, Image, Render, SolveError};
+
+use crate::{lowest_scoring_path, Day16, Maze};
+
+impl Draw for Day16 {
+    /// The rendered maze with one of the lowest scoring paths traced over the best-path tiles.
+    fn draw(maze: &Maze) -> Result<Image, SolveError> {
+        let (path, _) = lowest_scoring_path(maze)?;
+        Ok(Image::from_scene(&Day16::render(maze)?).with_path(
+            path.into_iter().map(|step| step.position).collect(),
+            Color::YELLOW,
+        ))
+    }
+}
@@ -0,0 +1,58 @@
+use common::Example;
+
+/// The first maze from the puzzle text.
+pub const FIRST: &str = "\
+###############
+#.......#....E#
+#.#.###.#.###.#
+#.....#.#...#.#
+#.###.#####.#.#
+#.#.#.......#.#
+#.#.#####.###.#
+#...........#.#
+###.#.#####.#.#
+#...#.....#.#.#
+#.#.#.###.#.#.#
+#.....#...#.#.#
+#.###.#.#.#.#.#
+#S..#.....#...#
+###############
+";
+
+/// The second maze from the puzzle text.
+pub const SECOND: &str = "\
+#################
+#...#...#...#..E#
+#.#.#.#.#.#.#.#.#
+#.#.#.#...#...#.#
+#.#.#.#.###.#.#.#
+#...#.#.#.....#.#
+#.#.#.#.#.#####.#
+#.#...#.#.#.....#
+#.#.#####.#.###.#
+#.#.#.......#...#
+#.#.###.#####.###
+#.#.#...#.....#.#
+#.#.#.#####.###.#
+#.#.#.........#.#
+#.#.#.#########.#
+#S#.............#
+#################
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "first maze",
+        input: FIRST,
+        part1: Some("7036"),
+        part2: Some("45"),
+        params: None,
+    },
+    Example {
+        name: "second maze",
+        input: SECOND,
+        part1: Some("11048"),
+        part2: Some("64"),
+        params: None,
+    },
+];
@@ -0,0 +1,26 @@
+use common::{generate, Generator, NoParams, Rng};
+
+use crate::Day16;
+
+impl Generator for Day16 {
+    const DEFAULT_SIZE: usize = 141;
+    /// The smallest maze with distinct corners for 'S' and 'E' is 5 by 5.
+    const MIN_SIZE: usize = 5;
+
+    /// A maze of about `size` by `size` tiles from the bottom-left to the top-right corner.
+    /// About one wall in ten between corridors is knocked down, so that there are several best
+    /// paths to tell apart.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut maze = generate::maze((size - 1) / 2, rng);
+        let side = maze.rows();
+        for (row, col) in maze.positions().collect::<Vec<_>>() {
+            let inner = row > 0 && col > 0 && row < side - 1 && col < side - 1;
+            if inner && (row + col) % 2 == 1 && rng.chance(1, 10) {
+                maze[(row, col)] = '.';
+            }
+        }
+        maze[(side - 2, 1)] = 'S';
+        maze[(1, side - 2)] = 'E';
+        maze.to_string()
+    }
+}
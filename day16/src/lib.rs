@@ -0,0 +1,242 @@
+use std::collections::HashSet;
+
+use common::{
+    search::{self, SearchSpace, ShortestPaths},
+    Answer, Direction, Example, Grid, NoParams, Point, Solution, SolveError,
+};
+
+pub use matrix_utils::Cell;
+
+mod draw;
+mod examples;
+mod generator;
+mod matrix_utils;
+mod render;
+
+/// Where the reindeer stands and which way it faces.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct StepState {
+    pub position: Point,
+    pub direction: Direction,
+}
+
+pub struct Day16;
+
+/// The reindeer maze with the start tile (facing east) and the end tile.
+pub struct Maze {
+    pub maze: Grid<Cell>,
+    pub start_position: StepState,
+    pub end_position: Point,
+}
+
+impl Solution for Day16 {
+    type Input = Maze;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        parse_input_to_maze_matrix(input)
+    }
+
+    fn part1(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(lowest_score(input)?.into())
+    }
+
+    fn part2(input: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(best_path_tiles(input)?.into())
+    }
+}
+
+/// Stepping forward costs 1 and turning a quarter before stepping costs 1000 more.
+impl SearchSpace for Maze {
+    type State = StepState;
+
+    fn position(state: &StepState) -> Point {
+        state.position
+    }
+
+    fn moves(&self, pos: &StepState) -> Vec<(StepState, usize)> {
+        [
+            (pos.direction, 1),
+            (pos.direction.turn_left(), 1001),
+            (pos.direction.turn_right(), 1001),
+        ]
+        .into_iter()
+        .filter_map(|(direction, cost)| {
+            let neighbor = pos.position + direction.offset();
+            (self.maze.get_point(neighbor) == Some(&Cell::Tile)).then_some((
+                StepState {
+                    position: neighbor,
+                    direction,
+                },
+                cost,
+            ))
+        })
+        .collect()
+    }
+}
+
+/// The lowest score of a path from the start to the end tile.
+pub fn lowest_score(input: &Maze) -> Result<usize, SolveError> {
+    Ok(lowest_scoring_path(input)?.1)
+}
+
+/// One of the lowest scoring paths from the start to the end tile, with its score.
+pub fn lowest_scoring_path(input: &Maze) -> Result<(Vec<StepState>, usize), SolveError> {
+    search::dijkstra(input, &input.start_position, |pos| {
+        pos.position == input.end_position
+    })
+    .ok_or_else(no_path_found)
+}
+
+/// The number of tiles on at least one of the lowest scoring paths.
+pub fn best_path_tiles(input: &Maze) -> Result<usize, SolveError> {
+    Ok(best_path_tile_set(input)?.len())
+}
+
+/// The tiles that are part of at least one of the lowest scoring paths: those the reindeer can
+/// stand on with a score from the start and a score left to the end adding up to the lowest.
+pub fn best_path_tile_set(input: &Maze) -> Result<HashSet<Point>, SolveError> {
+    let lowest = lowest_score(input)?;
+    let from_start = search::dijkstra_all(input, &input.start_position);
+    let to_end: Vec<ShortestPaths<StepState>> = Direction::ALL
+        .iter()
+        .map(|&direction| {
+            let end = StepState {
+                position: input.end_position,
+                direction,
+            };
+            search::dijkstra_all(&Backwards(input), &end)
+        })
+        .collect();
+
+    let start = (&input.start_position, 0);
+    let tiles = std::iter::once(start)
+        .chain(from_start.reached())
+        .filter(|&(state, score)| {
+            to_end
+                .iter()
+                .filter_map(|paths| paths.cost(state))
+                .any(|rest| score + rest == lowest)
+        })
+        .map(|(state, _)| state.position)
+        .collect();
+    Ok(tiles)
+}
+
+/// The maze searched from the end: the moves out of a state are the moves of [`Maze`] that lead
+/// into it, so the cost of reaching a state is the score left to get from it to the end.
+struct Backwards<'a>(&'a Maze);
+
+impl SearchSpace for Backwards<'_> {
+    type State = StepState;
+
+    fn position(state: &StepState) -> Point {
+        state.position
+    }
+
+    fn moves(&self, pos: &StepState) -> Vec<(StepState, usize)> {
+        let previous = pos.position - pos.direction.offset();
+        if self.0.maze.get_point(previous) != Some(&Cell::Tile) {
+            return Vec::new();
+        }
+        [
+            (pos.direction, 1),
+            (pos.direction.turn_left(), 1001),
+            (pos.direction.turn_right(), 1001),
+        ]
+        .into_iter()
+        .map(|(direction, cost)| {
+            (
+                StepState {
+                    position: previous,
+                    direction,
+                },
+                cost,
+            )
+        })
+        .collect()
+    }
+}
+
+fn no_path_found() -> SolveError {
+    SolveError::no_solution("the reindeer cannot reach the end tile")
+}
+
+/// The maze, with the reindeer at the start tile facing east.
+pub fn parse_input_to_maze_matrix(input: &str) -> Result<Maze, SolveError> {
+    let grid = Grid::try_parse_with(input, |c| matches!(c, '#' | '.' | 'S' | 'E').then_some(c))?;
+    let find_tile = |tile: char| {
+        grid.find(&tile)
+            .map(Point::from)
+            .ok_or_else(|| SolveError::invalid(format!("the maze has no '{}' tile", tile)))
+    };
+    let start_position: StepState = StepState {
+        position: find_tile('S')?,
+        direction: Direction::Right,
+    };
+    let end_position = find_tile('E')?;
+    let maze = grid.map(|c| match c {
+        'S' | 'E' | '.' => Cell::Tile,
+        _ => Cell::Wall,
+    });
+    Ok(Maze {
+        maze,
+        start_position,
+        end_position,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{differential::check_agreement, Draw, Render};
+
+    use super::*;
+
+    common::example_tests!(Day16);
+    common::generator_tests!(Day16, 15);
+    common::parser_fuzz_tests!(Day16, 15);
+
+    #[test]
+    fn test_best_path_tiles_agree_with_enumerating_the_paths() {
+        check_agreement::<Day16, _>(
+            15,
+            |maze| {
+                let (paths, _) =
+                    search::all_shortest_paths(maze, &maze.start_position, maze.end_position)?;
+                Some(
+                    paths
+                        .into_iter()
+                        .flatten()
+                        .map(|pos| pos.position)
+                        .collect(),
+                )
+            },
+            |maze| best_path_tile_set(maze).ok(),
+        );
+    }
+
+    #[test]
+    fn test_draw_traces_a_lowest_scoring_path() {
+        let maze = Day16::parse(examples::FIRST).unwrap();
+        let image = Day16::draw(&maze).unwrap();
+        let [path] = image.paths() else {
+            panic!("one path is traced");
+        };
+
+        assert_eq!(path.points.first(), Some(&maze.start_position.position));
+        assert_eq!(path.points.last(), Some(&maze.end_position));
+        assert_eq!(image.tiles().dimensions(), maze.maze.dimensions());
+    }
+
+    #[test]
+    fn test_render_marks_best_path_tiles() {
+        let scene = Day16::render(&Day16::parse(examples::FIRST).unwrap()).unwrap();
+        let drawn = scene.to_string();
+
+        // The start and end tiles are on every best path but keep their own glyphs.
+        assert_eq!(drawn.matches('O').count() + 2, 45);
+        assert_eq!(drawn.replace('O', "."), examples::FIRST);
+    }
+}
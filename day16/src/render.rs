@@ -0,0 +1,29 @@
+use common::{
+    render::{Cell as Drawn, Color, Style},
+    Render, Scene, SolveError,
+};
+
+use crate::{best_path_tile_set, matrix_utils::Cell, Day16, Maze};
+
+impl Render for Day16 {
+    /// The maze with the tiles of every best path drawn as `O`, as in the puzzle text.
+    fn render(maze: &Maze) -> Result<Scene, SolveError> {
+        let tiles = best_path_tile_set(maze)?;
+        let path_style = Style::fg(Color::GREEN).bold();
+
+        let scene = Scene::new(&maze.maze, |cell| match cell {
+            Cell::Wall => Drawn::new('#', Style::fg(Color::GRAY)),
+            Cell::Tile => Drawn::plain('.'),
+        });
+        let scene = tiles
+            .into_iter()
+            .fold(scene, |scene, tile| scene.mark(tile, 'O', path_style));
+        Ok(scene
+            .mark(
+                maze.start_position.position,
+                'S',
+                Style::fg(Color::YELLOW).bold(),
+            )
+            .mark(maze.end_position, 'E', Style::fg(Color::RED).bold()))
+    }
+}
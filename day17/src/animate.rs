@@ -0,0 +1,67 @@
+use common::{
+    image::BACKGROUND,
+    render::{Color, PALETTE},
+    Animate, Grid, Image, Recorder, SolveError,
+};
+
+use crate::{Computer, Day17};
+
+/// A cleared bit of a register.
+const CLEAR: Color = Color::rgb(40, 40, 56);
+
+impl Animate for Day17 {
+    /// The computer before its first instruction and after each one. The top three rows are
+    /// the bits of registers A, B and C, most significant first; below them is the program
+    /// with the executing opcode in red and its operand in orange, and the last outputs, each
+    /// value in its own color.
+    fn animate(computer: &Computer, recorder: &mut Recorder) -> Result<(), SolveError> {
+        let mut computer = computer.clone();
+        let bits = [
+            computer.register_a,
+            computer.register_b,
+            computer.register_c,
+        ]
+        .iter()
+        .map(|register| (usize::BITS - register.leading_zeros()) as usize)
+        .max()
+        .unwrap_or(0);
+        let width = bits.max(3).max(computer.instructions.len());
+
+        recorder.record(|| computer_image(&computer, width));
+        while !recorder.is_full() && computer.step() {
+            recorder.record(|| computer_image(&computer, width));
+        }
+        Ok(())
+    }
+}
+
+fn computer_image(computer: &Computer, width: usize) -> Image {
+    let mut tiles = Grid::new(6, width, BACKGROUND);
+    let registers = [
+        (computer.register_a, Color::YELLOW),
+        (computer.register_b, Color::CYAN),
+        (computer.register_c, Color::MAGENTA),
+    ];
+    for (row, (register, color)) in registers.into_iter().enumerate() {
+        for col in 0..width {
+            let bit = width - 1 - col;
+            let set = bit < usize::BITS as usize && register >> bit & 1 == 1;
+            tiles[(row, col)] = if set { color } else { CLEAR };
+        }
+    }
+
+    let pointer = computer.instruction_pointer();
+    for col in 0..computer.instructions.len() {
+        tiles[(4, col)] = match col {
+            _ if col == pointer => Color::RED,
+            _ if col == pointer + 1 => Color::ORANGE,
+            _ => Color::GRAY,
+        };
+    }
+
+    let shown = computer.output.len().saturating_sub(width);
+    for (col, &value) in computer.output[shown..].iter().enumerate() {
+        tiles[(5, col)] = PALETTE[value % PALETTE.len()];
+    }
+    Image::new(tiles)
+}
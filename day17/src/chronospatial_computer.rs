@@ -1,3 +1,9 @@
+use common::{input::Line, SolveError};
+
+/// How many instructions a program may execute before it is taken not to halt.
+pub const MAX_STEPS: usize = 10_000_000;
+
+#[derive(Clone)]
 pub struct Computer {
     pub register_a: usize,
     pub register_b: usize,
@@ -12,57 +18,106 @@ impl Computer {
         register_a: usize,
         register_b: usize,
         register_c: usize,
-        instructions: String,
+        instructions: Vec<usize>,
     ) -> Computer {
         Computer {
             register_a,
             register_b,
             register_c,
             instruction_pointer: 0,
-            instructions: instructions
-                .split(',')
-                .map(|c| c.parse::<usize>().unwrap())
-                .collect(),
+            instructions,
             output: Vec::new(),
         }
     }
 
-    pub fn new_from_input(input: &mut Vec<String>) -> Computer {
-        let register_a = Self::get_register_from_input(input[0].clone(), "A");
-        let register_b = Self::get_register_from_input(input[1].clone(), "B");
-        let register_c = Self::get_register_from_input(input[2].clone(), "C");
-        let instructions = input[4].replace("Program: ", "");
+    pub fn new_from_input(input: &[String]) -> Result<Computer, SolveError> {
+        let line = |index: usize| Line {
+            number: index + 1,
+            text: input.get(index).map(String::as_str).unwrap_or_default(),
+        };
+        let register_a = Self::get_register_from_input(line(0), "A")?;
+        let register_b = Self::get_register_from_input(line(1), "B")?;
+        let register_c = Self::get_register_from_input(line(2), "C")?;
+
+        let program_line = line(4);
+        let Some(program) = program_line.text.strip_prefix("Program: ") else {
+            return Err(program_line.error("expected 'Program: <instructions>'"));
+        };
+        let mut instructions = Vec::new();
+        for value in program.split(',') {
+            let instruction = program_line.parse::<usize>(value)?;
+            if instruction > 7 {
+                return Err(program_line.error_at(
+                    value.trim(),
+                    format!("'{}' is not a 3-bit number", instruction),
+                ));
+            }
+            instructions.push(instruction);
+        }
+        if instructions.len() % 2 != 0 {
+            return Err(program_line.error_at(
+                &program_line.text[program_line.text.len()..],
+                "every opcode needs an operand",
+            ));
+        }
 
-        Self::new(register_a, register_b, register_c, instructions)
+        Ok(Self::new(register_a, register_b, register_c, instructions))
     }
 
-    pub fn execute(&mut self) {
-        loop {
-            if self.instruction_pointer >= self.instructions.len() {
-                break;
-            }
-
-            let opcode: usize = self.instructions[self.instruction_pointer];
-            let operand: usize = self.instructions[self.instruction_pointer + 1];
-            match opcode {
-                0 => self.adv(operand),
-                1 => self.bxl(operand),
-                2 => self.bst(operand),
-                3 => self.jnz(operand),
-                4 => self.bxc(operand),
-                5 => self.out(operand),
-                6 => self.bdv(operand),
-                7 => self.cdv(operand),
-                _ => self,
-            };
-
-            // println!("Register A: {}, Register B: {}, Register C: {}, Output: {}",
-            //          self.register_a, self.register_b, self.register_c, self.print_output());
-
-            if opcode != 3 {
-                self.instruction_pointer += 2;
+    /// Runs the program until it halts, or fails after [`MAX_STEPS`] instructions.
+    pub fn execute(&mut self) -> Result<(), SolveError> {
+        for _ in 0..MAX_STEPS {
+            if !self.step() {
+                return Ok(());
             }
         }
+        Err(SolveError::NoSolution(format!(
+            "the program does not halt within {} instructions",
+            MAX_STEPS
+        )))
+    }
+
+    /// Executes the instruction at the instruction pointer, returning `false` without doing
+    /// anything once the program has halted, which it does when the pointer leaves the program
+    /// or lands on its last value after a jump to an odd position.
+    pub fn step(&mut self) -> bool {
+        let (Some(&opcode), Some(&operand)) = (
+            self.instructions.get(self.instruction_pointer),
+            self.instructions.get(self.instruction_pointer + 1),
+        ) else {
+            return false;
+        };
+        match opcode {
+            0 => self.adv(operand),
+            1 => self.bxl(operand),
+            2 => self.bst(operand),
+            3 => self.jnz(operand),
+            4 => self.bxc(operand),
+            5 => self.out(operand),
+            6 => self.bdv(operand),
+            7 => self.cdv(operand),
+            _ => self,
+        };
+
+        tracing::trace!(
+            opcode,
+            operand,
+            register_a = self.register_a,
+            register_b = self.register_b,
+            register_c = self.register_c,
+            output = %self.print_output(),
+            "executed instruction"
+        );
+
+        if opcode != 3 {
+            self.instruction_pointer += 2;
+        }
+        true
+    }
+
+    /// The position of the next instruction to execute.
+    pub fn instruction_pointer(&self) -> usize {
+        self.instruction_pointer
     }
 
     pub fn print_output(&self) -> String {
@@ -81,9 +136,12 @@ impl Computer {
         self.output = Vec::new();
     }
 
-    fn get_register_from_input(input: String, register_name: &str) -> usize {
+    fn get_register_from_input(line: Line, register_name: &str) -> Result<usize, SolveError> {
         let register = format!("Register {}: ", register_name);
-        input.replacen(&register, "", 1).parse::<usize>().unwrap()
+        let Some(value) = line.text.strip_prefix(&register) else {
+            return Err(line.error(format!("expected '{}<value>'", register)));
+        };
+        line.parse::<usize>(value)
     }
 
     /// The adv instruction (opcode 0) performs division.
@@ -99,7 +157,7 @@ impl Computer {
     /// The bxl instruction (opcode 1) calculates the bitwise XOR of register B and
     /// the instruction's literal operand, then stores the result in register B.
     fn bxl(&mut self, operand: usize) -> &mut Computer {
-        self.register_b = self.register_b ^ operand;
+        self.register_b ^= operand;
         self
     }
 
@@ -130,7 +188,7 @@ impl Computer {
     /// then stores the result in register B. (For legacy reasons, this instruction reads an
     /// operand but ignores it.)
     fn bxc(&mut self, _: usize) -> &mut Computer {
-        self.register_b = self.register_b ^ self.register_c;
+        self.register_b ^= self.register_c;
         self
     }
 
@@ -171,8 +229,11 @@ impl Computer {
 
     fn do_division(&mut self, operand: usize) -> usize {
         let operand: usize = self.get_combo_operand_value(operand);
-        let denominator = (1 << operand) as usize;
-        let division = self.register_a / denominator;
-        division
+
+        // Dividing by 2^operand is a right shift, which leaves nothing once it is wider than A.
+        u32::try_from(operand)
+            .ok()
+            .and_then(|shift| self.register_a.checked_shr(shift))
+            .unwrap_or(0)
     }
 }
@@ -0,0 +1,36 @@
+use common::Example;
+
+/// The program from part 1.
+pub const PROGRAM: &str = "\
+Register A: 729
+Register B: 0
+Register C: 0
+
+Program: 0,1,5,4,3,0
+";
+
+/// The program from part 2, which outputs a copy of itself when register A is 117440.
+pub const QUINE: &str = "\
+Register A: 2024
+Register B: 0
+Register C: 0
+
+Program: 0,3,5,4,3,0
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "program",
+        input: PROGRAM,
+        part1: Some("4,6,3,5,6,3,5,2,1,0"),
+        part2: None,
+        params: None,
+    },
+    Example {
+        name: "quine",
+        input: QUINE,
+        part1: None,
+        part2: Some("117440"),
+        params: None,
+    },
+];
@@ -0,0 +1,45 @@
+use common::{Generator, NoParams, Part, Rng};
+
+use crate::Day17;
+
+impl Generator for Day17 {
+    const DEFAULT_SIZE: usize = 16;
+
+    /// A random program is almost never a quine for any value of A, so part 2 may have no answer.
+    const SOLVES: &'static [Part] = &[Part::One];
+
+    /// A program shaped like the puzzle's: each pass takes the low three bits of A into B, mixes
+    /// them with random constants and with C (A shifted right by B), prints B, then drops the
+    /// low three bits of A and loops while A is not zero. Register A has `size` octal digits, at
+    /// most 20, so the program prints `size` values and always halts.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut mixing = [
+            [1, rng.usize_in(0..=7)],
+            [7, 5],
+            [1, rng.usize_in(0..=7)],
+            [4, rng.usize_in(0..=7)],
+        ];
+        rng.shuffle(&mut mixing);
+        // C must be computed before B is mixed with it, or B could hold a shift wider than A.
+        let cdv = mixing.iter().position(|&[opcode, _]| opcode == 7).unwrap();
+        let bxc = mixing.iter().position(|&[opcode, _]| opcode == 4).unwrap();
+        if bxc < cdv {
+            mixing.swap(cdv, bxc);
+        }
+        let program: Vec<String> = [[2, 4]]
+            .iter()
+            .chain(&mixing)
+            .chain(&[[5, 5], [0, 3], [3, 0]])
+            .flatten()
+            .map(usize::to_string)
+            .collect();
+
+        let digits = size.clamp(1, 20) as u32;
+        let register_a = rng.usize_in(8usize.pow(digits - 1)..=8usize.pow(digits) - 1);
+        format!(
+            "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: {}\n",
+            register_a,
+            program.join(",")
+        )
+    }
+}
@@ -0,0 +1,266 @@
+use common::{Answer, Example, NoParams, Solution, SolveError};
+
+pub use chronospatial_computer::Computer;
+mod animate;
+mod chronospatial_computer;
+mod examples;
+mod generator;
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Computer;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let lines: Vec<String> = input.lines().map(|line| line.to_string()).collect();
+        Computer::new_from_input(&lines)
+    }
+
+    fn part1(computer: &Self::Input) -> Result<Answer, SolveError> {
+        let mut computer = computer.clone();
+        computer.execute()?;
+        Ok(computer.print_output().into())
+    }
+
+    fn part2(computer: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(find_register_a(computer)?.into())
+    }
+}
+
+/// The lowest value of register A for which the program outputs a copy of itself.
+///
+/// Like the puzzle's, the program is taken to print one value per pass and to drop the low three
+/// bits of A before looping, so the last value printed depends only on the highest octal digit
+/// of A. A is built up one octal digit at a time, keeping every value whose output matches the
+/// end of the program, so each candidate that survives to the first value is a checked answer.
+pub fn find_register_a(computer: &Computer) -> Result<usize, SolveError> {
+    let program = &computer.instructions;
+    let mut computer = computer.clone();
+    let mut candidates: Vec<usize> = vec![0];
+    for start in (0..program.len()).rev() {
+        let mut matching = Vec::new();
+        for &prefix in &candidates {
+            for digit in 0..8 {
+                let Some(register_a) = prefix.checked_mul(8).map(|a| a + digit) else {
+                    continue;
+                };
+                computer.reset(register_a);
+                computer.execute()?;
+                if computer.output == program[start..] {
+                    matching.push(register_a);
+                }
+            }
+        }
+        tracing::debug!(
+            start,
+            candidates = matching.len(),
+            "matched the end of the program"
+        );
+        candidates = matching;
+    }
+
+    candidates.into_iter().min().ok_or_else(|| {
+        SolveError::NoSolution("no value of register A makes the program output itself".to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{animate::FrameLimits, render::Color, Animate, Recorder};
+
+    use crate::chronospatial_computer::Computer;
+
+    use super::*;
+
+    common::example_tests!(Day17);
+    common::generator_tests!(Day17, 8);
+    common::parser_fuzz_tests!(Day17, 8);
+
+    #[test]
+    fn test_part2_without_a_quine() {
+        assert!(matches!(
+            Day17::part2(&Day17::parse(examples::PROGRAM).unwrap()),
+            Err(SolveError::NoSolution(_))
+        ));
+    }
+
+    #[test]
+    #[ignore = "needs the personal puzzle input in challenge.txt"]
+    fn test_part2_challenge() {
+        assert_eq!(
+            Day17::part2(&Day17::parse_file("challenge.txt").unwrap())
+                .unwrap()
+                .to_string(),
+            "105875099912602"
+        );
+    }
+
+    #[test]
+    fn test_animation_shows_registers_and_program() {
+        let computer = Day17::parse(examples::PROGRAM).unwrap();
+        let mut recorder = Recorder::new(FrameLimits::default());
+        Day17::animate(&computer, &mut recorder).unwrap();
+        let frames = recorder.frames();
+        let first = frames[0].tiles();
+        let last = frames.last().unwrap().tiles();
+
+        // Register A starts at 729, ten bits wide, and the program halts once it reaches 0.
+        assert_eq!(first.dimensions(), (6, 10));
+        assert_eq!(first[(0, 0)], Color::YELLOW);
+        assert_eq!(first[(4, 0)], Color::RED);
+        assert_eq!(first[(4, 1)], Color::ORANGE);
+        assert!((0..10).all(|col| last[(0, col)] != Color::YELLOW));
+        assert_eq!(frames.len(), 1 + 3 * 10);
+    }
+
+    #[test]
+    fn test_programs_that_never_output_or_halt() {
+        let program = |register_a: usize| {
+            format!(
+                "Register A: {}\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0\n",
+                register_a
+            )
+        };
+
+        let halting = Day17::parse(&program(0)).unwrap();
+        assert_eq!(Day17::part1(&halting).unwrap().to_string(), "");
+
+        let looping = Day17::parse(&program(1)).unwrap();
+        assert!(matches!(
+            Day17::part1(&looping),
+            Err(SolveError::NoSolution(_))
+        ));
+    }
+
+    #[test]
+    fn test_division_by_a_huge_power_of_two() {
+        let mut computer = Computer::new(1 << 40, 200, 0, vec![6, 5]);
+        computer.execute().unwrap();
+        assert_eq!(computer.register_b, 0);
+    }
+
+    /// If register C contains 9, the program 2,6 would set register B to 1.
+    #[test]
+    fn test_example_1() {
+        let lines: Vec<String> = [
+            "Register A: 0",
+            "Register B: 0",
+            "Register C: 9",
+            "",
+            "Program: 2,6",
+        ]
+        .iter()
+        .map(|&line| line.to_string())
+        .collect();
+        let mut computer: Computer = Computer::new_from_input(&lines).unwrap();
+        computer.execute().unwrap();
+        assert_eq!(computer.register_b, 1);
+    }
+
+    /// If register A contains 10, the program 5,0,5,1,5,4 would output 0,1,2.
+    #[test]
+    fn test_example_2() {
+        let lines: Vec<String> = [
+            "Register A: 10",
+            "Register B: 0",
+            "Register C: 0",
+            "",
+            "Program: 5,0,5,1,5,4",
+        ]
+        .iter()
+        .map(|&line| line.to_string())
+        .collect();
+        let mut computer: Computer = Computer::new_from_input(&lines).unwrap();
+        computer.execute().unwrap();
+        assert_eq!(computer.output, vec![0, 1, 2]);
+    }
+
+    ///If register A contains 2024, the program 0,1,5,4,3,0 would output 4,2,5,6,7,7,7,7,3,1,0 and leave 0 in register A.
+    #[test]
+    fn test_example_3() {
+        let lines: Vec<String> = [
+            "Register A: 2024",
+            "Register B: 0",
+            "Register C: 0",
+            "",
+            "Program: 0,1,5,4,3,0",
+        ]
+        .iter()
+        .map(|&line| line.to_string())
+        .collect();
+        let mut computer: Computer = Computer::new_from_input(&lines).unwrap();
+        computer.execute().unwrap();
+        assert_eq!(computer.output, vec![4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]);
+        assert_eq!(computer.register_a, 0);
+    }
+
+    /// If register B contains 29, the program 1,7 would set register B to 26
+    #[test]
+    fn test_example_4() {
+        let lines: Vec<String> = [
+            "Register A: 0",
+            "Register B: 29",
+            "Register C: 0",
+            "",
+            "Program: 1,7",
+        ]
+        .iter()
+        .map(|&line| line.to_string())
+        .collect();
+        let mut computer: Computer = Computer::new_from_input(&lines).unwrap();
+        computer.execute().unwrap();
+        assert_eq!(computer.register_b, 26);
+    }
+
+    /// If register B contains 2024 and register C contains 43690, the program 4,0 would set register B to 44354.
+    #[test]
+    fn test_example_5() {
+        let lines: Vec<String> = [
+            "Register A: 0",
+            "Register B: 2024",
+            "Register C: 43690",
+            "",
+            "Program: 4,0",
+        ]
+        .iter()
+        .map(|&line| line.to_string())
+        .collect();
+        let mut computer: Computer = Computer::new_from_input(&lines).unwrap();
+        computer.execute().unwrap();
+        assert_eq!(computer.register_b, 44354);
+    }
+
+    #[test]
+    fn test_program_with_invalid_instruction() {
+        let Err(error) =
+            Day17::parse("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 0,9\n")
+        else {
+            panic!("9 is not a valid instruction");
+        };
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 12: '9' is not a 3-bit number\n    Program: 0,9\n               ^"
+        );
+    }
+
+    #[test]
+    fn text_copy_of_itself() {
+        let lines: Vec<String> = [
+            "Register A: 2024",
+            "Register B: 0",
+            "Register C: 0",
+            "",
+            "Program: 0,3,5,4,3,0",
+        ]
+        .iter()
+        .map(|&line| line.to_string())
+        .collect();
+        let mut computer: Computer = Computer::new_from_input(&lines).unwrap();
+        computer.execute().unwrap();
+        println!("{:?}", computer.output);
+        assert!(!computer.output.is_empty());
+    }
+}
@@ -0,0 +1,39 @@
+use common::Example;
+
+/// The falling bytes from the puzzle text. They fall into a 7x7 memory space, and part 1 walks
+/// after the first 12 of them.
+pub const BYTES: &str = "\
+5,4
+4,2
+4,5
+3,0
+2,1
+6,3
+2,4
+1,5
+0,6
+3,3
+2,6
+5,1
+1,2
+5,5
+2,5
+6,5
+1,4
+0,4
+6,4
+1,1
+6,1
+1,0
+0,5
+1,6
+2,0
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "bytes",
+    input: BYTES,
+    part1: Some("22"),
+    part2: Some("6,1"),
+    params: Some("maximum_index = 6\nfallen_bytes = 12"),
+}];
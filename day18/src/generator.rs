@@ -0,0 +1,52 @@
+use std::collections::HashSet;
+
+use common::{Generator, Rng};
+
+use crate::{Day18, MemoryParams};
+
+impl Generator for Day18 {
+    const DEFAULT_SIZE: usize = 3450;
+
+    /// About `size` distinct bytes in the memory space given by `params`, never on either corner.
+    /// The first `fallen_bytes` miss a random staircase path to the exit, so part 1 can reach it;
+    /// the last ones complete a wall across a random column, so the exit is eventually blocked.
+    fn generate(size: usize, params: &MemoryParams, rng: &mut Rng) -> String {
+        let last = params.maximum_index.max(2);
+
+        let mut path = HashSet::from([(0, 0)]);
+        let (mut x, mut y) = (0, 0);
+        while (x, y) != (last, last) {
+            if x < last && (y == last || rng.chance(1, 2)) {
+                x += 1;
+            } else {
+                y += 1;
+            }
+            path.insert((x, y));
+        }
+
+        let mut cells: Vec<(usize, usize)> = (0..=last)
+            .flat_map(|y| (0..=last).map(move |x| (x, y)))
+            .filter(|&cell| cell != (0, 0) && cell != (last, last))
+            .collect();
+        rng.shuffle(&mut cells);
+        let (mut bytes, mut rest): (Vec<_>, Vec<_>) =
+            cells.into_iter().partition(|cell| !path.contains(cell));
+        rest.extend(bytes.drain(params.fallen_bytes.min(bytes.len())..));
+        rng.shuffle(&mut rest);
+        rest.truncate(size.saturating_sub(bytes.len()));
+        bytes.extend(rest);
+
+        let fallen: HashSet<(usize, usize)> = bytes.iter().copied().collect();
+        let column = rng.usize_in(1..=last - 1);
+        bytes.extend(
+            (0..=last)
+                .map(|y| (column, y))
+                .filter(|cell| !fallen.contains(cell)),
+        );
+
+        bytes
+            .iter()
+            .map(|(x, y)| format!("{},{}\n", x, y))
+            .collect()
+    }
+}
@@ -0,0 +1,189 @@
+use common::{input, search::DistanceMap, Answer, Example, Grid, Point, Solution, SolveError};
+use serde::Deserialize;
+
+mod examples;
+mod generator;
+mod render;
+
+pub struct Day18;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct MemoryParams {
+    /// Largest coordinate of the memory space.
+    pub maximum_index: usize,
+    /// Bytes that have fallen before part 1 looks for the exit.
+    pub fallen_bytes: usize,
+}
+
+impl Default for MemoryParams {
+    fn default() -> Self {
+        MemoryParams {
+            maximum_index: 70,
+            fallen_bytes: 1024,
+        }
+    }
+}
+
+pub struct MemorySpace {
+    /// The incoming byte positions as `(x, y)`, in falling order.
+    pub bytes: Vec<(usize, usize)>,
+    pub params: MemoryParams,
+}
+
+impl Solution for Day18 {
+    type Input = MemorySpace;
+    type Params = MemoryParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Self::parse_with(input, &MemoryParams::default())
+    }
+
+    fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, SolveError> {
+        let bytes = input::lines(input)
+            .map(|line| {
+                let Some((x, y)) = line.text.split_once(',') else {
+                    return Err(line.error("expected an 'x,y' position"));
+                };
+                for coordinate in [x, y] {
+                    if line.parse::<usize>(coordinate)? > params.maximum_index {
+                        let length = params.maximum_index + 1;
+                        return Err(line.error_at(
+                            coordinate.trim(),
+                            format!("outside of the {}x{} memory space", length, length),
+                        ));
+                    }
+                }
+                Ok((line.parse::<usize>(x)?, line.parse::<usize>(y)?))
+            })
+            .collect::<Result<_, SolveError>>()?;
+
+        Ok(MemorySpace {
+            bytes,
+            params: *params,
+        })
+    }
+
+    fn part1(memory: &Self::Input) -> Result<Answer, SolveError> {
+        let MemorySpace { bytes, params } = memory;
+        Ok(shortest_path(bytes, params.maximum_index, Some(params.fallen_bytes))?.into())
+    }
+
+    fn part2(memory: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(first_blocking_byte(&memory.bytes, memory.params.maximum_index)?.into())
+    }
+}
+
+/// Minimum number of steps from the top left to the bottom right corner once `take` bytes fell.
+pub fn shortest_path(
+    bytes: &[(usize, usize)],
+    maximum_index: usize,
+    take: Option<usize>,
+) -> Result<usize, SolveError> {
+    let length = maximum_index + 1;
+
+    let grid = create_grid(take, length, bytes)?;
+    Ok(shortest_route(&grid)?.len() - 1)
+}
+
+/// The positions along a shortest path from the top left to the bottom right corner of `grid`.
+pub fn shortest_route(grid: &Grid<char>) -> Result<Vec<Point>, SolveError> {
+    distances_from_start(grid)
+        .path_to(exit(grid))
+        .ok_or_else(|| SolveError::no_solution("the fallen bytes cut off the exit"))
+}
+
+/// Coordinates of the first byte that cuts the path to the exit, found by bisecting the number
+/// of fallen bytes with one distance map per probe.
+pub fn first_blocking_byte(
+    bytes: &[(usize, usize)],
+    target_index: usize,
+) -> Result<(usize, usize), SolveError> {
+    let length = target_index + 1;
+    let exit_reachable = |fallen: usize| -> Result<bool, SolveError> {
+        let grid = create_grid(Some(fallen), length, bytes)?;
+        Ok(distances_from_start(&grid).get(exit(&grid)).is_some())
+    };
+    if exit_reachable(bytes.len())? {
+        return Err(SolveError::no_solution("no byte cuts off the exit"));
+    }
+
+    // The exit can be reached once `low` bytes fell, but not once `high` did.
+    let (mut low, mut high) = (0, bytes.len());
+    while high - low > 1 {
+        let middle = (low + high) / 2;
+        if exit_reachable(middle)? {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+    Ok(bytes[high - 1])
+}
+
+/// The memory space with the first `take` bytes fallen, or all of them.
+pub fn create_grid(
+    take: Option<usize>,
+    length: usize,
+    bytes: &[(usize, usize)],
+) -> Result<Grid<char>, SolveError> {
+    let mut grid = Grid::new(length, length, '.');
+    let take: usize = take.unwrap_or(bytes.len());
+    for (index, &(x, y)) in bytes.iter().enumerate().take(take) {
+        let Some(cell) = grid.get_mut(y, x) else {
+            return Err(SolveError::invalid(format!(
+                "byte {} at {},{} is outside of the {}x{} memory space",
+                index + 1,
+                x,
+                y,
+                length,
+                length
+            )));
+        };
+        *cell = '#';
+    }
+    Ok(grid)
+}
+
+/// The steps from the top left corner to every tile no byte fell on.
+fn distances_from_start(grid: &Grid<char>) -> DistanceMap {
+    DistanceMap::new(grid, Point::ZERO, |&c| c == '.')
+}
+
+/// The bottom right corner.
+fn exit(grid: &Grid<char>) -> Point {
+    Point::from((grid.rows() - 1, grid.cols() - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::Render;
+
+    use super::*;
+
+    common::example_tests!(Day18);
+    common::generator_tests!(Day18, 1100);
+    common::parser_fuzz_tests!(Day18, 1100);
+
+    #[test]
+    fn test_bytes_that_never_block_the_exit() {
+        let error = first_blocking_byte(&[(1, 0), (1, 1)], 2).unwrap_err();
+        assert_eq!(error.to_string(), "no solution: no byte cuts off the exit");
+    }
+
+    #[test]
+    fn test_render_draws_the_shortest_path() {
+        let params = MemoryParams {
+            maximum_index: 6,
+            fallen_bytes: 12,
+        };
+        let memory = Day18::parse_with(examples::BYTES, &params).unwrap();
+        let drawn = Day18::render(&memory).unwrap().to_string();
+
+        assert_eq!(drawn.lines().count(), 7);
+        assert_eq!(drawn.matches('#').count(), 12);
+        assert_eq!(drawn.matches('O').count(), 22 + 1);
+    }
+}
@@ -0,0 +1,24 @@
+use common::{
+    render::{Color, Style},
+    Render, Scene, SolveError,
+};
+
+use crate::{create_grid, shortest_route, Day18, MemorySpace};
+
+impl Render for Day18 {
+    /// The memory space once part 1's bytes have fallen, with a shortest path to the exit drawn
+    /// as `O`, as in the puzzle text.
+    fn render(memory: &MemorySpace) -> Result<Scene, SolveError> {
+        let MemorySpace { bytes, params } = memory;
+        let grid = create_grid(Some(params.fallen_bytes), params.maximum_index + 1, bytes)?;
+        let route = shortest_route(&grid)?;
+
+        let scene = Scene::from_chars(&grid, |c| match c {
+            '#' => Style::fg(Color::RED),
+            _ => Style::fg(Color::GRAY),
+        });
+        Ok(route.into_iter().fold(scene, |scene, position| {
+            scene.mark(position, 'O', Style::fg(Color::GREEN).bold())
+        }))
+    }
+}
@@ -0,0 +1,23 @@
+use common::Example;
+
+/// The towel patterns and designs from the puzzle text.
+pub const TOWELS: &str = "\
+r, wr, b, g, bwu, rb, gb, br
+
+brwrr
+bggr
+gbbr
+rrbgbr
+ubwu
+bwurrg
+brgr
+bbrgwb
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "towels",
+    input: TOWELS,
+    part1: Some("6"),
+    part2: Some("16"),
+    params: None,
+}];
@@ -0,0 +1,49 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day19;
+
+/// The stripe colours: white, blue, black, red and green.
+const COLOURS: &[u8] = b"wubrg";
+
+impl Generator for Day19 {
+    const DEFAULT_SIZE: usize = 400;
+
+    /// About 100 towel patterns of one to eight stripes, all but one colour having a towel of a
+    /// single stripe, then `size` designs of 20 to 60 stripes.
+    /// Most designs are built from the towels and can be made; the others have one stripe
+    /// changed, which usually makes them impossible.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        let mut towels: Vec<String> = (0..100)
+            .map(|_| {
+                (0..rng.usize_in(1..=8))
+                    .map(|_| char::from(*rng.pick(COLOURS)))
+                    .collect()
+            })
+            .collect();
+        // As in the real puzzle one colour has no towel of its own, so that designs can fail
+        // deep into a search instead of on their first stripe.
+        let missing = char::from(*rng.pick(COLOURS)).to_string();
+        towels.retain(|towel| *towel != missing);
+        towels.sort();
+        towels.dedup();
+        rng.shuffle(&mut towels);
+
+        let designs: Vec<String> = (0..size)
+            .map(|_| {
+                let length = rng.usize_in(20..=60);
+                let mut design = String::new();
+                while design.len() < length {
+                    design.push_str(rng.pick(&towels).as_str());
+                }
+                if rng.chance(1, 4) {
+                    let stripe = rng.usize_in(0..=design.len() - 1);
+                    let colour = char::from(*rng.pick(COLOURS));
+                    design.replace_range(stripe..=stripe, &colour.to_string());
+                }
+                design
+            })
+            .collect();
+
+        format!("{}\n\n{}\n", towels.join(", "), designs.join("\n"))
+    }
+}
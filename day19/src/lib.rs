@@ -0,0 +1,333 @@
+use common::{input, Answer, Example, Memo, NoParams, Solution, SolveError};
+use std::collections::HashSet;
+
+mod examples;
+mod generator;
+#[cfg(test)]
+mod reference;
+
+pub struct Day19;
+
+/// The available towel patterns, sorted, and the designs to build with them.
+pub struct Onsen {
+    pub towel_patterns: Vec<String>,
+    pub designs: Vec<String>,
+}
+
+impl Solution for Day19 {
+    type Input = Onsen;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        let sections = input::sections(input);
+        let mut towel_patterns = sections[0]
+            .join(" ")
+            .split_whitespace()
+            .map(|pattern| pattern.replace(",", ""))
+            .collect::<Vec<String>>();
+        towel_patterns.sort();
+
+        let Some(designs) = sections.get(1) else {
+            return Err(SolveError::invalid("the designs are missing"));
+        };
+
+        Ok(Onsen {
+            towel_patterns,
+            designs: designs.iter().map(|line| line.to_string()).collect(),
+        })
+    }
+
+    fn part1(onsen: &Self::Input) -> Result<Answer, SolveError> {
+        let towel_patterns: Vec<&str> = onsen.towel_patterns.iter().map(|s| s.as_str()).collect();
+        Ok(onsen
+            .designs
+            .iter()
+            .filter(|design| validate_design(design, &towel_patterns))
+            .count()
+            .into())
+    }
+
+    fn part2(onsen: &Self::Input) -> Result<Answer, SolveError> {
+        let mut memo = Memo::new();
+        let arrangements = onsen
+            .designs
+            .iter()
+            .map(|design| count_arrangements(design, &onsen.towel_patterns, &mut memo))
+            .sum::<u64>();
+        tracing::debug!(stats = %memo.stats(), "arrangement counts");
+        Ok(arrangements.into())
+    }
+}
+
+pub fn part1_using_design_is_possible2(onsen: &Onsen) -> i32 {
+    onsen
+        .designs
+        .iter()
+        .enumerate()
+        .fold(0, |acc, (index, design)| {
+            if design_is_possible2(design, &onsen.towel_patterns) {
+                tracing::trace!(index, "design is possible");
+                return acc + 1;
+            }
+            acc
+        })
+}
+
+/// The number of ways to make `design` from `towel_patterns`. The memo is keyed by the part of
+/// a design left to make, so it can be shared between designs.
+pub fn count_arrangements<'a>(
+    design: &'a str,
+    towel_patterns: &[String],
+    memo: &mut Memo<&'a str, u64>,
+) -> u64 {
+    if design.is_empty() {
+        return 1;
+    }
+
+    memo.call(design, |memo| {
+        towel_patterns
+            .iter()
+            .filter(|pattern| design.starts_with(pattern.as_str()))
+            .map(|pattern| count_arrangements(&design[pattern.len()..], towel_patterns, memo))
+            .sum()
+    })
+}
+
+/// Whether `design` can be made from `towel_patterns`.
+pub fn design_is_possible(design: String, towel_patterns: &HashSet<String>) -> bool {
+    design_is_possible_memo(design, towel_patterns, &mut Memo::new())
+}
+
+fn design_is_possible_memo(
+    design: String,
+    towel_patterns: &HashSet<String>,
+    memo: &mut Memo<String, bool>,
+) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+
+    memo.call(design.clone(), |memo| {
+        for pattern in towel_patterns {
+            if design.starts_with(pattern)
+                && design_is_possible_memo(
+                    String::from(&design[pattern.len()..]),
+                    towel_patterns,
+                    memo,
+                )
+            {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// Whether `design` can be made from the sorted `towel_patterns`.
+pub fn design_is_possible2(design: &str, towel_patterns: &[String]) -> bool {
+    design_is_possible2_memo(design, towel_patterns, &mut Memo::new())
+}
+
+fn design_is_possible2_memo<'a>(
+    design: &'a str,
+    towel_patterns: &[String],
+    memo: &mut Memo<&'a str, bool>,
+) -> bool {
+    if design.is_empty() {
+        return true;
+    }
+
+    memo.call(design, |memo| {
+        for pattern in towel_patterns {
+            if pattern.len() <= design.len()
+                && design.starts_with(pattern)
+                && design_is_possible2_memo(&design[pattern.len()..], towel_patterns, memo)
+            {
+                return true;
+            }
+        }
+
+        false
+    })
+}
+
+/// Whether `design` can be made from `towels`.
+pub fn validate_design(design: &str, towels: &[&str]) -> bool {
+    validate_design_memo(design, towels, &mut Memo::new())
+}
+
+fn validate_design_memo<'a>(
+    design: &'a str,
+    towels: &[&str],
+    memo: &mut Memo<&'a str, bool>,
+) -> bool {
+    memo.call(design, |memo| {
+        towels.iter().any(|towel| {
+            if design.starts_with(*towel) {
+                let new_design = &design[towel.len()..];
+                if new_design.is_empty() {
+                    return true;
+                }
+                validate_design_memo(new_design, towels, memo)
+            } else {
+                false
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use common::differential::check_agreement;
+
+    use super::*;
+
+    common::example_tests!(Day19);
+    common::generator_tests!(Day19, 3);
+    common::parser_fuzz_tests!(Day19, 3);
+
+    fn each_design(onsen: &Onsen, is_possible: impl Fn(&str) -> bool) -> Vec<bool> {
+        onsen
+            .designs
+            .iter()
+            .map(|design| is_possible(design))
+            .collect()
+    }
+
+    fn reference(onsen: &Onsen) -> Vec<bool> {
+        each_design(onsen, |design| {
+            reference::design_is_possible(design, &onsen.towel_patterns)
+        })
+    }
+
+    #[test]
+    fn test_design_checks_agree_with_reference() {
+        check_agreement::<Day19, _>(3, reference, |onsen| {
+            let towels: HashSet<String> = onsen.towel_patterns.iter().cloned().collect();
+            each_design(onsen, |design| {
+                design_is_possible(design.to_string(), &towels)
+            })
+        });
+        check_agreement::<Day19, _>(3, reference, |onsen| {
+            each_design(onsen, |design| {
+                design_is_possible2(design, &onsen.towel_patterns)
+            })
+        });
+        check_agreement::<Day19, _>(3, reference, |onsen| {
+            let towels: Vec<&str> = onsen.towel_patterns.iter().map(String::as_str).collect();
+            each_design(onsen, |design| validate_design(design, &towels))
+        });
+        check_agreement::<Day19, _>(3, reference, |onsen| {
+            let mut memo = Memo::new();
+            onsen
+                .designs
+                .iter()
+                .map(|design| count_arrangements(design, &onsen.towel_patterns, &mut memo) > 0)
+                .collect()
+        });
+    }
+
+    #[test]
+    fn test_part1_using_design_is_possible2() {
+        assert_eq!(
+            part1_using_design_is_possible2(&Day19::parse(examples::TOWELS).unwrap()),
+            6
+        );
+    }
+
+    #[test]
+    fn test_empty_design() {
+        let design = String::from("");
+        let towel_patterns = HashSet::new();
+        assert!(design_is_possible(design, &towel_patterns));
+    }
+
+    #[test]
+    fn test_design_found_with_one_pattern() {
+        let design = String::from("k");
+        let mut towel_patterns = HashSet::new();
+        towel_patterns.insert(String::from("k"));
+        assert!(design_is_possible(design, &towel_patterns));
+    }
+
+    #[test]
+    fn test_design_found_with_one_pattern_of_two_letters() {
+        let design = String::from("ka");
+        let mut towel_patterns = HashSet::new();
+        towel_patterns.insert(String::from("k"));
+        towel_patterns.insert(String::from("ka"));
+        assert!(design_is_possible(design, &towel_patterns));
+    }
+
+    #[test]
+    fn test_design_not_found_with_one_pattern() {
+        let design = String::from("u");
+        let mut towel_patterns = HashSet::new();
+        towel_patterns.insert(String::from("k"));
+        assert!(!design_is_possible(design, &towel_patterns));
+    }
+
+    #[test]
+    fn test_examples_from_sample() {
+        let mut towel_patterns: HashSet<String> = HashSet::new();
+        towel_patterns.extend(
+            vec!["r", "wr", "b", "g", "bwu", "rb", "gb", "br"]
+                .into_iter()
+                .map(String::from),
+        );
+
+        let design = String::from("brwrr");
+        assert!(design_is_possible(design, &towel_patterns));
+
+        let design = String::from("bggr");
+        assert!(design_is_possible(design, &towel_patterns));
+
+        let design = String::from("gbbr");
+        assert!(design_is_possible(design, &towel_patterns));
+
+        let design = String::from("rrbgbr");
+        assert!(design_is_possible(design, &towel_patterns));
+
+        let design = String::from("bwurrg");
+        assert!(design_is_possible(design, &towel_patterns));
+
+        let design = String::from("brgr");
+        assert!(design_is_possible(design, &towel_patterns));
+
+        let design = String::from("ubwu");
+        assert!(!design_is_possible(design, &towel_patterns));
+
+        let design = String::from("bbrgwb");
+        assert!(!design_is_possible(design, &towel_patterns));
+    }
+
+    #[test]
+    fn test_arrangement_counts() {
+        let onsen = Day19::parse(examples::TOWELS).unwrap();
+        let mut memo = Memo::new();
+        let counts: Vec<u64> = onsen
+            .designs
+            .iter()
+            .map(|design| count_arrangements(design, &onsen.towel_patterns, &mut memo))
+            .collect();
+
+        assert_eq!(counts, [2, 1, 4, 6, 0, 1, 2, 0]);
+        assert!(memo.stats().hits > 0);
+    }
+
+    #[test]
+    fn test_from_challenge() {
+        let first_line = String::from("rrbb, rgrb, rbbwbw, ruw, grwb, wbg, rrggu, wugbb, gwr, rrgu, wbbb, bwrgugb, rgww, brrw, gwwg, brgbb, ubbbu, rbgruw, bwub, rru, grbu, grw, bwrbr, wrwbwu, bgbr, urrb, bbb, guggb, gwuwuw, ubu, wbr, bb, ubwwrb, bruuwu, buw, wrr, gbgrr, ggw, ru, wub, uub, gbrw, uwrwrb, uwuu, wbbu, gwu, brrr, grggur, wugrbrw, rwuurgg, wrbg, wuugrgw, brr, wrgwu, rwwuur, wgug, guu, brru, bgr, buwruw, bbg, bw, brwuwbb, buwbrgrb, wrwuw, ubb, rgu, wru, ugw, rruubrr, bwur, rrb, uwg, wwbbuwu, rbw, uwrgbr, ubuw, uurwr, uggw, uuguur, guuww, bbrgw, ubuwbuu, buwgg, gru, uuu, u, wgguw, bruu, bu, gwurrw, rbgwur, gubg, ubgrubw, buwrgw, uu, rwgw, rrw, wgru, gwrb, bgguu, gwugwu, urrbg, ruwwb, wuw, wwgg, wwbbu, gggb, rgrruwrw, uugr, rwb, rggw, gbr, guuurru, wurubggw, gww, wuugb, rbbww, urwgb, wrruurr, guuurgg, bwbgbg, bubwb, ggrwwg, gugr, uwrrr, guurbu, uruw, gbguruu, grb, bwr, wrubggr, rbu, wgwgru, buuww, gub, wu, uw, rrbrb, ur, wrgbb, gbbu, wgw, ggwbgwb, wb, urwr, uggwbwu, wwwg, rbru, bwbw, brb, wbugw, r, gbb, rw, bru, wbbwbr, gwww, wgrr, rugg, bgrbrub, w, gwwb, wrwgu, wwgb, uwgbgwwb, wrrwb, ubw, rr, gbbuug, bbr, wugb, rbuww, uur, wgur, ugg, wwu, wwrww, uuwur, rwbbww, gbu, bgbuur, rrbugguw, ugrgwg, wurruu, rrrgww, ggbbgbb, bgbb, brrb, rwwr, brwwr, ubrbru, uburwwb, ggwu, rwbgrbu, bbwuwbr, urwggbwr, bbrgu, wbbuww, rbwwuwr, gu, brbb, wbwgu, ugbrrguw, urbu, gbgbbrub, gbuuuww, rww, gwwgwwg, buwu, bur, uubrw, rur, rgurr, rwbgrg, urr, wugw, wuur, wuub, wbgb, bgb, uubrbur, rrbugbu, ugru, guwr, uwu, uru, wbw, wugrwgb, ggwg, guw, ubbbg, grbuwg, gw, wgb, bbrbwgub, gr, rbruu, urwwu, gbuwbw, bug, rgrgb, uwr, wrw, urb, wgbw, rbbgr, rwww, wbubur, buubw, ruwgwbug, wwwgggub, uuwrwbww, burr, rubg, wgub, bww, urbuubb, bubugrb, rgwbbw, rb, ggu, gwg, rgw, ruwggbg, wrrwbg, gur, wwbgugb, ruu, rrgg, rwrr, uwb, bwbbwbg, rbgg, rwgwg, rrgrwg, gb, bgbg, urubwugb, ruww, grbbg, gwb, bugrr, wgwr, wuug, b, gbwgr, uugw, wwr, wuu, ggr, bbwb, ubbbwrg, wugbw, gguuw, bbw, ugr, wbbrrbu, grbub, ubuwuw, bwgw, bggw, bwu, ub, bwubw, rgru, brg, uruwr, rug, wrwwuubg, brw, wggurb, br, wgr, gug, wrww, gg, uuw, bguwbru, rrrw, uubgb, gbww, buu, rbbuw, bgru, ubbwr, bg, gbgrww, wrg, ugubw, rgrg, bwbbgw, ggubrwbg, uurgwg, gbw, ww, wr, wbgu, bbu, brrwb, wug, uurug, rgb, gwbwbgg, uuubg, ugub, bruw, rub, rgggrr, ruubrwuw, brguru, wbrbg, rrwr, bgrwbbr, ugu, grwbb, rgg, wwg, rgbuwr, wuwur, bbgub, ubg, rrg, wrur, bggbr, wgg, bbwbbru, bwwbrgu, rugrr, rgwbu, ugubwbgu, wwb, ggg, bwwu, gwuguu, rrwgwg, uuwu, rrug, bgwruw, brrgrb, rurg, bugb, grwbg, rwr, urug, rwg, bgg, gbggr, rrr, gbbrw, wgu, bwbg, bbgurggg, wuwguw, wwwuru, grg, grggbw, rubu, burw, grr, bgbbw, wgrbgg, rwrww, gwurgr, bruww, bub, wbu, rbb, uwgbb, rgr, ugb, buubb, wg, wwwgr, bgw, wbwr, wuwruu, wrgrrrw, rbr, wuwwr, wrwb, ggwgwbrw, urw, uwrubb, wuwg, wguu, ugwwuw, ggb, rrggr, wrb, urwgub, bgwwrw, rwu, wurug, gbgu, brrwbb, wwbub, uwrgw, bwb, ubr, uww, gbg, wgrugwbb, bwg, wbb, bbwwgr, www");
+        let mut towel_patterns = first_line
+            .split_whitespace()
+            .map(|pattern| pattern.replace(",", ""))
+            .collect::<Vec<String>>();
+        towel_patterns.sort();
+
+        let design = String::from("ugguwugrwrgwruwguuwurubggwgwbrwwwubburwrburg");
+        assert!(!design_is_possible2(&design, &towel_patterns));
+    }
+}
@@ -0,0 +1,15 @@
+//! A reference for differential tests of the recursive design checks.
+
+/// Whether `design` can be made from `towels`, by finding every prefix of the design that can.
+pub fn design_is_possible(design: &str, towels: &[String]) -> bool {
+    let mut possible = vec![false; design.len() + 1];
+    possible[0] = true;
+    for end in 1..=design.len() {
+        possible[end] = towels.iter().any(|towel| {
+            towel.len() <= end
+                && possible[end - towel.len()]
+                && design[..end].ends_with(towel.as_str())
+        });
+    }
+    possible[design.len()]
+}
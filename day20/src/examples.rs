@@ -0,0 +1,44 @@
+use common::Example;
+
+/// The racetrack from the puzzle text; no cheat on it saves 100 picoseconds.
+pub const RACETRACK: &str = "\
+###############
+#...#...#.....#
+#.#.#.#.#.###.#
+#S#...#.#.#...#
+#######.#.#.###
+#######.#.#...#
+#######.#.###.#
+###..E#...#...#
+###.#######.###
+#...###...#...#
+#.#####.#.###.#
+#.#...#.#.#...#
+#.#.#.#.#.#.###
+#...#...#...###
+###############
+";
+
+pub const EXAMPLES: &[Example] = &[
+    Example {
+        name: "racetrack",
+        input: RACETRACK,
+        part1: Some("0"),
+        part2: Some("0"),
+        params: None,
+    },
+    Example {
+        name: "racetrack, cheats saving at least 20 picoseconds",
+        input: RACETRACK,
+        part1: Some("5"),
+        part2: None,
+        params: Some("min_saving = 20"),
+    },
+    Example {
+        name: "racetrack, cheats saving at least 50 picoseconds",
+        input: RACETRACK,
+        part1: Some("1"),
+        part2: Some("285"),
+        params: Some("min_saving = 50"),
+    },
+];
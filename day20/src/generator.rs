@@ -0,0 +1,40 @@
+use std::collections::{HashMap, VecDeque};
+
+use common::{generate, Generator, Grid, Rng};
+
+use crate::{CheatParams, Day20};
+
+impl Generator for Day20 {
+    const DEFAULT_SIZE: usize = 141;
+    /// The smallest racetrack with distinct corners for 'S' and 'E' is 5 by 5.
+    const MIN_SIZE: usize = 5;
+
+    /// A racetrack of about `size` by `size` tiles: the only path of a random maze between two
+    /// opposite corners, with every other corridor walled up.
+    fn generate(size: usize, _params: &CheatParams, rng: &mut Rng) -> String {
+        let maze = generate::maze((size - 1) / 2, rng);
+        let side = maze.rows();
+        let (start, end) = ((side - 2, 1), (1, side - 2));
+
+        let mut previous = HashMap::from([(start, start)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(position) = queue.pop_front() {
+            for next in maze.neighbors4(position) {
+                if maze[next] == '.' && !previous.contains_key(&next) {
+                    previous.insert(next, position);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        let mut track = Grid::new(side, side, '#');
+        let mut position = end;
+        while position != start {
+            track[position] = '.';
+            position = previous[&position];
+        }
+        track[start] = 'S';
+        track[end] = 'E';
+        track.to_string()
+    }
+}
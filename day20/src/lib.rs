@@ -0,0 +1,219 @@
+use common::{
+    geometry::ORTHOGONAL, search::Route, Answer, Example, Grid, Point, Solution, SolveError,
+};
+use serde::Deserialize;
+
+mod examples;
+mod generator;
+#[cfg(test)]
+mod reference;
+mod render;
+
+pub struct Day20;
+
+/// The longest a cheat may last in part 2, in picoseconds.
+pub const LONG_CHEAT: usize = 20;
+
+/// Picoseconds a cheat must save to be counted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CheatParams {
+    pub min_saving: usize,
+}
+
+impl Default for CheatParams {
+    fn default() -> Self {
+        CheatParams { min_saving: 100 }
+    }
+}
+
+/// The racetrack with its start and end positions replaced by track tiles.
+pub struct RaceTrack {
+    pub grid: Grid<char>,
+    pub start: Point,
+    pub end: Point,
+    pub params: CheatParams,
+}
+
+impl Solution for Day20 {
+    type Input = RaceTrack;
+    type Params = CheatParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Self::parse_with(input, &CheatParams::default())
+    }
+
+    fn parse_with(input: &str, params: &Self::Params) -> Result<Self::Input, SolveError> {
+        let mut grid = Grid::parse(input)?;
+        let mut find_tile = |tile: char| {
+            let position = grid.find(&tile).map(Point::from).ok_or_else(|| {
+                SolveError::invalid(format!("the racetrack has no '{}' tile", tile))
+            })?;
+            grid[position] = '.';
+            Ok::<Point, SolveError>(position)
+        };
+        let start = find_tile('S')?;
+        let end = find_tile('E')?;
+
+        Ok(RaceTrack {
+            grid,
+            start,
+            end,
+            params: *params,
+        })
+    }
+
+    fn part1(track: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_cheats(track).into())
+    }
+
+    fn part2(track: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(count_long_cheats(track, LONG_CHEAT).into())
+    }
+}
+
+/// A shortcut through the single wall tile `wall`, from the track position `start` to `end`.
+pub struct Cheat {
+    pub start: Point,
+    pub wall: Point,
+    pub end: Point,
+}
+
+/// The number of cheats that save at least `min_saving` picoseconds.
+pub fn count_cheats(track: &RaceTrack) -> usize {
+    find_cheats(track).1.len()
+}
+
+/// The number of cheats lasting at most `duration` picoseconds that save at least `min_saving`.
+/// Such a cheat goes from a track tile to any other within `duration` steps, whatever lies
+/// between, so it is told apart by where it starts and ends only.
+pub fn count_long_cheats(track: &RaceTrack, duration: usize) -> usize {
+    let route = Route::new(&track.grid, track.start, track.end, |&c| c == '.');
+    let Some(length) = route.length() else {
+        return 0;
+    };
+    route
+        .from_start
+        .reachable()
+        .map(|(from, _)| {
+            from.within(duration)
+                .filter(|&(to, jump)| {
+                    route
+                        .via_shortcut(from, to, jump)
+                        .is_some_and(|cheating| cheating + track.params.min_saving <= length)
+                })
+                .count()
+        })
+        .sum()
+}
+
+/// The path from the start to the end and the cheats that save at least `min_saving`.
+pub fn find_cheats(track: &RaceTrack) -> (Vec<Point>, Vec<Cheat>) {
+    let RaceTrack {
+        grid,
+        start,
+        end,
+        params,
+    } = track;
+    let (rows, cols) = (grid.rows() as isize, grid.cols() as isize);
+
+    let route = Route::new(grid, *start, *end, |&c| c == '.');
+    let (Some(length), Some(path)) = (route.length(), route.from_start.path_to(*end)) else {
+        return (Vec::new(), Vec::new());
+    };
+
+    let mut cheats: Vec<Cheat> = Vec::new();
+    for &pos in &path {
+        for direction in ORTHOGONAL {
+            let wall = pos + direction;
+            let inner = wall.row > 0 && wall.row < rows - 1 && wall.col > 0 && wall.col < cols - 1;
+            if !inner || grid.get_point(wall) != Some(&'#') {
+                continue;
+            }
+            let next = wall + direction;
+            // The cheat takes two picoseconds to cross the wall.
+            let Some(cheating) = route.via_shortcut(pos, next, 2) else {
+                continue;
+            };
+            if cheating + params.min_saving <= length {
+                cheats.push(Cheat {
+                    start: pos,
+                    wall,
+                    end: next,
+                });
+            }
+        }
+    }
+    tracing::debug!(
+        ?start,
+        ?end,
+        path_length = path.len(),
+        count_cheat_path = cheats.len(),
+        "counted cheats"
+    );
+    tracing::trace!(?path, "racetrack path");
+    (path, cheats)
+}
+
+#[cfg(test)]
+mod tests {
+    use common::{differential::check_agreement, render::Color, Render};
+
+    use super::*;
+
+    common::example_tests!(Day20);
+    common::generator_tests!(Day20, 21);
+    common::parser_fuzz_tests!(Day20, 21);
+
+    #[test]
+    fn test_cheats_agree_with_scanning_the_path() {
+        let with_small_savings = |track: &RaceTrack| RaceTrack {
+            grid: track.grid.clone(),
+            params: CheatParams { min_saving: 4 },
+            ..*track
+        };
+        check_agreement::<Day20, _>(
+            21,
+            |track| reference::count_cheats(&with_small_savings(track)),
+            |track| count_cheats(&with_small_savings(track)),
+        );
+    }
+
+    #[test]
+    fn test_long_cheats_agree_with_scanning_the_path() {
+        let with_small_savings = |track: &RaceTrack| RaceTrack {
+            grid: track.grid.clone(),
+            params: CheatParams { min_saving: 10 },
+            ..*track
+        };
+        check_agreement::<Day20, _>(
+            21,
+            |track| reference::count_long_cheats(&with_small_savings(track), LONG_CHEAT),
+            |track| count_long_cheats(&with_small_savings(track), LONG_CHEAT),
+        );
+    }
+
+    #[test]
+    fn test_two_picosecond_cheats_are_the_short_ones() {
+        let params = CheatParams { min_saving: 20 };
+        let track = Day20::parse_with(examples::RACETRACK, &params).unwrap();
+        assert_eq!(count_long_cheats(&track, 2), count_cheats(&track));
+    }
+
+    #[test]
+    fn test_render_highlights_cheat_walls() {
+        let params = CheatParams { min_saving: 20 };
+        let track = Day20::parse_with(examples::RACETRACK, &params).unwrap();
+        let scene = Day20::render(&track).unwrap();
+        let walls = scene
+            .cells()
+            .iter()
+            .filter(|(_, cell)| cell.style.bg == Some(Color::RED))
+            .count();
+
+        assert_eq!(walls, 5);
+        assert_eq!(scene.to_string(), examples::RACETRACK);
+    }
+}
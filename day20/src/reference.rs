@@ -0,0 +1,76 @@
+//! Brute-force answers for differential tests of the optimized solver.
+
+use common::{geometry::ORTHOGONAL, Point};
+
+use crate::RaceTrack;
+
+/// The cheats through one wall tile that save at least `min_saving`, counted by following the
+/// track from the start and looking up, for every wall next to it, how much further along the
+/// track the tile behind that wall is.
+pub fn count_cheats(track: &RaceTrack) -> usize {
+    let RaceTrack { grid, params, .. } = track;
+    let path = track_path(track);
+
+    let inner = |point: Point| {
+        point.row > 0
+            && point.col > 0
+            && point.row < grid.rows() as isize - 1
+            && point.col < grid.cols() as isize - 1
+    };
+    let mut count = 0;
+    for (index, &position) in path.iter().enumerate() {
+        for offset in ORTHOGONAL {
+            let wall = position + offset;
+            if !inner(wall) || grid.get_point(wall) != Some(&'#') {
+                continue;
+            }
+            let behind = wall + offset;
+            if path[index..]
+                .iter()
+                .position(|&later| later == behind)
+                .is_some_and(|ahead| ahead >= 2 + params.min_saving)
+            {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// The cheats of at most `duration` picoseconds that save at least `min_saving`, counted by
+/// comparing every pair of tiles along the track.
+pub fn count_long_cheats(track: &RaceTrack, duration: usize) -> usize {
+    let path = track_path(track);
+    let mut count = 0;
+    for (index, &from) in path.iter().enumerate() {
+        for (ahead, &to) in path[index..].iter().enumerate() {
+            let jump = from.manhattan_distance(to);
+            if jump <= duration && ahead >= jump + track.params.min_saving {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+/// The tiles from the start to the end, empty when the track is broken. The track is a single
+/// path, so every tile but the start has one way on.
+fn track_path(track: &RaceTrack) -> Vec<Point> {
+    let RaceTrack {
+        grid, start, end, ..
+    } = track;
+    let mut path = vec![*start];
+    let mut previous = None;
+    while let Some(&position) = path.last().filter(|&position| position != end) {
+        let Some(next) = ORTHOGONAL
+            .iter()
+            .map(|&offset| position + offset)
+            .find(|&next| grid.get_point(next) == Some(&'.') && Some(next) != previous)
+        else {
+            return Vec::new();
+        };
+        previous = Some(position);
+        path.push(next);
+    }
+    path
+}
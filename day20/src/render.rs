@@ -0,0 +1,31 @@
+use common::{
+    render::{Color, Style},
+    Render, Scene, SolveError,
+};
+
+use crate::{find_cheats, Day20, RaceTrack};
+
+impl Render for Day20 {
+    /// The racetrack with its path in blue and, for every cheat saving at least `min_saving`,
+    /// the wall it passes through in red and the track tiles it joins in yellow.
+    fn render(track: &RaceTrack) -> Result<Scene, SolveError> {
+        let (path, cheats) = find_cheats(track);
+
+        let scene = Scene::from_chars(&track.grid, |c| match c {
+            '#' => Style::fg(Color::GRAY),
+            _ => Style::PLAIN,
+        })
+        .highlight(path, Style::bg(Color::BLUE))
+        .highlight(
+            cheats.iter().flat_map(|cheat| [cheat.start, cheat.end]),
+            Style::bg(Color::YELLOW),
+        )
+        .highlight(
+            cheats.iter().map(|cheat| cheat.wall),
+            Style::fg(Color::WHITE).on(Color::RED).bold(),
+        );
+        Ok(scene
+            .mark(track.start, 'S', Style::fg(Color::WHITE).bold())
+            .mark(track.end, 'E', Style::fg(Color::WHITE).bold()))
+    }
+}
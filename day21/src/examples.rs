@@ -0,0 +1,18 @@
+use common::Example;
+
+/// The door codes from the puzzle text.
+pub const CODES: &str = "\
+029A
+980A
+179A
+456A
+379A
+";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: "codes",
+    input: CODES,
+    part1: Some("126384"),
+    part2: Some("154115708116294"),
+    params: None,
+}];
@@ -0,0 +1,14 @@
+use common::{Generator, NoParams, Rng};
+
+use crate::Day21;
+
+impl Generator for Day21 {
+    const DEFAULT_SIZE: usize = 5;
+
+    /// `size` door codes of three digits followed by `A`.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| format!("{:03}A\n", rng.usize_in(0..=999)))
+            .collect()
+    }
+}
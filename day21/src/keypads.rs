@@ -1,72 +1,121 @@
-use pathfinding::prelude::dijkstra;
-use std::collections::HashMap; // Import dijkstra from the pathfinding crate
+use common::{
+    search::{self, GridMaze},
+    Grid, Memo, Point,
+};
 
+/// The fewest presses on the human's keypad that make a robot arm some layers down move between
+/// two directional keys and press the second, by the two keys and the number of layers.
+pub type PressCosts = Memo<(char, char, usize), u64>;
+
+/// The keypads laid out as in the puzzle, with a space for the gap no robot arm may point at.
 #[derive(Debug)]
 pub struct Keypad {
-    pub numerical: HashMap<char, Vec<(char, char)>>,
-    pub directional: HashMap<char, Vec<(char, char)>>,
+    pub numerical: Grid<char>,
+    pub directional: Grid<char>,
 }
 
 impl Keypad {
     pub fn new() -> Self {
-        let mut numerical = HashMap::new();
-
-        // First row
-        numerical.insert('7', vec![('8', '>'), ('4', 'v')]);
-        numerical.insert('8', vec![('7', '<'), ('9', '>'), ('5', 'v')]);
-        numerical.insert('9', vec![('8', '<'), ('6', 'v')]);
-
-        // Second row
-        numerical.insert('4', vec![('7', '^'), ('5', '>'), ('1', 'v')]);
-        numerical.insert('5', vec![('8', '^'), ('4', '<'), ('6', '>'), ('2', 'v')]);
-        numerical.insert('6', vec![('9', '^'), ('5', '<'), ('3', 'v')]);
-
-        // Third row
-        numerical.insert('1', vec![('4', '^'), ('2', '>')]);
-        numerical.insert('2', vec![('5', '^'), ('1', '<'), ('3', '>'), ('0', 'v')]);
-        numerical.insert('3', vec![('6', '^'), ('2', '<'), ('A', 'v')]);
-
-        // Fourth row
-        numerical.insert('0', vec![('2', '^'), ('A', '>')]);
-        numerical.insert('A', vec![('3', '^'), ('0', '<')]);
-
-        let mut directional = HashMap::new();
-        directional.insert('A', vec![('>', 'v'), ('^', '<')]);
-        directional.insert('^', vec![('A', '>'), ('v', 'v')]);
-        directional.insert('<', vec![('v', '>')]);
-        directional.insert('v', vec![('>', '>'), ('<', '<')]);
-        directional.insert('>', vec![('A', '^'), ('v', '<')]);
-
         Keypad {
-            numerical,
-            directional,
+            numerical: Grid::parse("789\n456\n123\n 0A\n").expect("the keypad is rectangular"),
+            directional: Grid::parse(" ^A\n<v>\n").expect("the keypad is rectangular"),
         }
     }
 
-    pub fn path_directional_to_numerical(&self, code: &String) -> Vec<char> {
+    pub fn path_directional_to_numerical(&self, code: &str) -> Vec<char> {
+        let keys = GridMaze::new(&self.numerical, |&key| (key != ' ').then_some(1));
+        let key_position = |key: char| self.numerical.find(&key).map(Point::from);
         let mut result: Vec<char> = Vec::new();
         let mut start = 'A';
         result.push(start);
 
         code.chars().for_each(|c| {
-            let path: Option<(Vec<char>, usize)> = dijkstra(
-                &start,
-                |p| {
-                    self.numerical
-                        .get(&p)
-                        .unwrap()
-                        .iter()
-                        .map(|(c, _)| (*c, 1))
-                        .collect::<Vec<(char, usize)>>()
-                },
-                |p| *p == c,
+            let path = key_position(start)
+                .zip(key_position(c))
+                .and_then(|(from, to)| search::astar(&keys, &from, to));
+            tracing::trace!(?path, "numerical keypad path to {}", c);
+            let (path, _) = path.unwrap_or_default();
+            result.extend(
+                path.iter()
+                    .skip(1)
+                    .map(|&position| self.numerical[position]),
             );
-            println!("Path: {:?}", path);
-            result.extend(path.unwrap_or_else(|| (Vec::new(), 0)).0[1..].iter());
             start = c;
         });
 
-        println!("Result: {:?}", result);
+        tracing::debug!(?result, "numerical keypad presses for {}", code);
         result
     }
+
+    /// The fewest presses on the human's keypad that type `code` on the numerical keypad, with
+    /// `robots` robots on directional keypads in between.
+    pub fn code_presses(&self, code: &str, robots: usize, costs: &mut PressCosts) -> u64 {
+        let mut from = 'A';
+        code.chars()
+            .map(|to| {
+                let presses = arm_moves(&self.numerical, from, to)
+                    .iter()
+                    .map(|keys| self.sequence_presses(keys, robots, costs))
+                    .min()
+                    .unwrap_or_default();
+                from = to;
+                presses
+            })
+            .sum()
+    }
+
+    /// The fewest presses on the human's keypad that type `keys` on a directional keypad
+    /// `robots` layers down, starting with its arm on A.
+    fn sequence_presses(&self, keys: &str, robots: usize, costs: &mut PressCosts) -> u64 {
+        if robots == 0 {
+            return keys.len() as u64;
+        }
+
+        let mut from = 'A';
+        keys.chars()
+            .map(|to| {
+                let presses = self.key_presses(from, to, robots, costs);
+                from = to;
+                presses
+            })
+            .sum()
+    }
+
+    fn key_presses(&self, from: char, to: char, robots: usize, costs: &mut PressCosts) -> u64 {
+        costs.call((from, to, robots), |costs| {
+            arm_moves(&self.directional, from, to)
+                .iter()
+                .map(|keys| self.sequence_presses(keys, robots - 1, costs))
+                .min()
+                .unwrap_or_default()
+        })
+    }
+}
+
+impl Default for Keypad {
+    fn default() -> Self {
+        Keypad::new()
+    }
+}
+
+/// The directional keys that move an arm over `keypad` from `from` to `to` and press it. Only
+/// all the horizontal moves then all the vertical ones, or the other way round, can be shortest
+/// further up, and either is left out when it would pass over the gap.
+fn arm_moves(keypad: &Grid<char>, from: char, to: char) -> Vec<String> {
+    let position = |key: char| Point::from(keypad.find(&key).expect("the key is on the keypad"));
+    let (from, to) = (position(from), position(to));
+    let gap = keypad.find(&' ').map(Point::from);
+
+    let horizontal = if to.col < from.col { "<" } else { ">" }.repeat(from.col.abs_diff(to.col));
+    let vertical = if to.row < from.row { "^" } else { "v" }.repeat(from.row.abs_diff(to.row));
+
+    let mut moves = Vec::new();
+    if gap != Some(Point::new(from.row, to.col)) {
+        moves.push(format!("{horizontal}{vertical}A"));
+    }
+    if gap != Some(Point::new(to.row, from.col)) {
+        moves.push(format!("{vertical}{horizontal}A"));
+    }
+    moves.dedup();
+    moves
 }
@@ -0,0 +1,133 @@
+use common::{input, Answer, Example, NoParams, Solution, SolveError};
+
+pub use keypads::{Keypad, PressCosts};
+mod examples;
+mod generator;
+mod keypads;
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    /// The door codes to type, one per line.
+    type Input = Vec<String>;
+    type Params = NoParams;
+
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        input::lines(input)
+            .map(|line| {
+                if let Some((index, c)) = line
+                    .text
+                    .char_indices()
+                    .find(|&(_, c)| !(c.is_ascii_digit() || c == 'A'))
+                {
+                    return Err(line.error_at(
+                        &line.text[index..],
+                        format!("'{}' is not a numeric keypad key", c),
+                    ));
+                }
+                let digits = line.text.strip_suffix('A').unwrap_or_default();
+                if digits.len() != 3 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(line.error("a code is three digits followed by 'A'"));
+                }
+                Ok(line.text.to_string())
+            })
+            .collect()
+    }
+
+    fn part1(codes: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(complexities(codes, 2)?.into())
+    }
+
+    fn part2(codes: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(complexities(codes, 25)?.into())
+    }
+}
+
+/// The sum of each code's complexity: the presses it takes on the human's keypad, with `robots`
+/// robots on directional keypads in between, times the number in the code. Fails with
+/// [`SolveError::Overflow`] if the sum does not fit in a `u64`.
+pub fn complexities(codes: &[String], robots: usize) -> Result<u64, SolveError> {
+    let keypads = Keypad::new();
+    tracing::debug!(?keypads, "keypad layouts");
+    let mut costs = PressCosts::new();
+
+    let total = codes.iter().try_fold(0u64, |total, code| {
+        let presses = keypads.code_presses(code, robots, &mut costs);
+        let number = code
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .try_fold(0u64, |number, digit| {
+                number.checked_mul(10)?.checked_add(u64::from(digit))
+            })
+            .ok_or(SolveError::Overflow)?;
+        tracing::trace!(code, presses, number, "complexity");
+        presses
+            .checked_mul(number)
+            .and_then(|complexity| total.checked_add(complexity))
+            .ok_or(SolveError::Overflow)
+    })?;
+    tracing::debug!(stats = %costs.stats(), robots, "press costs");
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    common::example_tests!(Day21);
+    common::generator_tests!(Day21, 3);
+    common::parser_fuzz_tests!(Day21, 3);
+
+    #[test]
+    fn test_codes_must_be_three_digits_and_a() {
+        for input in [
+            "316\n",
+            "262A\n638A504A165A\n",
+            "29A\n",
+            "0029A\n",
+            "A\n",
+            "02A9\n",
+        ] {
+            assert!(
+                matches!(Day21::parse(input), Err(SolveError::Parse(_))),
+                "{:?}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_complexities_overflow() {
+        let codes = vec!["999A".to_string()];
+        assert!(matches!(
+            complexities(&codes, 40),
+            Err(SolveError::Overflow)
+        ));
+    }
+
+    #[test]
+    fn test_code_presses() {
+        let keypads = Keypad::new();
+        let mut costs = PressCosts::new();
+
+        // <A^A>^^AvvvA, typed by one robot, then by a second one.
+        assert_eq!(keypads.code_presses("029A", 0, &mut costs), 12);
+        assert_eq!(keypads.code_presses("029A", 1, &mut costs), 28);
+        assert_eq!(keypads.code_presses("029A", 2, &mut costs), 68);
+        assert_eq!(keypads.code_presses("379A", 2, &mut costs), 64);
+        assert!(costs.stats().hits > 0);
+    }
+
+    #[test]
+    fn test_numerical_keypad_path_avoids_the_gap() {
+        let keys = Keypad::new().path_directional_to_numerical("029A");
+
+        assert_eq!(keys.len(), 1 + 1 + 1 + 3 + 3);
+        assert_eq!(keys[..3], ['A', '0', '2']);
+        assert_eq!(keys[5], '9');
+        assert_eq!(keys.last(), Some(&'A'));
+        assert!(!keys.contains(&' '));
+    }
+}
@@ -16,65 +16,113 @@ if [ ! -d "$SUB_FOLDER" ]; then
     echo "Created sub-folder: $SUB_FOLDER"
 fi
 
-# Content for main.rs
-MAIN_RS_CONTENT="use std::{
-    collections::{HashMap, HashSet},
-    fs::File,
-    io::{self, BufRead},
-};
+# Name of the Solution implementor, e.g. day22 -> Day22
+SOLUTION_NAME="${SUB_FOLDER^}"
 
-fn main() {
-    println!(\"Advent of Code 2024 - $SUB_FOLDER\");
-    println!(\"Part 1: {}\", part1(\"challenge.txt\"));
-    println!(\"Part 2: {}\", part2(\"challenge.txt\"));
-}
+# Content for lib.rs
+LIB_RS_CONTENT="use common::{Answer, Example, NoParams, Solution, SolveError};
 
-fn part1(file_path: &str) -> i32 {
-    let mut lines = read_file(file_path).unwrap();
-    0
-}
+mod examples;
+mod generator;
 
-fn part2(file_path: &str) -> i32 {
-    let mut lines = read_file(file_path).unwrap();
-    0
-}
+pub struct $SOLUTION_NAME;
+
+impl Solution for $SOLUTION_NAME {
+    type Input = Vec<String>;
+    type Params = NoParams;
 
-fn read_file(file_path: &str) -> io::Result<Vec<String>> {
-    // Open the file
-    let file = File::open(file_path)?;
-    let reader = io::BufReader::new(file);
+    const EXAMPLES: &'static [Example] = examples::EXAMPLES;
+
+    fn parse(input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.lines().map(|line| line.to_string()).collect())
+    }
 
-    // Collect the lines into a vector
-    let lines: Vec<String> = reader.lines().filter_map(Result::ok).collect();
-    Ok(lines)
+    fn part1(_lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(0.into())
+    }
+
+    fn part2(_lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(0.into())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(\"test.txt\"), 0);
-    }
+    common::example_tests!($SOLUTION_NAME);
+    common::generator_tests!($SOLUTION_NAME, 10);
+    common::parser_fuzz_tests!($SOLUTION_NAME, 10);
+}
+"
+
+# Content for examples.rs: paste the puzzle's example and fill in its answers once known
+EXAMPLES_RS_CONTENT="use common::Example;
 
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(\"test.txt\"), 0);
+pub const EXAMPLE: &str = \"\\
+\";
+
+pub const EXAMPLES: &[Example] = &[Example {
+    name: \"example\",
+    input: EXAMPLE,
+    part1: None,
+    part2: None,
+    params: None,
+}];
+"
+
+# Content for generator.rs: replace the placeholder lines with structurally valid puzzle inputs
+GENERATOR_RS_CONTENT="use common::{Generator, NoParams, Rng};
+
+use crate::$SOLUTION_NAME;
+
+impl Generator for $SOLUTION_NAME {
+    const DEFAULT_SIZE: usize = 1000;
+
+    /// \`size\` lines of random numbers.
+    fn generate(size: usize, _params: &NoParams, rng: &mut Rng) -> String {
+        (0..size)
+            .map(|_| format!(\"{}\\n\", rng.usize_in(0..=999)))
+            .collect()
     }
 }
 "
 
-# Update or create main.rs in the sub-folder
+# Content for main.rs
+MAIN_RS_CONTENT="use common::{Solution, SolveError};
+use $SUB_FOLDER::$SOLUTION_NAME;
+
+fn main() -> Result<(), SolveError> {
+    common::logging::init_from_env();
+    println!(\"Advent of Code 2024 - $SUB_FOLDER\");
+    let input = $SOLUTION_NAME::parse_file(\"challenge.txt\")?;
+    println!(\"Part 1: {}\", $SOLUTION_NAME::part1(&input)?);
+    println!(\"Part 2: {}\", $SOLUTION_NAME::part2(&input)?);
+    Ok(())
+}
+"
+
+# Update or create lib.rs and main.rs in the sub-folder
+echo "$LIB_RS_CONTENT" > "$SUB_FOLDER/src/lib.rs"
+echo "Updated $SUB_FOLDER/lib.rs with predefined content."
 echo "$MAIN_RS_CONTENT" > "$SUB_FOLDER/src/main.rs"
 echo "Updated $SUB_FOLDER/main.rs with predefined content."
+echo "Remember to add $SUB_FOLDER to the workspace members and to the aoc runner."
+
+# Create examples.rs in the sub-folder
+if [ ! -f "$SUB_FOLDER/src/examples.rs" ]; then
+    echo "$EXAMPLES_RS_CONTENT" > "$SUB_FOLDER/src/examples.rs"
+    echo "Created $SUB_FOLDER/src/examples.rs."
+else
+    echo "$SUB_FOLDER/src/examples.rs already exists."
+fi
 
-# Create example.txt in the sub-folder
-if [ ! -f "$SUB_FOLDER/test.txt" ]; then
-    touch "$SUB_FOLDER/test.txt"
-    echo "Created $SUB_FOLDER/test.txt."
+# Create generator.rs in the sub-folder
+if [ ! -f "$SUB_FOLDER/src/generator.rs" ]; then
+    echo "$GENERATOR_RS_CONTENT" > "$SUB_FOLDER/src/generator.rs"
+    echo "Created $SUB_FOLDER/src/generator.rs."
 else
-    echo "$SUB_FOLDER/test.txt already exists."
+    echo "$SUB_FOLDER/src/generator.rs already exists."
 fi
 
 # Create challenge.txt in the sub-folder